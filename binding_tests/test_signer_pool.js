@@ -0,0 +1,59 @@
+// Checks the `SignerPool` of the node wasm package, the path of the package can be set by
+// `ZKLINK_SDK_NODE`.
+const test = require("node:test");
+const assert = require("node:assert");
+const path = require("path");
+
+const sdkPath = process.env.ZKLINK_SDK_NODE
+    ?? path.join(__dirname, "..", "bindings", "generated", "wasm", "node-dist", "zklink-sdk-node");
+const { Signer, L1Type, Order } = require(sdkPath);
+const { SignerPool } = require(path.join(path.dirname(sdkPath), "signer_pool"));
+
+const PRIVATE_KEY = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+
+function order(nonce) {
+    return new Order(5, 20, 1, nonce, 18, 17, "10000000000000", "10000000000", true, 5, 3, false).json_value();
+}
+
+test("sign through the pool", async () => {
+    const pool = new SignerPool(PRIVATE_KEY, { size: 2 });
+    try {
+        await pool.init();
+        const signer = new Signer(PRIVATE_KEY, L1Type.Eth);
+        const orders = [1, 2, 3, 4].map(order);
+        const signedOrders = await Promise.all(orders.map((order) => pool.createSignedOrder(order)));
+        for (let i = 0; i < orders.length; i++) {
+            assert.deepStrictEqual(signedOrders[i], signer.createSignedOrderFromJson(orders[i]));
+        }
+    } finally {
+        await pool.close();
+    }
+    await assert.rejects(pool.createSignedOrder(order(5)), /closed/);
+});
+
+test("respawn the crashed worker", async () => {
+    const pool = new SignerPool(PRIVATE_KEY, { size: 2 });
+    try {
+        await pool.init();
+        const crashed = pool.workers[0];
+        await crashed.worker.terminate();
+        assert.strictEqual(pool.workers.length, 2);
+        assert.ok(!pool.workers.includes(crashed));
+        // the tasks are signed by the alive and the respawned workers
+        const signedOrders = await Promise.all([1, 2, 3, 4].map((nonce) => pool.createSignedOrder(order(nonce))));
+        assert.strictEqual(signedOrders.length, 4);
+    } finally {
+        await pool.close();
+    }
+});
+
+test("reject the tasks if no worker is alive", async () => {
+    const pool = new SignerPool("invalid private key", { size: 2 });
+    await assert.rejects(pool.init());
+    // wait for the failed workers to exit
+    while (pool.workers.length > 0) {
+        await new Promise((resolve) => setTimeout(resolve, 10));
+    }
+    await assert.rejects(pool.createSignedOrder(order(1)), /no signer worker is alive/);
+    await pool.close();
+});
@@ -0,0 +1,62 @@
+// Checks the node wasm package with the conformance test vectors generated by
+// `make generate_test_vectors`, the path of the package can be set by `ZKLINK_SDK_NODE`.
+const test = require("node:test");
+const assert = require("node:assert");
+const fs = require("fs");
+const path = require("path");
+
+const sdkPath = process.env.ZKLINK_SDK_NODE
+    ?? path.join(__dirname, "..", "bindings", "generated", "wasm", "node-dist", "zklink-sdk-node");
+const { Signer, L1Type, getZkLinkTxBytes, getZkLinkTxHash } = require(sdkPath);
+
+const TEST_VECTORS_PATH = path.join(__dirname, "..", "test_vectors", "vectors.json");
+const TEST_VECTORS_VERSION = 2;
+
+const vectors = JSON.parse(fs.readFileSync(TEST_VECTORS_PATH, "utf8"));
+
+test("test vectors version", () => {
+    assert.strictEqual(vectors.version, TEST_VECTORS_VERSION);
+});
+
+test("test vectors keys", () => {
+    for (const key of vectors.keys) {
+        const signer = key.starknetChainId !== undefined && key.starknetAddr !== undefined
+            ? new Signer(key.privateKey, L1Type.Starknet, key.starknetChainId, key.starknetAddr)
+            : new Signer(key.privateKey, L1Type.Eth);
+        assert.strictEqual(signer.getPubkey(), key.publicKey, key.name);
+        assert.strictEqual(signer.getPubkeyHash(), key.pubkeyHash, key.name);
+    }
+});
+
+test("test vectors txs", () => {
+    const keys = new Map(vectors.keys.map((key) => [key.name, key]));
+    for (const vector of vectors.txs) {
+        const bytes = Buffer.from(getZkLinkTxBytes(vector.tx)).toString("hex");
+        assert.strictEqual("0x" + bytes, vector.bytes, vector.name);
+        assert.strictEqual(getZkLinkTxHash(vector.tx), vector.txHash, vector.name);
+        if (vector.signer === undefined) {
+            continue;
+        }
+
+        const key = keys.get(vector.signer);
+        const context = vector.context ?? {};
+        const signer = context.starknetChainId !== undefined && context.starknetAddr !== undefined
+            ? new Signer(key.privateKey, L1Type.Starknet, context.starknetChainId, context.starknetAddr)
+            : new Signer(key.privateKey, L1Type.Eth);
+        const txSignature = signer.sign(vector.tx, vector.context);
+        const signature = txSignature.tx.signature;
+        assert.strictEqual(signature.pubKey, vector.zkSignature.pubKey, vector.name);
+        // the signature of the tx json is the hex string without `0x`
+        assert.strictEqual("0x" + signature.signature, vector.zkSignature.signature, vector.name);
+        if (vector.l1SignatureType === undefined) {
+            // the layer1 signature of `ChangePubKey` is the eth auth data of the signed tx
+            assert.ok(txSignature.layer1_signature == null, vector.name);
+            if (vector.l1Signature !== undefined) {
+                assert.strictEqual(txSignature.tx.ethAuthData.ethSignature, vector.l1Signature, vector.name);
+            }
+            continue;
+        }
+        assert.strictEqual(txSignature.layer1_signature.type, vector.l1SignatureType, vector.name);
+        assert.strictEqual(txSignature.layer1_signature.signature, vector.l1Signature, vector.name);
+    }
+});
@@ -0,0 +1,93 @@
+# Checks the python bindings with the conformance test vectors generated by `make generate_test_vectors`,
+# the generated `zklink_sdk` module and its library must be in the `PYTHONPATH`.
+import json
+import os
+import unittest
+
+import zklink_sdk as sdk
+
+TEST_VECTORS_PATH = os.path.join(os.path.dirname(__file__), "..", "test_vectors", "vectors.json")
+TEST_VECTORS_VERSION = 2
+# only the auths without the data are used by the vectors
+CHANGE_PUBKEY_AUTHS = {
+    "Onchain": sdk.ChangePubKeyAuthRequest.ONCHAIN,
+    "EthECDSA": sdk.ChangePubKeyAuthRequest.ETH_ECDSA,
+}
+
+
+def load_test_vectors():
+    with open(TEST_VECTORS_PATH) as f:
+        return json.load(f)
+
+
+class TestVectors(unittest.TestCase):
+    def setUp(self):
+        self.vectors = load_test_vectors()
+        self.assertEqual(self.vectors["version"], TEST_VECTORS_VERSION)
+
+    def test_keys(self):
+        for key in self.vectors["keys"]:
+            if "starknetChainId" in key and "starknetAddr" in key:
+                zk_signer = sdk.ZkLinkSigner.new_from_hex_stark_signer(
+                    key["privateKey"], key["starknetAddr"], key["starknetChainId"]
+                )
+            else:
+                eth_signer = sdk.EthSigner(key["privateKey"])
+                self.assertEqual(eth_signer.get_address(), key["ethAddress"], key["name"])
+                zk_signer = sdk.ZkLinkSigner.new_from_hex_eth_signer(key["privateKey"])
+            pub_key = zk_signer.public_key()
+            self.assertEqual(pub_key, key["publicKey"], key["name"])
+            self.assertEqual(sdk.get_public_key_hash(pub_key), key["pubkeyHash"], key["name"])
+
+    def test_txs(self):
+        keys = {key["name"]: key for key in self.vectors["keys"]}
+        for vector in self.vectors["txs"]:
+            name = vector["name"]
+            tx = json.dumps(vector["tx"])
+            tx_bytes = bytes(sdk.get_zklink_tx_bytes(tx))
+            self.assertEqual("0x" + tx_bytes.hex(), vector["bytes"], name)
+            self.assertEqual(sdk.get_zklink_tx_hash(tx), vector["txHash"], name)
+            if "signer" not in vector:
+                continue
+
+            key = keys[vector["signer"]]
+            context = vector.get("context", {})
+            if "starknetChainId" in context and "starknetAddr" in context:
+                l1_type = sdk.L1SignerType.STARKNET(context["starknetChainId"], context["starknetAddr"])
+            else:
+                l1_type = sdk.L1SignerType.ETH()
+            signer = sdk.Signer(key["privateKey"], l1_type)
+            change_pubkey_auth = None
+            if "changePubkeyAuth" in context:
+                change_pubkey_auth = CHANGE_PUBKEY_AUTHS[context["changePubkeyAuth"]["type"]]()
+            ctx = sdk.SigningContext(
+                token_symbol=context.get("tokenSymbol"),
+                starknet_chain_id=context.get("starknetChainId"),
+                starknet_addr=context.get("starknetAddr"),
+                change_pubkey_auth=change_pubkey_auth,
+            )
+            tx_signature = signer.sign(tx, ctx)
+            signed_tx = json.loads(tx_signature.tx)
+            signature = signed_tx["signature"]
+            self.assertEqual(signature["pubKey"], vector["zkSignature"]["pubKey"], name)
+            # the signature of the tx json is the hex string without `0x`
+            self.assertEqual("0x" + signature["signature"], vector["zkSignature"]["signature"], name)
+
+            if "l1SignatureType" not in vector:
+                # the layer1 signature of `ChangePubKey` is the eth auth data of the signed tx
+                self.assertIsNone(tx_signature.layer1_signature, name)
+                if "l1Signature" in vector:
+                    self.assertEqual(signed_tx["ethAuthData"]["ethSignature"], vector["l1Signature"], name)
+            else:
+                l1_signature = json.loads(tx_signature.layer1_signature)
+                self.assertEqual(l1_signature["type"], vector["l1SignatureType"], name)
+                self.assertEqual(l1_signature["signature"], vector["l1Signature"], name)
+            if "l1Message" not in vector:
+                continue
+            eth_signer = sdk.EthSigner(key["privateKey"])
+            signature = eth_signer.sign_message(list(vector["l1Message"].encode()))
+            self.assertEqual(signature, vector["l1Signature"], name)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,176 @@
+package binding_tests
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	sdk "github.com/zkLinkProtocol/zklink_sdk/binding_tests/generated/uniffi/zklink_sdk"
+)
+
+// the conformance test vectors generated by `make generate_test_vectors`
+const testVectorsPath = "../test_vectors/vectors.json"
+const testVectorsVersion = 2
+
+// the eth address only exists for the ethereum keys, the starknet keys have the starknet account
+type keyVector struct {
+	Name            string  `json:"name"`
+	PrivateKey      string  `json:"privateKey"`
+	EthAddress      *string `json:"ethAddress"`
+	StarknetChainId *string `json:"starknetChainId"`
+	StarknetAddr    *string `json:"starknetAddr"`
+	PublicKey       string  `json:"publicKey"`
+	PubkeyHash      string  `json:"pubkeyHash"`
+}
+
+type zkSignatureVector struct {
+	PubKey    string `json:"pubKey"`
+	Signature string `json:"signature"`
+}
+
+type signingContextVector struct {
+	TokenSymbol     *string `json:"tokenSymbol"`
+	StarknetChainId *string `json:"starknetChainId"`
+	StarknetAddr    *string `json:"starknetAddr"`
+	// only the auths without the data are used by the vectors
+	ChangePubkeyAuth *struct {
+		Type string `json:"type"`
+	} `json:"changePubkeyAuth"`
+}
+
+type txVector struct {
+	Name            string                `json:"name"`
+	Signer          *string               `json:"signer"`
+	Context         *signingContextVector `json:"context"`
+	Tx              json.RawMessage       `json:"tx"`
+	Bytes           string                `json:"bytes"`
+	TxHash          string                `json:"txHash"`
+	ZkSignature     *zkSignatureVector    `json:"zkSignature"`
+	L1Message       *string               `json:"l1Message"`
+	L1SignatureType *string               `json:"l1SignatureType"`
+	L1Signature     *string               `json:"l1Signature"`
+}
+
+type testVectors struct {
+	Version uint32      `json:"version"`
+	Keys    []keyVector `json:"keys"`
+	Txs     []txVector  `json:"txs"`
+}
+
+func loadTestVectors(t *testing.T) testVectors {
+	data, err := os.ReadFile(testVectorsPath)
+	assert.Nil(t, err)
+	var vectors testVectors
+	err = json.Unmarshal(data, &vectors)
+	assert.Nil(t, err)
+	assert.Equal(t, uint32(testVectorsVersion), vectors.Version)
+	return vectors
+}
+
+func TestVectorsKeys(t *testing.T) {
+	vectors := loadTestVectors(t)
+	for _, key := range vectors.Keys {
+		var zkSigner *sdk.ZkLinkSigner
+		var err error
+		if key.StarknetChainId != nil && key.StarknetAddr != nil {
+			zkSigner, err = sdk.ZkLinkSignerNewFromHexStarkSigner(key.PrivateKey, *key.StarknetAddr, *key.StarknetChainId)
+			assert.Nil(t, err)
+		} else {
+			ethSigner, err := sdk.NewEthSigner(key.PrivateKey)
+			assert.Nil(t, err)
+			assert.Equal(t, *key.EthAddress, ethSigner.GetAddress(), key.Name)
+			zkSigner, err = sdk.ZkLinkSignerNewFromHexEthSigner(key.PrivateKey)
+			assert.Nil(t, err)
+		}
+		pubKey := zkSigner.PublicKey()
+		assert.Equal(t, key.PublicKey, pubKey, key.Name)
+		assert.Equal(t, key.PubkeyHash, sdk.GetPublicKeyHash(pubKey), key.Name)
+	}
+}
+
+func TestVectorsTxs(t *testing.T) {
+	vectors := loadTestVectors(t)
+	keys := make(map[string]keyVector)
+	for _, key := range vectors.Keys {
+		keys[key.Name] = key
+	}
+	for _, vector := range vectors.Txs {
+		tx := sdk.ZkLinkTx(vector.Tx)
+		bytes := sdk.GetZklinkTxBytes(tx)
+		assert.Equal(t, vector.Bytes, "0x"+hex.EncodeToString(bytes), vector.Name)
+		assert.Equal(t, vector.TxHash, sdk.GetZklinkTxHash(tx), vector.Name)
+		if vector.Signer == nil {
+			continue
+		}
+
+		key := keys[*vector.Signer]
+		var l1Type sdk.L1SignerType = sdk.L1SignerTypeEth{}
+		ctx := sdk.SigningContext{}
+		if vector.Context != nil {
+			ctx.TokenSymbol = vector.Context.TokenSymbol
+			ctx.StarknetChainId = vector.Context.StarknetChainId
+			ctx.StarknetAddr = vector.Context.StarknetAddr
+			if ctx.StarknetChainId != nil && ctx.StarknetAddr != nil {
+				l1Type = sdk.L1SignerTypeStarknet{ChainId: *ctx.StarknetChainId, Address: *ctx.StarknetAddr}
+			}
+			if vector.Context.ChangePubkeyAuth != nil {
+				var auth sdk.ChangePubKeyAuthRequest
+				switch vector.Context.ChangePubkeyAuth.Type {
+				case "Onchain":
+					auth = sdk.ChangePubKeyAuthRequestOnchain{}
+				case "EthECDSA":
+					auth = sdk.ChangePubKeyAuthRequestEthEcdsa{}
+				default:
+					t.Fatalf("unsupported change pubkey auth of %s", vector.Name)
+				}
+				ctx.ChangePubkeyAuth = &auth
+			}
+		}
+		signer, err := sdk.NewSigner(key.PrivateKey, l1Type)
+		assert.Nil(t, err)
+		txSignature, err := signer.Sign(tx, ctx)
+		assert.Nil(t, err, vector.Name)
+
+		var signedTx struct {
+			Signature zkSignatureVector `json:"signature"`
+			EthAuthData *struct {
+				EthSignature string `json:"ethSignature"`
+			} `json:"ethAuthData"`
+		}
+		err = json.Unmarshal([]byte(txSignature.Tx), &signedTx)
+		assert.Nil(t, err)
+		assert.Equal(t, vector.ZkSignature.PubKey, signedTx.Signature.PubKey, vector.Name)
+		// the signature of the tx json is the hex string without `0x`
+		assert.Equal(t, vector.ZkSignature.Signature, "0x"+signedTx.Signature.Signature, vector.Name)
+
+		if vector.L1SignatureType == nil {
+			// the layer1 signature of `ChangePubKey` is the eth auth data of the signed tx
+			assert.Nil(t, txSignature.Layer1Signature, vector.Name)
+			if vector.L1Signature != nil {
+				assert.NotNil(t, signedTx.EthAuthData, vector.Name)
+				assert.Equal(t, *vector.L1Signature, signedTx.EthAuthData.EthSignature, vector.Name)
+			}
+		} else {
+			var l1Signature struct {
+				Type      string `json:"type"`
+				Signature string `json:"signature"`
+			}
+			assert.NotNil(t, txSignature.Layer1Signature, vector.Name)
+			err = json.Unmarshal([]byte(*txSignature.Layer1Signature), &l1Signature)
+			assert.Nil(t, err)
+			assert.Equal(t, *vector.L1SignatureType, l1Signature.Type, vector.Name)
+			assert.Equal(t, *vector.L1Signature, l1Signature.Signature, vector.Name)
+		}
+		if vector.L1Message == nil {
+			continue
+		}
+
+		ethSigner, err := sdk.NewEthSigner(key.PrivateKey)
+		assert.Nil(t, err)
+		signature, err := ethSigner.SignMessage([]byte(*vector.L1Message))
+		assert.Nil(t, err)
+		assert.Equal(t, *vector.L1Signature, signature, vector.Name)
+	}
+}
@@ -0,0 +1,169 @@
+/* The C ABI of the zklink signing core. */
+
+#ifndef ZKLINK_SDK_H
+#define ZKLINK_SDK_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// The function succeeded.
+#define ZKLINK_OK 0
+
+// A required pointer argument is null.
+#define ZKLINK_NULL_POINTER 1
+
+// A string argument is not valid UTF-8.
+#define ZKLINK_INVALID_UTF8 2
+
+// A JSON argument can't be parsed into the expected type.
+#define ZKLINK_INVALID_JSON 3
+
+// The private key, public key or signature is invalid, or the signing failed.
+#define ZKLINK_SIGNER_ERROR 4
+
+// The rust code panicked, which is a bug of the sdk.
+#define ZKLINK_PANIC 5
+
+// The opaque handle of the ethereum layer1 signer.
+typedef struct EthSignerHandle EthSignerHandle;
+
+// The opaque handle of the zklink layer2 signer.
+typedef struct ZkLinkSignerHandle ZkLinkSignerHandle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Get the message of the last error of the current thread, null if the last call succeeded.
+//
+// The returned string is owned by the sdk and valid until the next call on the same thread,
+// it must not be freed.
+const char *zklink_last_error_message(void);
+
+// Create the zklink signer from the seed, which is at least 32 bytes.
+//
+// # Safety
+//
+// `seed` must point to `seed_len` readable bytes, `out` must be a valid pointer.
+int32_t zklink_signer_new_from_seed(const uint8_t *seed,
+                                    size_t seed_len,
+                                    ZkLinkSignerHandle **out);
+
+// Create the zklink signer from the hex private key of the ethereum account, which is the same
+// key as the one derived by the zklink dapp.
+//
+// # Safety
+//
+// `eth_private_key` must be a nul-terminated string, `out` must be a valid pointer.
+int32_t zklink_signer_new_from_hex_eth_signer(const char *eth_private_key,
+                                              ZkLinkSignerHandle **out);
+
+// Free the zklink signer, it's a no-op if the signer is null.
+//
+// # Safety
+//
+// `signer` must be null or a signer created by the sdk which is not freed yet.
+void zklink_signer_free(ZkLinkSignerHandle *signer);
+
+// Get the hex packed public key of the zklink signer.
+//
+// # Safety
+//
+// `signer` must be a signer created by the sdk, `out` must be a valid pointer.
+int32_t zklink_signer_public_key(const ZkLinkSignerHandle *signer, char **out);
+
+// Sign the message by the zklink signer, the signature is the json of `ZkLinkSignature`,
+// e.g. `{"pubKey":"0x...","signature":"..."}`.
+//
+// # Safety
+//
+// `signer` must be a signer created by the sdk, `msg` must point to `msg_len` readable bytes,
+// `out` must be a valid pointer.
+int32_t zklink_signer_sign_musig(const ZkLinkSignerHandle *signer,
+                                 const uint8_t *msg,
+                                 size_t msg_len,
+                                 char **out);
+
+// Verify the json `ZkLinkSignature` of the message.
+//
+// # Safety
+//
+// `signature` must be a nul-terminated string, `msg` must point to `msg_len` readable bytes,
+// `out` must be a valid pointer.
+int32_t zklink_verify_musig(const char *signature, const uint8_t *msg, size_t msg_len, bool *out);
+
+// Get the hex pubkey hash of the hex packed public key.
+//
+// # Safety
+//
+// `public_key` must be a nul-terminated string, `out` must be a valid pointer.
+int32_t zklink_public_key_hash(const char *public_key, char **out);
+
+// Create the ethereum signer from the hex private key.
+//
+// # Safety
+//
+// `private_key` must be a nul-terminated string, `out` must be a valid pointer.
+int32_t zklink_eth_signer_new(const char *private_key, EthSignerHandle **out);
+
+// Free the ethereum signer, it's a no-op if the signer is null.
+//
+// # Safety
+//
+// `signer` must be null or a signer created by the sdk which is not freed yet.
+void zklink_eth_signer_free(EthSignerHandle *signer);
+
+// Build the order from the json params and sign it, the result is the json of the signed
+// `Order`, which is used as the `taker` or `maker` of the `OrderMatching`.
+//
+// The params are `{"accountId","subAccountId","slotId","nonce","baseTokenId","quoteTokenId",
+// "amount","price","isSell","hasSubsidy","makerFeeRate","takerFeeRate"}`, the amount and the
+// price are decimal strings.
+//
+// # Safety
+//
+// `signer` must be a signer created by the sdk, `order_json` must be a nul-terminated string,
+// `out` must be a valid pointer.
+int32_t zklink_sign_order(const ZkLinkSignerHandle *signer, const char *order_json, char **out);
+
+// Sign the json `OrderMatching` by the submitter, the result is the json of `TxSignature`.
+//
+// # Safety
+//
+// `signer` must be a signer created by the sdk, `tx_json` must be a nul-terminated string,
+// `out` must be a valid pointer.
+int32_t zklink_sign_order_matching(const ZkLinkSignerHandle *signer,
+                                   const char *tx_json,
+                                   char **out);
+
+// Sign the json `Transfer`, the result is the json of `TxSignature`.
+//
+// The layer1 signature of the transfer is signed by `eth_signer` with `token_symbol` if the
+// `eth_signer` is not null.
+//
+// # Safety
+//
+// `signer` must be a signer created by the sdk, `eth_signer` must be null or a signer created by
+// the sdk, `tx_json` and `token_symbol` must be nul-terminated strings, `out` must be a valid
+// pointer.
+int32_t zklink_sign_transfer(const ZkLinkSignerHandle *signer,
+                             const EthSignerHandle *eth_signer,
+                             const char *tx_json,
+                             const char *token_symbol,
+                             char **out);
+
+// Free the string returned by the sdk, it's a no-op if the string is null.
+//
+// # Safety
+//
+// `s` must be null or a string returned by the sdk which is not freed yet.
+void zklink_string_free(char *s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* ZKLINK_SDK_H */
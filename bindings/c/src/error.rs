@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use thiserror::Error;
+use zklink_sdk_signers::eth_signer::error::EthSignerError;
+use zklink_sdk_signers::zklink_signer::error::ZkSignerError;
+
+/// The function succeeded.
+pub const ZKLINK_OK: i32 = 0;
+/// A required pointer argument is null.
+pub const ZKLINK_NULL_POINTER: i32 = 1;
+/// A string argument is not valid UTF-8.
+pub const ZKLINK_INVALID_UTF8: i32 = 2;
+/// A JSON argument can't be parsed into the expected type.
+pub const ZKLINK_INVALID_JSON: i32 = 3;
+/// The private key, public key or signature is invalid, or the signing failed.
+pub const ZKLINK_SIGNER_ERROR: i32 = 4;
+/// The rust code panicked, which is a bug of the sdk.
+pub const ZKLINK_PANIC: i32 = 5;
+
+#[derive(Debug, Error)]
+pub(crate) enum Error {
+    #[error("null pointer")]
+    NullPointer,
+    #[error("invalid utf8 string")]
+    InvalidUtf8,
+    #[error("invalid json: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    #[error("{0}")]
+    ZkSignerError(#[from] ZkSignerError),
+    #[error("{0}")]
+    EthSignerError(#[from] EthSignerError),
+    #[error("panicked")]
+    Panic,
+}
+
+impl Error {
+    fn code(&self) -> i32 {
+        match self {
+            Error::NullPointer => ZKLINK_NULL_POINTER,
+            Error::InvalidUtf8 => ZKLINK_INVALID_UTF8,
+            Error::InvalidJson(_) => ZKLINK_INVALID_JSON,
+            Error::ZkSignerError(_) | Error::EthSignerError(_) => ZKLINK_SIGNER_ERROR,
+            Error::Panic => ZKLINK_PANIC,
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Run the function body, the error is saved as the last error of the thread and converted to
+/// the error code, so is the panic, which must not unwind across the ffi boundary.
+pub(crate) fn ffi_call<F: FnOnce() -> Result<(), Error>>(f: F) -> i32 {
+    let result = catch_unwind(AssertUnwindSafe(f)).unwrap_or(Err(Error::Panic));
+    let (code, message) = match result {
+        Ok(()) => (ZKLINK_OK, None),
+        Err(e) => (e.code(), CString::new(e.to_string()).ok()),
+    };
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+    code
+}
+
+/// Get the message of the last error of the current thread, null if the last call succeeded.
+///
+/// The returned string is owned by the sdk and valid until the next call on the same thread,
+/// it must not be freed.
+#[no_mangle]
+pub extern "C" fn zklink_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
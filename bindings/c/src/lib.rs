@@ -0,0 +1,69 @@
+//! The C ABI of the zklink signing core, the header is generated to `include/zklink_sdk.h`.
+//!
+//! All the functions return one of the `ZKLINK_*` error codes, the message of the error can be got
+//! by `zklink_last_error_message`. The results are written to the `out` pointers only on success.
+//!
+//! The strings returned by the sdk must be freed by `zklink_string_free`, and the signers must be
+//! freed by `zklink_signer_free` and `zklink_eth_signer_free`.
+mod error;
+mod signer;
+mod tx;
+
+pub use error::*;
+pub use signer::*;
+pub use tx::*;
+
+use crate::error::Error;
+use std::ffi::{c_char, CStr, CString};
+use std::slice;
+
+/// Free the string returned by the sdk, it's a no-op if the string is null.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by the sdk which is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+pub(crate) unsafe fn str_arg<'a>(s: *const c_char) -> Result<&'a str, Error> {
+    if s.is_null() {
+        return Err(Error::NullPointer);
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| Error::InvalidUtf8)
+}
+
+pub(crate) unsafe fn bytes_arg<'a>(data: *const u8, len: usize) -> Result<&'a [u8], Error> {
+    if data.is_null() {
+        return if len == 0 {
+            Ok(&[])
+        } else {
+            Err(Error::NullPointer)
+        };
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+pub(crate) unsafe fn ref_arg<'a, T>(ptr: *const T) -> Result<&'a T, Error> {
+    ptr.as_ref().ok_or(Error::NullPointer)
+}
+
+pub(crate) unsafe fn write_out<T>(out: *mut T, value: T) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::NullPointer);
+    }
+    out.write(value);
+    Ok(())
+}
+
+pub(crate) unsafe fn write_string(out: *mut *mut c_char, s: String) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::NullPointer);
+    }
+    let s = CString::new(s).map_err(|_| Error::InvalidUtf8)?;
+    out.write(s.into_raw());
+    Ok(())
+}
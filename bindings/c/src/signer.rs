@@ -0,0 +1,225 @@
+use crate::error::ffi_call;
+use crate::{bytes_arg, ref_arg, str_arg, write_out, write_string};
+use std::ffi::c_char;
+use zklink_sdk_signers::eth_signer::EthSigner;
+use zklink_sdk_signers::zklink_signer::public_key::PackedPublicKey;
+use zklink_sdk_signers::zklink_signer::signature::ZkLinkSignature;
+use zklink_sdk_signers::zklink_signer::ZkLinkSigner;
+
+/// The opaque handle of the zklink layer2 signer.
+pub struct ZkLinkSignerHandle(pub(crate) ZkLinkSigner);
+
+/// The opaque handle of the ethereum layer1 signer.
+pub struct EthSignerHandle(pub(crate) EthSigner);
+
+/// Create the zklink signer from the seed, which is at least 32 bytes.
+///
+/// # Safety
+///
+/// `seed` must point to `seed_len` readable bytes, `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_signer_new_from_seed(
+    seed: *const u8,
+    seed_len: usize,
+    out: *mut *mut ZkLinkSignerHandle,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ZkLinkSigner::new_from_seed(bytes_arg(seed, seed_len)?)?;
+        write_out(out, Box::into_raw(Box::new(ZkLinkSignerHandle(signer))))
+    })
+}
+
+/// Create the zklink signer from the hex private key of the ethereum account, which is the same
+/// key as the one derived by the zklink dapp.
+///
+/// # Safety
+///
+/// `eth_private_key` must be a nul-terminated string, `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_signer_new_from_hex_eth_signer(
+    eth_private_key: *const c_char,
+    out: *mut *mut ZkLinkSignerHandle,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ZkLinkSigner::new_from_hex_eth_signer(str_arg(eth_private_key)?)?;
+        write_out(out, Box::into_raw(Box::new(ZkLinkSignerHandle(signer))))
+    })
+}
+
+/// Free the zklink signer, it's a no-op if the signer is null.
+///
+/// # Safety
+///
+/// `signer` must be null or a signer created by the sdk which is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_signer_free(signer: *mut ZkLinkSignerHandle) {
+    if !signer.is_null() {
+        drop(Box::from_raw(signer));
+    }
+}
+
+/// Get the hex packed public key of the zklink signer.
+///
+/// # Safety
+///
+/// `signer` must be a signer created by the sdk, `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_signer_public_key(
+    signer: *const ZkLinkSignerHandle,
+    out: *mut *mut c_char,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ref_arg(signer)?;
+        write_string(out, signer.0.public_key().as_hex())
+    })
+}
+
+/// Sign the message by the zklink signer, the signature is the json of `ZkLinkSignature`,
+/// e.g. `{"pubKey":"0x...","signature":"..."}`.
+///
+/// # Safety
+///
+/// `signer` must be a signer created by the sdk, `msg` must point to `msg_len` readable bytes,
+/// `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_signer_sign_musig(
+    signer: *const ZkLinkSignerHandle,
+    msg: *const u8,
+    msg_len: usize,
+    out: *mut *mut c_char,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ref_arg(signer)?;
+        let signature = signer.0.sign_musig(bytes_arg(msg, msg_len)?)?;
+        write_string(out, serde_json::to_string(&signature)?)
+    })
+}
+
+/// Verify the json `ZkLinkSignature` of the message.
+///
+/// # Safety
+///
+/// `signature` must be a nul-terminated string, `msg` must point to `msg_len` readable bytes,
+/// `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_verify_musig(
+    signature: *const c_char,
+    msg: *const u8,
+    msg_len: usize,
+    out: *mut bool,
+) -> i32 {
+    ffi_call(|| {
+        let signature: ZkLinkSignature = serde_json::from_str(str_arg(signature)?)?;
+        let valid = signature.verify_musig(bytes_arg(msg, msg_len)?);
+        write_out(out, valid)
+    })
+}
+
+/// Get the hex pubkey hash of the hex packed public key.
+///
+/// # Safety
+///
+/// `public_key` must be a nul-terminated string, `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_public_key_hash(
+    public_key: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    ffi_call(|| {
+        let public_key = PackedPublicKey::from_hex(str_arg(public_key)?)?;
+        write_string(out, public_key.public_key_hash().as_hex())
+    })
+}
+
+/// Create the ethereum signer from the hex private key.
+///
+/// # Safety
+///
+/// `private_key` must be a nul-terminated string, `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_eth_signer_new(
+    private_key: *const c_char,
+    out: *mut *mut EthSignerHandle,
+) -> i32 {
+    ffi_call(|| {
+        let signer = EthSigner::try_from(str_arg(private_key)?)?;
+        write_out(out, Box::into_raw(Box::new(EthSignerHandle(signer))))
+    })
+}
+
+/// Free the ethereum signer, it's a no-op if the signer is null.
+///
+/// # Safety
+///
+/// `signer` must be null or a signer created by the sdk which is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_eth_signer_free(signer: *mut EthSignerHandle) {
+    if !signer.is_null() {
+        drop(Box::from_raw(signer));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{ZKLINK_NULL_POINTER, ZKLINK_OK, ZKLINK_SIGNER_ERROR};
+    use crate::zklink_string_free;
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    #[test]
+    fn test_sign_musig() {
+        let private_key =
+            CString::new("be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4")
+                .unwrap();
+        unsafe {
+            let mut signer = ptr::null_mut();
+            let code = zklink_signer_new_from_hex_eth_signer(private_key.as_ptr(), &mut signer);
+            assert_eq!(code, ZKLINK_OK);
+
+            let mut public_key = ptr::null_mut();
+            assert_eq!(zklink_signer_public_key(signer, &mut public_key), ZKLINK_OK);
+            assert_eq!(
+                CStr::from_ptr(public_key).to_str().unwrap(),
+                "0x7b173e25e484eed3461091430f81b2a5bd7ae792f69701dcb073cb903f812510"
+            );
+            let mut pubkey_hash = ptr::null_mut();
+            assert_eq!(
+                zklink_public_key_hash(public_key, &mut pubkey_hash),
+                ZKLINK_OK
+            );
+            assert_eq!(
+                CStr::from_ptr(pubkey_hash).to_str().unwrap(),
+                "0xd8d5fb6a6caef06aa3dc2abdcdc240987e5330fe"
+            );
+
+            let msg = [0u8, 1, 2, 3, 4, 5, 6];
+            let mut signature = ptr::null_mut();
+            let code = zklink_signer_sign_musig(signer, msg.as_ptr(), msg.len(), &mut signature);
+            assert_eq!(code, ZKLINK_OK);
+            let mut valid = false;
+            let code = zklink_verify_musig(signature, msg.as_ptr(), msg.len(), &mut valid);
+            assert_eq!(code, ZKLINK_OK);
+            assert!(valid);
+
+            zklink_string_free(public_key);
+            zklink_string_free(pubkey_hash);
+            zklink_string_free(signature);
+            zklink_signer_free(signer);
+        }
+    }
+
+    #[test]
+    fn test_error_code() {
+        let invalid_key = CString::new("0x1234").unwrap();
+        unsafe {
+            let mut signer = ptr::null_mut();
+            let code = zklink_signer_new_from_hex_eth_signer(ptr::null(), &mut signer);
+            assert_eq!(code, ZKLINK_NULL_POINTER);
+            let code = zklink_signer_new_from_hex_eth_signer(invalid_key.as_ptr(), &mut signer);
+            assert_eq!(code, ZKLINK_SIGNER_ERROR);
+            assert!(signer.is_null());
+            assert!(!crate::zklink_last_error_message().is_null());
+        }
+    }
+}
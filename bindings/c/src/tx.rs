@@ -0,0 +1,131 @@
+use crate::error::ffi_call;
+use crate::signer::{EthSignerHandle, ZkLinkSignerHandle};
+use crate::{ref_arg, str_arg, write_string};
+use serde::Deserialize;
+use std::ffi::c_char;
+use zklink_sdk_types::basic_types::{AccountId, BigUint, Nonce, SlotId, SubAccountId, TokenId};
+use zklink_sdk_types::prelude::{Order, OrderMatching, Transfer, TxLayer1Signature, TxSignature};
+use zklink_sdk_types::tx_type::ZkSignatureTrait;
+use zklink_sdk_utils::serde::BigUintSerdeAsRadix10Str;
+
+/// The params of `Order::new`, the order is signed by the account which places it.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderParams {
+    account_id: AccountId,
+    sub_account_id: SubAccountId,
+    slot_id: SlotId,
+    nonce: Nonce,
+    base_token_id: TokenId,
+    quote_token_id: TokenId,
+    #[serde(with = "BigUintSerdeAsRadix10Str")]
+    amount: BigUint,
+    #[serde(with = "BigUintSerdeAsRadix10Str")]
+    price: BigUint,
+    is_sell: bool,
+    has_subsidy: bool,
+    maker_fee_rate: u8,
+    taker_fee_rate: u8,
+}
+
+/// Build the order from the json params and sign it, the result is the json of the signed
+/// `Order`, which is used as the `taker` or `maker` of the `OrderMatching`.
+///
+/// The params are `{"accountId","subAccountId","slotId","nonce","baseTokenId","quoteTokenId",
+/// "amount","price","isSell","hasSubsidy","makerFeeRate","takerFeeRate"}`, the amount and the
+/// price are decimal strings.
+///
+/// # Safety
+///
+/// `signer` must be a signer created by the sdk, `order_json` must be a nul-terminated string,
+/// `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_sign_order(
+    signer: *const ZkLinkSignerHandle,
+    order_json: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ref_arg(signer)?;
+        let params: OrderParams = serde_json::from_str(str_arg(order_json)?)?;
+        let mut order = Order::new(
+            params.account_id,
+            params.sub_account_id,
+            params.slot_id,
+            params.nonce,
+            params.base_token_id,
+            params.quote_token_id,
+            params.amount,
+            params.price,
+            params.is_sell,
+            params.has_subsidy,
+            params.maker_fee_rate,
+            params.taker_fee_rate,
+            None,
+        );
+        order.sign(&signer.0)?;
+        write_string(out, serde_json::to_string(&order)?)
+    })
+}
+
+/// Sign the json `OrderMatching` by the submitter, the result is the json of `TxSignature`.
+///
+/// # Safety
+///
+/// `signer` must be a signer created by the sdk, `tx_json` must be a nul-terminated string,
+/// `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_sign_order_matching(
+    signer: *const ZkLinkSignerHandle,
+    tx_json: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ref_arg(signer)?;
+        let mut tx: OrderMatching = serde_json::from_str(str_arg(tx_json)?)?;
+        tx.sign(&signer.0)?;
+        let tx_signature = TxSignature {
+            tx: tx.into(),
+            layer1_signature: None,
+        };
+        write_string(out, serde_json::to_string(&tx_signature)?)
+    })
+}
+
+/// Sign the json `Transfer`, the result is the json of `TxSignature`.
+///
+/// The layer1 signature of the transfer is signed by `eth_signer` with `token_symbol` if the
+/// `eth_signer` is not null.
+///
+/// # Safety
+///
+/// `signer` must be a signer created by the sdk, `eth_signer` must be null or a signer created by
+/// the sdk, `tx_json` and `token_symbol` must be nul-terminated strings, `out` must be a valid
+/// pointer.
+#[no_mangle]
+pub unsafe extern "C" fn zklink_sign_transfer(
+    signer: *const ZkLinkSignerHandle,
+    eth_signer: *const EthSignerHandle,
+    tx_json: *const c_char,
+    token_symbol: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    ffi_call(|| {
+        let signer = ref_arg(signer)?;
+        let mut tx: Transfer = serde_json::from_str(str_arg(tx_json)?)?;
+        tx.sign(&signer.0)?;
+        let layer1_signature = match eth_signer.as_ref() {
+            Some(eth_signer) => {
+                let message = tx.get_eth_sign_msg(str_arg(token_symbol)?);
+                let signature = eth_signer.0.sign_message(message.as_bytes())?;
+                Some(TxLayer1Signature::from(signature))
+            }
+            None => None,
+        };
+        let tx_signature = TxSignature {
+            tx: tx.into(),
+            layer1_signature,
+        };
+        write_string(out, serde_json::to_string(&tx_signature)?)
+    })
+}
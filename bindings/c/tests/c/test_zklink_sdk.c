@@ -0,0 +1,150 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "zklink_sdk.h"
+
+#define CHECK(call)                                                                 \
+    do {                                                                            \
+        int32_t code = (call);                                                      \
+        if (code != ZKLINK_OK) {                                                    \
+            const char *message = zklink_last_error_message();                      \
+            fprintf(stderr, "%s:%d: %s failed with %d: %s\n", __FILE__, __LINE__,   \
+                    #call, code, message ? message : "");                           \
+            exit(1);                                                                \
+        }                                                                           \
+    } while (0)
+
+#define ASSERT(cond)                                                                \
+    do {                                                                            \
+        if (!(cond)) {                                                              \
+            fprintf(stderr, "%s:%d: assertion failed: %s\n", __FILE__, __LINE__,    \
+                    #cond);                                                         \
+            exit(1);                                                                \
+        }                                                                           \
+    } while (0)
+
+static const char *PRIVATE_KEY =
+    "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+
+static void test_signer(void) {
+    ZkLinkSignerHandle *signer = NULL;
+    CHECK(zklink_signer_new_from_hex_eth_signer(PRIVATE_KEY, &signer));
+
+    char *public_key = NULL;
+    CHECK(zklink_signer_public_key(signer, &public_key));
+    ASSERT(strcmp(public_key,
+                  "0x7b173e25e484eed3461091430f81b2a5bd7ae792f69701dcb073cb903f812510") == 0);
+
+    char *pubkey_hash = NULL;
+    CHECK(zklink_public_key_hash(public_key, &pubkey_hash));
+    ASSERT(strcmp(pubkey_hash, "0xd8d5fb6a6caef06aa3dc2abdcdc240987e5330fe") == 0);
+
+    const char *msg = "hello world";
+    char *signature = NULL;
+    CHECK(zklink_signer_sign_musig(signer, (const uint8_t *)msg, strlen(msg), &signature));
+    bool valid = false;
+    CHECK(zklink_verify_musig(signature, (const uint8_t *)msg, strlen(msg), &valid));
+    ASSERT(valid);
+    CHECK(zklink_verify_musig(signature, (const uint8_t *)"hello", 5, &valid));
+    ASSERT(!valid);
+
+    zklink_string_free(signature);
+    zklink_string_free(pubkey_hash);
+    zklink_string_free(public_key);
+    zklink_signer_free(signer);
+}
+
+static void test_error_code(void) {
+    ZkLinkSignerHandle *signer = NULL;
+    ASSERT(zklink_signer_new_from_hex_eth_signer(NULL, &signer) == ZKLINK_NULL_POINTER);
+    ASSERT(zklink_last_error_message() != NULL);
+    ASSERT(zklink_signer_new_from_hex_eth_signer("0x1234", &signer) == ZKLINK_SIGNER_ERROR);
+    ASSERT(signer == NULL);
+
+    CHECK(zklink_signer_new_from_hex_eth_signer(PRIVATE_KEY, &signer));
+    ASSERT(zklink_last_error_message() == NULL);
+    char *out = NULL;
+    ASSERT(zklink_sign_order(signer, "{\"accountId\":1}", &out) == ZKLINK_INVALID_JSON);
+    ASSERT(out == NULL);
+    zklink_signer_free(signer);
+
+    // freeing null is a no-op
+    zklink_signer_free(NULL);
+    zklink_eth_signer_free(NULL);
+    zklink_string_free(NULL);
+}
+
+static void test_sign_order_matching(void) {
+    ZkLinkSignerHandle *signer = NULL;
+    CHECK(zklink_signer_new_from_hex_eth_signer(PRIVATE_KEY, &signer));
+
+    char *taker = NULL;
+    CHECK(zklink_sign_order(signer,
+                            "{\"accountId\":10,\"subAccountId\":1,\"slotId\":1,\"nonce\":1,"
+                            "\"baseTokenId\":18,\"quoteTokenId\":145,\"amount\":\"10000\","
+                            "\"price\":\"5000\",\"isSell\":false,\"hasSubsidy\":false,"
+                            "\"makerFeeRate\":5,\"takerFeeRate\":10}",
+                            &taker));
+    char *maker = NULL;
+    CHECK(zklink_sign_order(signer,
+                            "{\"accountId\":20,\"subAccountId\":1,\"slotId\":1,\"nonce\":1,"
+                            "\"baseTokenId\":18,\"quoteTokenId\":145,\"amount\":\"10000\","
+                            "\"price\":\"5000\",\"isSell\":true,\"hasSubsidy\":false,"
+                            "\"makerFeeRate\":5,\"takerFeeRate\":10}",
+                            &maker));
+
+    const char *format =
+        "{\"accountId\":3,\"subAccountId\":1,\"taker\":%s,\"maker\":%s,"
+        "\"oraclePrices\":{\"contractPrices\":[],\"marginPrices\":[]},"
+        "\"fee\":\"1000\",\"feeToken\":18,\"expectBaseAmount\":\"10000\","
+        "\"expectQuoteAmount\":\"5000\"}";
+    size_t len = strlen(format) + strlen(taker) + strlen(maker);
+    char *tx = malloc(len);
+    ASSERT(tx != NULL);
+    snprintf(tx, len, format, taker, maker);
+
+    char *tx_signature = NULL;
+    CHECK(zklink_sign_order_matching(signer, tx, &tx_signature));
+    ASSERT(strstr(tx_signature, "\"type\":\"OrderMatching\"") != NULL);
+    ASSERT(strstr(tx_signature, "\"layer1_signature\":null") != NULL);
+
+    zklink_string_free(tx_signature);
+    free(tx);
+    zklink_string_free(maker);
+    zklink_string_free(taker);
+    zklink_signer_free(signer);
+}
+
+static void test_sign_transfer(void) {
+    ZkLinkSignerHandle *signer = NULL;
+    CHECK(zklink_signer_new_from_hex_eth_signer(PRIVATE_KEY, &signer));
+    EthSignerHandle *eth_signer = NULL;
+    CHECK(zklink_eth_signer_new(PRIVATE_KEY, &eth_signer));
+
+    const char *tx = "{\"accountId\":10,\"fromSubAccountId\":1,\"toSubAccountId\":1,"
+                     "\"to\":\"0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9\",\"token\":18,"
+                     "\"amount\":\"100000\",\"fee\":\"3\",\"nonce\":1,\"ts\":1646101085}";
+    char *tx_signature = NULL;
+    CHECK(zklink_sign_transfer(signer, eth_signer, tx, "USDC", &tx_signature));
+    ASSERT(strstr(tx_signature, "\"type\":\"Transfer\"") != NULL);
+    ASSERT(strstr(tx_signature, "\"layer1_signature\":null") == NULL);
+    zklink_string_free(tx_signature);
+
+    // the layer1 signature is not signed without the eth signer
+    CHECK(zklink_sign_transfer(signer, NULL, tx, NULL, &tx_signature));
+    ASSERT(strstr(tx_signature, "\"layer1_signature\":null") != NULL);
+    zklink_string_free(tx_signature);
+
+    zklink_eth_signer_free(eth_signer);
+    zklink_signer_free(signer);
+}
+
+int main(void) {
+    test_signer();
+    test_error_code();
+    test_sign_order_matching();
+    test_sign_transfer();
+    printf("all tests passed\n");
+    return 0;
+}
@@ -0,0 +1,41 @@
+//! Compile `tests/c/test_zklink_sdk.c` against the generated header and the cdylib of the crate,
+//! then run it. The C compiler is `cc` by default, which can be changed by the `CC` env.
+#![cfg(unix)]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn test_c_api() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // the cdylib is built into the same `deps` dir as the test binary
+    let lib_dir = env::current_exe()
+        .unwrap()
+        .parent()
+        .expect("the dir of the test binary")
+        .to_path_buf();
+    let exe = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("test_zklink_sdk");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(cc)
+        .arg(manifest_dir.join("tests/c/test_zklink_sdk.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-lzklink_sdk_c")
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .expect("failed to run the c compiler");
+    assert!(status.success(), "failed to compile the c tests");
+
+    let output = Command::new(&exe).output().unwrap();
+    assert!(
+        output.status.success(),
+        "the c tests failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
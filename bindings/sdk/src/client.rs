@@ -0,0 +1,480 @@
+use crate::rpc::{AccountQueryParam, ZkLinkRpcClient};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+use zklink_sdk_interface::error::SignError;
+use zklink_sdk_interface::signer::{L1SignerType, Signer};
+use zklink_sdk_interface::{ChangePubKeyAuthRequest, SigningContext};
+use zklink_sdk_provider::error::RpcError;
+use zklink_sdk_provider::network::Network;
+use zklink_sdk_provider::response::AccountInfoResp;
+use zklink_sdk_signers::eth_signer::pk_signer::EthSigner;
+use zklink_sdk_types::basic_types::tx_hash::TxHash;
+use zklink_sdk_types::basic_types::{
+    AccountId, BigUint, ChainId, Nonce, SubAccountId, TimeStamp, TokenId, ZkLinkAddress,
+};
+use zklink_sdk_types::prelude::{
+    ChangePubKeyBuilder, ContractPrice, Order, OrderMatchingBuilder, SpotPriceInfo,
+    TransferBuilder, TxSignature, WithdrawBuilder, ZkLinkTx,
+};
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("{0}")]
+    RpcError(#[from] RpcError),
+    #[error("{0}")]
+    SignError(#[from] SignError),
+    #[error("Invalid layer1 address")]
+    InvalidAddress,
+    #[error("Token {0} not found")]
+    TokenNotFound(TokenId),
+}
+
+/// The high level client of a zklink account, which signs the txs by the `Signer` and submits
+/// them by the rpc client.
+///
+/// The account nonce is tracked locally after it's fetched when the client is created, and
+/// refetched on the next tx if a submission fails. The token symbols required by the layer1
+/// signatures are loaded from the server when they are first used.
+pub struct ZkLinkClient {
+    signer: Signer,
+    rpc: ZkLinkRpcClient,
+    account_id: AccountId,
+    starknet_chain_id: Option<String>,
+    starknet_addr: Option<String>,
+    nonce: Mutex<Option<Nonce>>,
+    token_symbols: Mutex<HashMap<TokenId, String>>,
+}
+
+impl ZkLinkClient {
+    pub fn new(
+        private_key: String,
+        l1_signer_type: L1SignerType,
+        network: Network,
+        timeout_secs: Option<u64>,
+    ) -> Result<Self, ClientError> {
+        let rpc = ZkLinkRpcClient::new(network, timeout_secs)?;
+        Self::new_with_rpc(&private_key, l1_signer_type, rpc)
+    }
+
+    pub fn new_with_url(
+        private_key: String,
+        l1_signer_type: L1SignerType,
+        url: String,
+        timeout_secs: Option<u64>,
+    ) -> Result<Self, ClientError> {
+        let rpc = ZkLinkRpcClient::new_with_url(url, timeout_secs)?;
+        Self::new_with_rpc(&private_key, l1_signer_type, rpc)
+    }
+
+    fn new_with_rpc(
+        private_key: &str,
+        l1_signer_type: L1SignerType,
+        rpc: ZkLinkRpcClient,
+    ) -> Result<Self, ClientError> {
+        let (starknet_chain_id, starknet_addr) = match &l1_signer_type {
+            L1SignerType::Eth => (None, None),
+            L1SignerType::Starknet { chain_id, address } => {
+                (Some(chain_id.clone()), Some(address.clone()))
+            }
+        };
+        let signer = Signer::new(private_key, l1_signer_type)?;
+        let address = match &starknet_addr {
+            Some(address) => {
+                ZkLinkAddress::from_hex(address).map_err(|_| ClientError::InvalidAddress)?
+            }
+            None => {
+                let eth_signer = EthSigner::try_from(private_key).map_err(SignError::from)?;
+                ZkLinkAddress::from(eth_signer.get_address().to_fixed_bytes())
+            }
+        };
+        let account = rpc.account_info(AccountQueryParam::Address { address })?;
+        Ok(Self {
+            signer,
+            rpc,
+            account_id: account.id,
+            starknet_chain_id,
+            starknet_addr,
+            nonce: Mutex::new(Some(account.nonce)),
+            token_symbols: Mutex::default(),
+        })
+    }
+
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    pub fn account_info(&self) -> Result<AccountInfoResp, ClientError> {
+        let account = self.rpc.account_info(AccountQueryParam::Id {
+            id: self.account_id,
+        })?;
+        Ok(account)
+    }
+
+    pub fn transfer(
+        &self,
+        from_sub_account_id: SubAccountId,
+        to_address: ZkLinkAddress,
+        to_sub_account_id: SubAccountId,
+        token: TokenId,
+        amount: BigUint,
+        fee: BigUint,
+    ) -> Result<TxHash, ClientError> {
+        let ctx = self.signing_context(Some(token))?;
+        self.submit_with_nonce(|nonce| {
+            let tx = TransferBuilder {
+                account_id: self.account_id,
+                to_address,
+                from_sub_account_id,
+                to_sub_account_id,
+                token,
+                amount,
+                fee,
+                nonce,
+                timestamp: now(),
+            }
+            .build();
+            (tx.into(), ctx)
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn withdraw(
+        &self,
+        sub_account_id: SubAccountId,
+        to_chain_id: ChainId,
+        to_address: ZkLinkAddress,
+        l2_source_token: TokenId,
+        l1_target_token: TokenId,
+        amount: BigUint,
+        fee: BigUint,
+        withdraw_to_l1: bool,
+        withdraw_fee_ratio: u16,
+    ) -> Result<TxHash, ClientError> {
+        let ctx = self.signing_context(Some(l2_source_token))?;
+        self.submit_with_nonce(|nonce| {
+            let tx = WithdrawBuilder {
+                account_id: self.account_id,
+                sub_account_id,
+                to_chain_id,
+                to_address,
+                l2_source_token,
+                l1_target_token,
+                amount,
+                data_hash: None,
+                fee,
+                nonce,
+                withdraw_to_l1,
+                withdraw_fee_ratio,
+                timestamp: now(),
+            }
+            .build();
+            (tx.into(), ctx)
+        })
+    }
+
+    /// Set the public key of the signer as the new public key of the account.
+    pub fn change_pubkey(
+        &self,
+        sub_account_id: SubAccountId,
+        fee_token: TokenId,
+        fee: BigUint,
+        auth: ChangePubKeyAuthRequest,
+    ) -> Result<TxHash, ClientError> {
+        let chain_id = self.rpc.get_change_pubkey_chain_id()?;
+        let ctx = SigningContext {
+            change_pubkey_auth: Some(auth),
+            ..self.signing_context(None)?
+        };
+        self.submit_with_nonce(|nonce| {
+            let tx = ChangePubKeyBuilder {
+                chain_id,
+                account_id: self.account_id,
+                sub_account_id,
+                new_pubkey_hash: self.signer.pubkey_hash(),
+                fee_token,
+                fee,
+                nonce,
+                eth_signature: None,
+                timestamp: now(),
+            }
+            .build();
+            (tx.into(), ctx)
+        })
+    }
+
+    /// Submit the matching of the orders signed by the taker and the maker, the client is the
+    /// submitter.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_order_matching(
+        &self,
+        sub_account_id: SubAccountId,
+        taker: Arc<Order>,
+        maker: Arc<Order>,
+        fee: BigUint,
+        fee_token: TokenId,
+        contract_prices: Vec<ContractPrice>,
+        margin_prices: Vec<SpotPriceInfo>,
+        expect_base_amount: BigUint,
+        expect_quote_amount: BigUint,
+    ) -> Result<TxHash, ClientError> {
+        let tx = OrderMatchingBuilder {
+            account_id: self.account_id,
+            sub_account_id,
+            contract_prices,
+            margin_prices,
+            taker,
+            maker,
+            fee,
+            fee_token,
+            expect_base_amount,
+            expect_quote_amount,
+        }
+        .build();
+        self.submit(tx.into(), self.signing_context(None)?)
+    }
+
+    /// Build the tx with the current nonce of the account and submit it, the nonce is increased
+    /// if the tx is submitted, otherwise it's refetched by the next tx.
+    fn submit_with_nonce<F>(&self, build_tx: F) -> Result<TxHash, ClientError>
+    where
+        F: FnOnce(Nonce) -> (ZkLinkTx, SigningContext),
+    {
+        let mut nonce = self.nonce.lock().unwrap();
+        let current = match *nonce {
+            Some(nonce) => nonce,
+            None => self.account_info()?.nonce,
+        };
+        let (tx, ctx) = build_tx(current);
+        let result = self.submit(tx, ctx);
+        *nonce = result.is_ok().then_some(Nonce(current.0 + 1));
+        result
+    }
+
+    fn submit(&self, tx: ZkLinkTx, ctx: SigningContext) -> Result<TxHash, ClientError> {
+        let TxSignature {
+            tx,
+            layer1_signature,
+        } = self.signer.sign(tx, ctx)?;
+        let tx_hash = self.rpc.tx_submit(tx, layer1_signature, None)?;
+        Ok(tx_hash)
+    }
+
+    /// The signing context of the account, with the symbol of `token` if it's required by the
+    /// layer1 signature.
+    fn signing_context(&self, token: Option<TokenId>) -> Result<SigningContext, ClientError> {
+        let token_symbol = token.map(|token| self.token_symbol(token)).transpose()?;
+        Ok(SigningContext {
+            token_symbol,
+            starknet_chain_id: self.starknet_chain_id.clone(),
+            starknet_addr: self.starknet_addr.clone(),
+            change_pubkey_auth: None,
+        })
+    }
+
+    fn token_symbol(&self, token: TokenId) -> Result<String, ClientError> {
+        let mut token_symbols = self.token_symbols.lock().unwrap();
+        if !token_symbols.contains_key(&token) {
+            // the new tokens may be listed since the last loading
+            *token_symbols = self
+                .rpc
+                .tokens()?
+                .into_iter()
+                .map(|(id, token)| (id, token.symbol))
+                .collect();
+        }
+        token_symbols
+            .get(&token)
+            .cloned()
+            .ok_or(ClientError::TokenNotFound(token))
+    }
+}
+
+fn now() -> TimeStamp {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("the system time is before the unix epoch")
+        .as_secs();
+    TimeStamp(secs as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use jsonrpsee::server::{RpcModule, Server, ServerHandle};
+    use jsonrpsee::types::ErrorObjectOwned;
+    use serde_json::{json, Value};
+    use tokio::runtime::Runtime;
+
+    const PRIVATE_KEY: &str = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+
+    /// The state of the mock zklink server.
+    #[derive(Default)]
+    struct MockState {
+        nonce: u32,
+        tokens: Vec<(u32, &'static str)>,
+        fail_submission: bool,
+        account_requests: usize,
+        token_requests: usize,
+        /// The nonces of the submitted txs
+        submitted: Vec<u32>,
+    }
+
+    struct MockServer {
+        state: Arc<Mutex<MockState>>,
+        url: String,
+        _handle: ServerHandle,
+        // the server runs on the runtime, the client blocks on its own runtime
+        _runtime: Runtime,
+    }
+
+    fn mock_server(state: MockState) -> MockServer {
+        let state = Arc::new(Mutex::new(state));
+        let mut module = RpcModule::new(state.clone());
+        module
+            .register_method("getAccount", |_, state| {
+                let mut state = state.lock().unwrap();
+                state.account_requests += 1;
+                Ok::<_, ErrorObjectOwned>(json!({
+                    "id": 1,
+                    "address": "0xafaff3ad1a0425d792432d9ecd1c3e26ef2c42e9",
+                    "nonce": state.nonce,
+                    "pubKeyHash": "0x0000000000000000000000000000000000000000",
+                    "subAccountNonces": {}
+                }))
+            })
+            .unwrap();
+        module
+            .register_method("getSupportTokens", |_, state| {
+                let mut state = state.lock().unwrap();
+                state.token_requests += 1;
+                let tokens: serde_json::Map<String, Value> = state
+                    .tokens
+                    .iter()
+                    .map(|(id, symbol)| {
+                        let token =
+                            json!({"id": id, "symbol": symbol, "usdPrice": "1", "chains": {}});
+                        (id.to_string(), token)
+                    })
+                    .collect();
+                Ok::<_, ErrorObjectOwned>(Value::Object(tokens))
+            })
+            .unwrap();
+        module
+            .register_method("sendTransaction", |params, state| {
+                let params: Vec<Value> = params.parse()?;
+                let mut state = state.lock().unwrap();
+                if state.fail_submission {
+                    return Err(ErrorObjectOwned::owned(-32000, "invalid nonce", None::<()>));
+                }
+                state
+                    .submitted
+                    .push(params[0]["nonce"].as_u64().unwrap() as u32);
+                Ok(json!(
+                    "0x0cbeabac1a2257fb095c2465e148570e32793345442b39bf64cad4ed87475f9b"
+                ))
+            })
+            .unwrap();
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let (url, handle) = runtime.block_on(async {
+            let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", server.local_addr().unwrap());
+            (url, server.start(module))
+        });
+        MockServer {
+            state,
+            url,
+            _handle: handle,
+            _runtime: runtime,
+        }
+    }
+
+    fn client(server: &MockServer) -> ZkLinkClient {
+        ZkLinkClient::new_with_url(
+            PRIVATE_KEY.to_string(),
+            L1SignerType::Eth,
+            server.url.clone(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn transfer(client: &ZkLinkClient, token: u32) -> Result<TxHash, ClientError> {
+        client.transfer(
+            SubAccountId(1),
+            ZkLinkAddress::from_hex("0xafaff3ad1a0425d792432d9ecd1c3e26ef2c42e9").unwrap(),
+            SubAccountId(1),
+            TokenId(token),
+            BigUint::from(100000u32),
+            BigUint::from(100u32),
+        )
+    }
+
+    #[test]
+    fn test_submit_with_nonce() {
+        let server = mock_server(MockState {
+            nonce: 5,
+            tokens: vec![(18, "USDC")],
+            ..Default::default()
+        });
+        let client = client(&server);
+        assert_eq!(client.account_id(), AccountId(1));
+
+        // the nonce is increased locally after the submissions
+        transfer(&client, 18).unwrap();
+        transfer(&client, 18).unwrap();
+        assert_eq!(server.state.lock().unwrap().submitted, vec![5, 6]);
+        assert_eq!(server.state.lock().unwrap().account_requests, 1);
+
+        // the nonce is refetched by the next tx after the submission fails
+        server.state.lock().unwrap().fail_submission = true;
+        assert!(matches!(
+            transfer(&client, 18),
+            Err(ClientError::RpcError(RpcError::GetErrorResult(_)))
+        ));
+        {
+            let mut state = server.state.lock().unwrap();
+            state.fail_submission = false;
+            state.nonce = 10;
+        }
+        transfer(&client, 18).unwrap();
+        transfer(&client, 18).unwrap();
+        let state = server.state.lock().unwrap();
+        assert_eq!(state.submitted, vec![5, 6, 10, 11]);
+        assert_eq!(state.account_requests, 2);
+    }
+
+    #[test]
+    fn test_token_symbol() {
+        let server = mock_server(MockState {
+            nonce: 1,
+            tokens: vec![(18, "USDC")],
+            ..Default::default()
+        });
+        let client = client(&server);
+
+        // the tokens are loaded once when they are first used
+        transfer(&client, 18).unwrap();
+        transfer(&client, 18).unwrap();
+        assert_eq!(server.state.lock().unwrap().token_requests, 1);
+
+        // the tokens are reloaded for the newly listed token
+        server.state.lock().unwrap().tokens.push((19, "USDT"));
+        transfer(&client, 19).unwrap();
+        assert_eq!(server.state.lock().unwrap().token_requests, 2);
+
+        // the unknown token isn't submitted and doesn't consume the nonce
+        assert!(matches!(
+            transfer(&client, 20),
+            Err(ClientError::TokenNotFound(TokenId(20)))
+        ));
+        transfer(&client, 18).unwrap();
+        let state = server.state.lock().unwrap();
+        assert_eq!(state.token_requests, 3);
+        assert_eq!(state.submitted, vec![1, 2, 3, 4]);
+    }
+}
@@ -1,6 +1,8 @@
 use zklink_sdk_signers::zklink_signer::pubkey_hash::PubKeyHash;
 use zklink_sdk_signers::zklink_signer::public_key::PackedPublicKey;
 use zklink_sdk_signers::zklink_signer::signature::ZkLinkSignature;
+use zklink_sdk_types::basic_types::tx_hash::TxHash;
+use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 
 pub fn verify_musig(signature: ZkLinkSignature, msg: &[u8]) -> bool {
     signature.verify_musig(msg)
@@ -9,3 +11,11 @@ pub fn verify_musig(signature: ZkLinkSignature, msg: &[u8]) -> bool {
 pub fn get_public_key_hash(public_key: PackedPublicKey) -> PubKeyHash {
     public_key.public_key_hash()
 }
+
+pub fn get_zklink_tx_bytes(tx: ZkLinkTx) -> Vec<u8> {
+    tx.get_bytes()
+}
+
+pub fn get_zklink_tx_hash(tx: ZkLinkTx) -> TxHash {
+    tx.tx_hash()
+}
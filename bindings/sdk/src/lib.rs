@@ -1,9 +1,17 @@
+mod client;
 mod crypto;
 mod network;
+mod rpc;
 mod type_convert;
+mod wallet;
 
-use crate::crypto::{get_public_key_hash, verify_musig};
+use crate::client::{ClientError, ZkLinkClient};
+use crate::crypto::{get_public_key_hash, get_zklink_tx_bytes, get_zklink_tx_hash, verify_musig};
 use crate::network::{zklink_main_net_url, zklink_test_net_url};
+use crate::rpc::{
+    AccountQueryParam, TimeStampMicro, ZkLinkRpcCallback, ZkLinkRpcClient, ZkLinkTxHistoryPage,
+};
+use crate::wallet::Wallet;
 
 use zklink_sdk_signers::eth_signer::error::EthSignerError;
 use zklink_sdk_signers::eth_signer::packed_eth_signature::PackedEthSignature;
@@ -36,7 +44,23 @@ use zklink_sdk_interface::sign_change_pubkey::{
 };
 use zklink_sdk_interface::signer::L1SignerType;
 use zklink_sdk_interface::signer::{L1Type, Signer};
-use zklink_sdk_interface::ChangePubKeyAuthRequest;
+use zklink_sdk_interface::{ChangePubKeyAuthRequest, SigningContext};
+
+use bigdecimal::BigDecimal;
+use zklink_sdk_provider::error::RpcError;
+use zklink_sdk_provider::network::Network;
+use zklink_sdk_provider::response::{
+    AccountInfoResp, AccountSnapshotResp, BlockNumberResp, BlockOnChainResp, BlockResp, ChainResp,
+    ChainTokenResp, EthPropertyResp, GateWayInfo, GlobalVarsResp, OnChainResp, OracleSignature,
+    ResponseContractParams, ResponseMarginParams, ResponsePosition, ResponseTidyOrder,
+    StateUpdateResp, TokenInfo, TokenResp, TxHashOrDetailResp, TxReceiptResp, TxResp,
+    WithdrawTxResp, ZkLinkTxHistory,
+};
+use zklink_sdk_provider::web_socket::ws_message::message::response::TxTopicEvent;
+
+use zklink_sdk_wallet::error::WalletError;
+use zklink_sdk_wallet::eth::EthTxOption;
+use zklink_sdk_wallet::wallet::WaitForTxStatus;
 
 use zklink_sdk_signers::starknet_signer::typed_data::message::Message;
 use zklink_sdk_signers::starknet_signer::typed_data::message::TxMessage;
@@ -0,0 +1,251 @@
+use crate::wallet::block_on;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use zklink_sdk_provider::error::RpcError;
+use zklink_sdk_provider::network::Network;
+use zklink_sdk_provider::response::*;
+use zklink_sdk_provider::web_socket::ws_message::message::request::ClientOffset;
+use zklink_sdk_provider::web_socket::ws_message::message::response::TxTopicEvent;
+use zklink_sdk_provider::web_socket::ws_message::topic::Topic;
+use zklink_sdk_provider::{ZkLinkRpcClient as _, ZkLinkRpcProvider};
+use zklink_sdk_types::basic_types::tx_hash::TxHash;
+use zklink_sdk_types::basic_types::{
+    AccountId, BlockNumber, ChainId, SubAccountId, TokenId, ZkLinkAddress,
+};
+use zklink_sdk_types::prelude::BigUintSerdeWrapper;
+use zklink_sdk_types::signatures::TxLayer1Signature;
+use zklink_sdk_types::tx_type::zklink_tx::{ZkLinkTx, ZkLinkTxType};
+
+pub type TimeStampMicro = DateTime<Utc>;
+pub type ZkLinkTxHistoryPage = Page<ZkLinkTxHistory>;
+
+/// The uniffi enum can't have the unnamed fields of `AccountQuery`.
+pub enum AccountQueryParam {
+    Id { id: AccountId },
+    Address { address: ZkLinkAddress },
+}
+
+impl From<AccountQueryParam> for AccountQuery {
+    fn from(query: AccountQueryParam) -> Self {
+        match query {
+            AccountQueryParam::Id { id } => AccountQuery::Id(id),
+            AccountQueryParam::Address { address } => AccountQuery::Address(address),
+        }
+    }
+}
+
+/// The callback of the async rpc request, which is called on the background thread of the request.
+pub trait ZkLinkRpcCallback: Send + Sync + Debug {
+    /// The json of the result.
+    fn on_success(&self, result: String);
+    fn on_error(&self, error: String);
+}
+
+/// Block on the request of the async rpc client.
+fn call<R, E: Into<RpcError>>(request: impl Future<Output = Result<R, E>>) -> Result<R, RpcError> {
+    block_on(request).map_err(Into::into)
+}
+
+/// The blocking client of the zklink rpc, every method blocks on the request of the async
+/// `ZkLinkRpcProvider`, and `request_async` sends the request of any method on the background
+/// thread.
+pub struct ZkLinkRpcClient(ZkLinkRpcProvider);
+
+impl ZkLinkRpcClient {
+    pub fn new(network: Network, timeout_secs: Option<u64>) -> Result<Self, RpcError> {
+        Self::new_with_url(network.url().to_string(), timeout_secs)
+    }
+
+    pub fn new_with_url(url: String, timeout_secs: Option<u64>) -> Result<Self, RpcError> {
+        let provider =
+            ZkLinkRpcProvider::new_with_url(&url, timeout_secs.map(Duration::from_secs))?;
+        Ok(Self(provider))
+    }
+
+    /// Send the request of the rpc `method`, the `params` is the json array of the positional
+    /// params and the result is returned as json.
+    pub fn request(&self, method: String, params: String) -> Result<String, RpcError> {
+        let params: Vec<Value> =
+            serde_json::from_str(&params).map_err(RpcError::InvalidArgument)?;
+        let result = call(self.0.raw_request(&method, params))?;
+        Ok(result.to_string())
+    }
+
+    /// The async version of `request`, the result is passed to the `callback` on the background
+    /// thread.
+    pub fn request_async(
+        self: Arc<Self>,
+        method: String,
+        params: String,
+        callback: Box<dyn ZkLinkRpcCallback>,
+    ) {
+        std::thread::spawn(move || match self.request(method, params) {
+            Ok(result) => callback.on_success(result),
+            Err(error) => callback.on_error(error.to_string()),
+        });
+    }
+
+    pub fn get_support_chains(&self) -> Result<Vec<ChainResp>, RpcError> {
+        call(self.0.get_support_chains())
+    }
+
+    pub fn tokens(&self) -> Result<HashMap<TokenId, TokenResp>, RpcError> {
+        call(self.0.tokens())
+    }
+
+    pub fn block_info(&self) -> Result<BlockNumberResp, RpcError> {
+        call(self.0.block_info())
+    }
+
+    pub fn block_detail(
+        &self,
+        block_number: Option<BlockNumber>,
+        include_tx: bool,
+        include_update: bool,
+    ) -> Result<BlockResp, RpcError> {
+        call(
+            self.0
+                .block_detail(block_number, include_tx, include_update),
+        )
+    }
+
+    pub fn pending_block_detail(
+        &self,
+        last_tx_timestamp_micro: u64,
+        include_tx: bool,
+        include_update: bool,
+        limit: Option<u32>,
+    ) -> Result<Vec<TxHashOrDetailResp>, RpcError> {
+        call(self.0.pending_block_detail(
+            last_tx_timestamp_micro,
+            include_tx,
+            include_update,
+            limit.map(|l| l as usize),
+        ))
+    }
+
+    pub fn block_onchain_detail(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<BlockOnChainResp, RpcError> {
+        call(self.0.block_onchain_detail(block_number))
+    }
+
+    pub fn account_info(
+        &self,
+        account_query: AccountQueryParam,
+    ) -> Result<AccountInfoResp, RpcError> {
+        call(self.0.account_info(account_query.into()))
+    }
+
+    pub fn global_vars_info(
+        &self,
+        sub_account_id: SubAccountId,
+    ) -> Result<GlobalVarsResp, RpcError> {
+        call(self.0.global_vars_info(sub_account_id))
+    }
+
+    pub fn account_balances(
+        &self,
+        account_id: AccountId,
+        sub_account_id: Option<SubAccountId>,
+    ) -> Result<SubAccountBalances, RpcError> {
+        call(self.0.account_balances(account_id, sub_account_id))
+    }
+
+    pub fn account_order_slots(
+        &self,
+        account_id: AccountId,
+        sub_account_id: Option<SubAccountId>,
+    ) -> Result<SubAccountOrders, RpcError> {
+        call(self.0.account_order_slots(account_id, sub_account_id))
+    }
+
+    pub fn account_positions(
+        &self,
+        account_id: AccountId,
+        sub_account_id: Option<SubAccountId>,
+    ) -> Result<SubAccountPositions, RpcError> {
+        call(self.0.account_positions(account_id, sub_account_id))
+    }
+
+    pub fn token_remain(
+        &self,
+        token_id: TokenId,
+        mapping: bool,
+    ) -> Result<HashMap<ChainId, BigUintSerdeWrapper>, RpcError> {
+        call(self.0.token_remain(token_id, mapping))
+    }
+
+    pub fn account_snapshot(
+        &self,
+        account_query: AccountQueryParam,
+        sub_account_id: Option<SubAccountId>,
+        block_number: Option<BlockNumber>,
+    ) -> Result<AccountSnapshotResp, RpcError> {
+        call(
+            self.0
+                .account_snapshot(account_query.into(), sub_account_id, block_number),
+        )
+    }
+
+    pub fn tx_info(&self, hash: TxHash, include_update: bool) -> Result<TxResp, RpcError> {
+        call(self.0.tx_info(hash, include_update))
+    }
+
+    pub fn tx_history(
+        &self,
+        tx_type: ZkLinkTxType,
+        address: ZkLinkAddress,
+        page_index: u64,
+        page_size: u32,
+    ) -> Result<ZkLinkTxHistoryPage, RpcError> {
+        call(self.0.tx_history(tx_type, address, page_index, page_size))
+    }
+
+    pub fn tx_withdraw(
+        &self,
+        last_tx_timestamp_micro: u64,
+        max_txs: u32,
+    ) -> Result<Vec<WithdrawTxResp>, RpcError> {
+        call(self.0.tx_withdraw(last_tx_timestamp_micro, max_txs))
+    }
+
+    /// The `topic` is the string of the websocket topic, e.g. `txExecuteResult@1`.
+    pub fn get_websocket_events(
+        &self,
+        topic: String,
+        from_topic_index_included: i64,
+        limit: Option<u32>,
+    ) -> Result<Vec<TxTopicEvent>, RpcError> {
+        let topic = Topic::from_str(&topic).map_err(|_| RpcError::InvalidInputParameter)?;
+        let offset = ClientOffset {
+            from_topic_index_included,
+            limit: limit.map(|l| l as usize),
+        };
+        call(self.0.get_websocket_events(topic, offset))
+    }
+
+    pub fn get_change_pubkey_chain_id(&self) -> Result<ChainId, RpcError> {
+        call(self.0.get_change_pubkey_chain_id())
+    }
+
+    pub fn get_eth_property(&self) -> Result<EthPropertyResp, RpcError> {
+        call(self.0.get_eth_property())
+    }
+
+    pub fn tx_submit(
+        &self,
+        tx: ZkLinkTx,
+        l1_signature: Option<TxLayer1Signature>,
+        oracle_signature: Option<OracleSignature>,
+    ) -> Result<TxHash, RpcError> {
+        call(self.0.tx_submit(tx, l1_signature, oracle_signature))
+    }
+}
@@ -1,5 +1,7 @@
 use crate::UniffiCustomTypeConverter;
-use crate::{TxLayer1Signature, ZkLinkTx};
+use crate::{
+    OracleSignature, StateUpdateResp, TxHashOrDetailResp, TxLayer1Signature, TxTopicEvent, ZkLinkTx,
+};
 use zklink_sdk_signers::zklink_signer::signature::ZkLinkSignature;
 
 macro_rules! ffi_json_convert {
@@ -20,3 +22,7 @@ macro_rules! ffi_json_convert {
 ffi_json_convert!(TxLayer1Signature);
 ffi_json_convert!(ZkLinkTx);
 ffi_json_convert!(ZkLinkSignature);
+ffi_json_convert!(StateUpdateResp);
+ffi_json_convert!(TxHashOrDetailResp);
+ffi_json_convert!(OracleSignature);
+ffi_json_convert!(TxTopicEvent);
@@ -2,4 +2,4 @@ mod hex_convert;
 mod json_convert;
 mod number_convert;
 mod str_convert;
-// mod time_convert;
+mod time_convert;
@@ -1,4 +1,7 @@
-use crate::{BigUint, UniffiCustomTypeConverter, ZkLinkAddress};
+use crate::{
+    BigDecimal, BigIntSerdeWrapper, BigUint, BigUintSerdeWrapper, UniffiCustomTypeConverter,
+    ZkLinkAddress, U256,
+};
 use std::str::FromStr;
 
 macro_rules! ffi_str_convert {
@@ -18,6 +21,39 @@ macro_rules! ffi_str_convert {
 
 ffi_str_convert!(BigUint);
 ffi_str_convert!(ZkLinkAddress);
+ffi_str_convert!(BigDecimal);
+
+impl UniffiCustomTypeConverter for BigUintSerdeWrapper {
+    type Builtin = String;
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(BigUintSerdeWrapper(BigUint::from_str(&val)?))
+    }
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0.to_string()
+    }
+}
+
+impl UniffiCustomTypeConverter for BigIntSerdeWrapper {
+    type Builtin = String;
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        // the wrapper is serialized as the decimal string
+        Ok(serde_json::from_value(serde_json::Value::String(val))?)
+    }
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0.to_string()
+    }
+}
+
+/// The `U256` of the rpc responses is in decimal, e.g. the layer one chain id.
+impl UniffiCustomTypeConverter for U256 {
+    type Builtin = String;
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(U256::from_dec_str(&val)?)
+    }
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.to_string()
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -32,5 +68,53 @@ mod test {
         println!("big uint: {:?}", s);
         println!("big uint: {:?}", b2);
         println!("big uint: {:?}", b2.to_string());
+
+        let b = BigIntSerdeWrapper::into_custom("-12345678909876543219999999999".into()).unwrap();
+        assert_eq!(
+            BigIntSerdeWrapper::from_custom(b),
+            "-12345678909876543219999999999"
+        );
+        let n = U256::into_custom("80001".into()).unwrap();
+        assert_eq!(n, U256::from(80001));
+        assert_eq!(U256::from_custom(n), "80001");
+    }
+
+    #[test]
+    fn test_rpc_convert() {
+        let d = BigDecimal::into_custom("1234.5678".into()).unwrap();
+        assert_eq!(d, BigDecimal::from_str("1234.5678").unwrap());
+        assert_eq!(BigDecimal::from_custom(d), "1234.5678");
+        assert!(BigDecimal::into_custom("1.2.3".into()).is_err());
+
+        let b = BigUintSerdeWrapper::into_custom("12345678909876543219999999999".into()).unwrap();
+        assert_eq!(
+            b.0,
+            BigUint::from_str("12345678909876543219999999999").unwrap()
+        );
+        assert_eq!(
+            BigUintSerdeWrapper::from_custom(b),
+            "12345678909876543219999999999"
+        );
+        assert!(BigUintSerdeWrapper::into_custom("-1".into()).is_err());
+
+        let b = BigIntSerdeWrapper::into_custom("100".into()).unwrap();
+        assert_eq!(BigIntSerdeWrapper::from_custom(b), "100");
+        assert!(BigIntSerdeWrapper::into_custom("0x10".into()).is_err());
+
+        // the U256 of the rpc is decimal, not hex
+        let n = U256::into_custom(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935".into(),
+        )
+        .unwrap();
+        assert_eq!(n, U256::MAX);
+        assert_eq!(
+            U256::from_custom(n),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert!(U256::into_custom("0x10".into()).is_err());
+        assert!(U256::into_custom(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936".into()
+        )
+        .is_err());
     }
 }
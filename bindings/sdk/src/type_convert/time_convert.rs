@@ -8,6 +8,23 @@ impl UniffiCustomTypeConverter for TimeStampMicro {
         Ok(date_time)
     }
     fn from_custom(obj: Self) -> Self::Builtin {
-        (obj.timestamp_nanos() / 1000) as u64
+        obj.timestamp_micros() as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let micros = 1696595303123456u64;
+        let t = TimeStampMicro::into_custom(micros).unwrap();
+        assert_eq!(t.timestamp(), 1696595303);
+        assert_eq!(t.timestamp_subsec_micros(), 123456);
+        assert_eq!(TimeStampMicro::from_custom(t), micros);
+
+        let t = TimeStampMicro::into_custom(0).unwrap();
+        assert_eq!(t, Utc.timestamp_opt(0, 0).unwrap());
     }
 }
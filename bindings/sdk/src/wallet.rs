@@ -0,0 +1,228 @@
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Runtime};
+use zklink_sdk_signers::eth_signer::H256;
+use zklink_sdk_signers::zklink_signer::pubkey_hash::PubKeyHash;
+use zklink_sdk_types::basic_types::{BigUint, ZkLinkAddress};
+use zklink_sdk_types::prelude::U256;
+use zklink_sdk_wallet::error::WalletError;
+use zklink_sdk_wallet::eth::EthTxOption;
+use zklink_sdk_wallet::wallet::{WaitForTxStatus, Wallet as InnerWallet};
+
+/// The runtime of the async layer1 requests, which are blocked on by the ffi calls.
+pub(crate) fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build the tokio runtime")
+    })
+}
+
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
+
+pub struct Wallet {
+    inner: InnerWallet,
+}
+
+impl Wallet {
+    pub fn new(url: &str, private_key: &str) -> Result<Self, WalletError> {
+        let inner = InnerWallet::new(url, private_key)?;
+        Ok(Wallet { inner })
+    }
+
+    pub fn get_balance(&self) -> Result<U256, WalletError> {
+        block_on(self.inner.get_balance())
+    }
+
+    /// The `block_number` is `latest`, `pending` or the number of the block, etc.
+    pub fn get_nonce(&self, block_number: String) -> Result<u64, WalletError> {
+        let nonce = block_on(self.inner.get_nonce(block_number))?;
+        Ok(nonce.as_u64())
+    }
+
+    pub fn get_fee(&self, eth_params: EthTxOption) -> Result<BigUint, WalletError> {
+        block_on(self.inner.get_fee(eth_params))
+    }
+
+    /// Poll the receipt of the transaction every second, `retries` is the max number of the polls
+    /// and 60 by default.
+    pub fn wait_for_transaction(
+        &self,
+        tx_hash: H256,
+        retries: Option<u32>,
+    ) -> Result<WaitForTxStatus, WalletError> {
+        block_on(self.inner.wait_for_transaction(tx_hash, retries))
+    }
+
+    pub fn approve_erc20(
+        &self,
+        contract: ZkLinkAddress,
+        amount: BigUint,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        block_on(self.inner.approve_erc20(contract, amount, eth_params))
+    }
+
+    pub fn deposit_eth(
+        &self,
+        sub_account_id: u8,
+        deposit_to: ZkLinkAddress,
+        eth_params: EthTxOption,
+        is_gateway: bool,
+    ) -> Result<H256, WalletError> {
+        if is_gateway {
+            block_on(
+                self.inner
+                    .deposit_eth_to_gateway(sub_account_id, deposit_to, eth_params),
+            )
+        } else {
+            block_on(
+                self.inner
+                    .deposit_eth_to_layer1(sub_account_id, deposit_to, eth_params),
+            )
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_erc20(
+        &self,
+        sub_account_id: u8,
+        deposit_to: ZkLinkAddress,
+        token_addr: ZkLinkAddress,
+        amount: BigUint,
+        mapping: bool,
+        eth_params: EthTxOption,
+        is_gateway: bool,
+    ) -> Result<H256, WalletError> {
+        if is_gateway {
+            block_on(self.inner.deposit_erc20_to_gateway(
+                sub_account_id,
+                deposit_to,
+                token_addr,
+                amount,
+                mapping,
+                eth_params,
+            ))
+        } else {
+            block_on(self.inner.deposit_erc20_to_layer1(
+                sub_account_id,
+                deposit_to,
+                token_addr,
+                amount,
+                mapping,
+                eth_params,
+            ))
+        }
+    }
+
+    pub fn set_auth_pubkey_hash(
+        &self,
+        nonce: u64,
+        new_pubkey_hash: PubKeyHash,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        block_on(
+            self.inner
+                .set_auth_pubkey_hash(nonce, new_pubkey_hash, eth_params),
+        )
+    }
+
+    pub fn full_exit(
+        &self,
+        account_id: u32,
+        sub_account_id: u8,
+        token_id: u16,
+        mapping: bool,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        block_on(
+            self.inner
+                .full_exit(account_id, sub_account_id, token_id, mapping, eth_params),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    const PRIVATE_KEY: &str = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+    // nothing listens on the port, the requests are refused
+    const URL: &str = "http://127.0.0.1:1";
+
+    fn eth_params() -> EthTxOption {
+        EthTxOption {
+            is_support_eip1559: false,
+            to: ZkLinkAddress::from_str("0x5505a8cD4594Dbf79d8C59C0Df1414AB871CA896").unwrap(),
+            nonce: Some(1),
+            value: None,
+            gas: Some(100000),
+            gas_price: Some(BigUint::from(1000u32)),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+        }
+    }
+
+    #[test]
+    fn test_new_wallet_error() {
+        assert!(matches!(
+            Wallet::new("invalid url", PRIVATE_KEY),
+            Err(WalletError::InvalidNetwork)
+        ));
+        assert!(matches!(
+            Wallet::new(URL, "invalid key"),
+            Err(WalletError::EthSignerError(_))
+        ));
+    }
+
+    #[test]
+    fn test_eth_tx_option_error() {
+        let wallet = Wallet::new(URL, PRIVATE_KEY).unwrap();
+        // the contract address must be a layer1 address
+        let invalid_to = EthTxOption {
+            to: ZkLinkAddress::from([0x11; 32]),
+            ..eth_params()
+        };
+        assert!(matches!(
+            wallet.get_fee(invalid_to),
+            Err(WalletError::InvalidInputParameter)
+        ));
+        // the value overflows the uint256
+        let invalid_value = EthTxOption {
+            value: Some(BigUint::from(2u32).pow(256)),
+            ..eth_params()
+        };
+        assert!(matches!(
+            wallet.deposit_eth(1, ZkLinkAddress::from([0x22; 20]), invalid_value, false),
+            Err(WalletError::InvalidInputParameter)
+        ));
+        let invalid_gas_price = EthTxOption {
+            gas_price: Some(BigUint::from(2u32).pow(256)),
+            ..eth_params()
+        };
+        assert!(matches!(
+            wallet.full_exit(1, 1, 18, false, invalid_gas_price),
+            Err(WalletError::InvalidInputParameter)
+        ));
+    }
+
+    #[test]
+    fn test_provider_error() {
+        let wallet = Wallet::new(URL, PRIVATE_KEY).unwrap();
+        assert!(matches!(
+            wallet.get_balance(),
+            Err(WalletError::MiddlewareError(_))
+        ));
+        assert!(matches!(
+            wallet.wait_for_transaction(H256::zero(), Some(1)),
+            Err(WalletError::MiddlewareError(_))
+        ));
+        // the eth_params are valid, the request of the fee fails
+        assert!(wallet.get_fee(eth_params()).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import com.zklink.sdk.*
+import java.math.BigInteger
+
+fun bytes(s: String): List<UByte> = s.toByteArray().map { it.toUByte() }
+
+// eth signer
+val ethSigner = EthSigner("0xb32593e347bf09436b058fbeabc17ebd2c7c1fa42e542f5f78fc3580faef83b7")
+assert(ethSigner.signMessage(bytes("hello world")) == "0xa9aa0710adb18f84d4bed8057382fc433c3dcff1bddf3b2b1c2cb11386ef3be4172b5d0688143759d4e744acc434ae4f96575c7fa9096971fd02fb3d2aaa77121c")
+assert(ethSigner.getAddress() == "0x9e372368c25056d44045e445d72d7b91ce3ee3b1")
+
+// zklink signer
+val privateKey = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4"
+val zklinkSigner = ZkLinkSigner.newFromHexEthSigner(privateKey)
+val pubKey = zklinkSigner.publicKey()
+assert(pubKey == "0x7b173e25e484eed3461091430f81b2a5bd7ae792f69701dcb073cb903f812510")
+assert(getPublicKeyHash(pubKey) == "0xd8d5fb6a6caef06aa3dc2abdcdc240987e5330fe")
+val msg = listOf<UByte>(0u, 1u, 2u, 3u, 4u, 5u, 6u)
+val signature = zklinkSigner.signMusig(msg)
+assert(verifyMusig(signature, msg))
+assert(!verifyMusig(signature, bytes("hello world")))
+
+// tx builder
+val forcedExit = ForcedExit(
+    ForcedExitBuilder(
+        1u.toUByte(),
+        1u,
+        1u.toUByte(),
+        "0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9",
+        1u.toUByte(),
+        18u,
+        18u,
+        1u,
+        BigInteger("100000"),
+        true,
+        1693472232u,
+    )
+)
+val signedForcedExit = forcedExit.createSignedTx(zklinkSigner)
+assert(signedForcedExit.isSignatureValid())
+assert(signedForcedExit.toZklinkTx().contains("\"type\":\"ForcedExit\""))
+
+// signer
+val transfer = Transfer(
+    TransferBuilder(
+        10u,
+        "0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9",
+        1u.toUByte(),
+        1u.toUByte(),
+        18u,
+        BigInteger("1000000000000000000"),
+        BigInteger("10000000000000"),
+        1u,
+        1693472232u,
+    )
+)
+val signer = Signer(privateKey, L1SignerType.Eth)
+val txSignature = signer.signTransfer(transfer, "USDC", null, null)
+assert(txSignature.tx.contains("\"type\":\"Transfer\""))
+assert(txSignature.layer1Signature != null)
@@ -0,0 +1,56 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import ZkLinkSdk
+
+// eth signer
+let ethSigner = try! EthSigner(privateKey: "0xb32593e347bf09436b058fbeabc17ebd2c7c1fa42e542f5f78fc3580faef83b7")
+assert(try! ethSigner.signMessage(message: Array("hello world".utf8)) == "0xa9aa0710adb18f84d4bed8057382fc433c3dcff1bddf3b2b1c2cb11386ef3be4172b5d0688143759d4e744acc434ae4f96575c7fa9096971fd02fb3d2aaa77121c")
+assert(ethSigner.getAddress() == "0x9e372368c25056d44045e445d72d7b91ce3ee3b1")
+
+// zklink signer
+let privateKey = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4"
+let zklinkSigner = try! ZkLinkSigner.newFromHexEthSigner(ethHexPrivateKey: privateKey)
+let pubKey = zklinkSigner.publicKey()
+assert(pubKey == "0x7b173e25e484eed3461091430f81b2a5bd7ae792f69701dcb073cb903f812510")
+assert(getPublicKeyHash(publicKey: pubKey) == "0xd8d5fb6a6caef06aa3dc2abdcdc240987e5330fe")
+let msg: [UInt8] = [0, 1, 2, 3, 4, 5, 6]
+let signature = try! zklinkSigner.signMusig(msg: msg)
+assert(verifyMusig(signature: signature, msg: msg))
+assert(!verifyMusig(signature: signature, msg: Array("hello world".utf8)))
+
+// tx builder
+let forcedExit = ForcedExit(builder: ForcedExitBuilder(
+    toChainId: 1,
+    initiatorAccountId: 1,
+    initiatorSubAccountId: 1,
+    target: "0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9",
+    targetSubAccountId: 1,
+    l2SourceToken: 18,
+    l1TargetToken: 18,
+    initiatorNonce: 1,
+    exitAmount: "100000",
+    withdrawToL1: true,
+    timestamp: 1693472232
+))
+let signedForcedExit = try! forcedExit.createSignedTx(signer: zklinkSigner)
+assert(signedForcedExit.isSignatureValid())
+assert(signedForcedExit.toZklinkTx().contains("\"type\":\"ForcedExit\""))
+
+// signer
+let transfer = Transfer(builder: TransferBuilder(
+    accountId: 10,
+    toAddress: "0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9",
+    fromSubAccountId: 1,
+    toSubAccountId: 1,
+    token: 18,
+    amount: "1000000000000000000",
+    fee: "10000000000000",
+    nonce: 1,
+    timestamp: 1693472232
+))
+let signer = try! Signer(privateKey: privateKey, l1Type: .eth)
+let txSignature = try! signer.signTransfer(tx: transfer, tokenSybmol: "USDC", chainId: nil, addr: nil)
+assert(txSignature.tx.contains("\"type\":\"Transfer\""))
+assert(txSignature.layer1Signature != nil)
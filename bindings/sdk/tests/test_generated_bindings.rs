@@ -0,0 +1,6 @@
+// The generated bindings are tested by running the scripts with `kotlinc` and `swiftc`, which are
+// only required when the feature of the language is enabled.
+#[cfg(feature = "kotlin")]
+uniffi::build_foreign_language_testcases!("tests/bindings/test_zklink_sdk.kts");
+#[cfg(feature = "swift")]
+uniffi::build_foreign_language_testcases!("tests/bindings/test_zklink_sdk.swift");
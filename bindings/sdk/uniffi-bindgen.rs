@@ -1,4 +1,4 @@
 fn main() {
-    #[cfg(feature = "python")]
+    #[cfg(any(feature = "python", feature = "kotlin", feature = "swift"))]
     uniffi::uniffi_bindgen_main()
 }
@@ -0,0 +1,68 @@
+// The precomputed params of the zklink signer, copied into the `node-dist` built with the `node`
+// feature.
+//
+// The jubjub and rescue params are plain data in the linear memory of the wasm instance once
+// `initParams` returns, so the memory of an instance which has done nothing but `initParams` is
+// saved by `make build_wasm` and copied into a new instance instead of computing the params again.
+// The saved memory is only valid for the same wasm binary, it's bound to the sha256 of the binary.
+const crypto = require("crypto");
+const fs = require("fs");
+const path = require("path");
+const sdk = require("./zklink-sdk-node");
+
+const WASM_FILE = path.join(__dirname, "zklink-sdk-node_bg.wasm");
+const PARAMS_FILE = path.join(__dirname, "zklink-sdk-node_params.bin");
+const HASH_LEN = 32;
+const PAGE_SIZE = 65536;
+
+function wasmHash() {
+    return crypto.createHash("sha256").update(fs.readFileSync(WASM_FILE)).digest();
+}
+
+/**
+ * Initialize the params and save the memory of the wasm instance, it must be called by a process
+ * which hasn't called any other sdk function.
+ * @param {string} [file] the file of the params, `zklink-sdk-node_params.bin` by default
+ */
+function saveParams(file = PARAMS_FILE) {
+    sdk.initParams();
+    const memory = new Uint8Array(sdk.__wasm.memory.buffer);
+    fs.writeFileSync(file, Buffer.concat([wasmHash(), memory]));
+}
+
+/**
+ * Load the saved params into the wasm instance, it must be called before any other sdk function.
+ * Returns false if the params are not loaded because the file is missing or saved by another wasm
+ * binary, then `initParams` is required.
+ * @param {string} [file] the file of the params, `zklink-sdk-node_params.bin` by default
+ */
+function loadParams(file = PARAMS_FILE) {
+    if (!fs.existsSync(file)) {
+        return false;
+    }
+    const data = fs.readFileSync(file);
+    if (data.length < HASH_LEN || !data.subarray(0, HASH_LEN).equals(wasmHash())) {
+        return false;
+    }
+    const snapshot = data.subarray(HASH_LEN);
+    const memory = sdk.__wasm.memory;
+    if (snapshot.length % PAGE_SIZE !== 0 || snapshot.length < memory.buffer.byteLength) {
+        return false;
+    }
+    memory.grow((snapshot.length - memory.buffer.byteLength) / PAGE_SIZE);
+    new Uint8Array(memory.buffer).set(snapshot);
+    return true;
+}
+
+/** Load the saved params if they are valid, otherwise initialize them. */
+function initParams(file = PARAMS_FILE) {
+    if (!loadParams(file)) {
+        sdk.initParams();
+    }
+}
+
+module.exports = { saveParams, loadParams, initParams };
+
+if (require.main === module) {
+    saveParams(process.argv[2]);
+}
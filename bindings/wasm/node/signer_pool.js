@@ -0,0 +1,150 @@
+// The pool of worker threads signing the txs off the event loop, copied into the `node-dist`
+// built with the `node` feature.
+const os = require("os");
+const path = require("path");
+const { Worker } = require("worker_threads");
+
+class SignerPool {
+    /**
+     * Start the workers, each worker loads the signer params and creates the signer once. The
+     * crashed worker is removed and respawned, its pending tasks are rejected.
+     * @param {string} privateKey the private key of the layer1 account
+     * @param {object} [options]
+     * @param {number} [options.size] the number of the workers, the number of the cpus by default
+     * @param {number} [options.l1Type] the `L1Type` of the signer, `L1Type.Eth` by default
+     * @param {string} [options.starknetChainId] the chain id of the starknet signer
+     * @param {string} [options.starknetAddr] the address of the starknet signer
+     * @param {string} [options.paramsFile] the precomputed params saved by `params.js`, the params
+     * shipped in the `node-dist` by default, the workers compute the params if it's invalid
+     */
+    constructor(privateKey, options = {}) {
+        const size = options.size || os.cpus().length;
+        this.workerData = {
+            paramsFile: options.paramsFile,
+            privateKey,
+            l1Type: options.l1Type,
+            starknetChainId: options.starknetChainId,
+            starknetAddr: options.starknetAddr,
+        };
+        this.workers = [];
+        this.tasks = new Map();
+        this.nextTaskId = 0;
+        this.closed = false;
+        const ready = [];
+        for (let i = 0; i < size; i++) {
+            ready.push(this.spawn());
+        }
+        this.ready = Promise.all(ready);
+    }
+
+    /** Resolve when all the workers are ready to sign. */
+    init() {
+        return this.ready;
+    }
+
+    /** Sign the `Order.json_value()`, resolve the signed order. */
+    createSignedOrder(order) {
+        return this.call("createSignedOrder", [order]);
+    }
+
+    /** Sign the `Contract.json_value()`, resolve the signed contract. */
+    createSignedContract(contract) {
+        return this.call("createSignedContract", [contract]);
+    }
+
+    /** Sign the `OrderMatching.json_value()`, resolve the `TxSignature`. */
+    signOrderMatching(tx) {
+        return this.call("signOrderMatching", [tx]);
+    }
+
+    /** Sign the `ContractMatching.json_value()`, resolve the `TxSignature`. */
+    signContractMatching(tx) {
+        return this.call("signContractMatching", [tx]);
+    }
+
+    /** Sign the `Transfer.json_value()`, resolve the `TxSignature`. */
+    signTransfer(tx, tokenSymbol, chainId, addr) {
+        return this.call("signTransfer", [tx, tokenSymbol, chainId, addr]);
+    }
+
+    /** Sign the `Withdraw.json_value()`, resolve the `TxSignature`. */
+    signWithdraw(tx, tokenSymbol, chainId, addr) {
+        return this.call("signWithdraw", [tx, tokenSymbol, chainId, addr]);
+    }
+
+    /** Terminate the workers, the pending tasks are rejected. */
+    async close() {
+        this.closed = true;
+        for (const { reject } of this.tasks.values()) {
+            reject(new Error("the signer pool is closed"));
+        }
+        this.tasks.clear();
+        await Promise.all(this.workers.map(({ worker }) => worker.terminate()));
+    }
+
+    /** Start a worker, resolve when it's ready to sign. */
+    spawn() {
+        const worker = new Worker(path.join(__dirname, "signer_worker.js"), { workerData: this.workerData });
+        const entry = { worker, pending: new Set(), ready: false };
+        const ready = new Promise((resolve, reject) => {
+            worker.once("message", (message) => {
+                entry.ready = message.ready;
+                message.ready ? resolve() : reject(new Error(message.error));
+            });
+            worker.once("error", reject);
+            worker.once("exit", (code) => reject(new Error(`the signer worker exited with code ${code}`)));
+        });
+        worker.on("message", (message) => this.onMessage(entry, message));
+        worker.on("error", (error) => this.onExit(entry, error));
+        worker.on("exit", (code) => this.onExit(entry, new Error(`the signer worker exited with code ${code}`)));
+        this.workers.push(entry);
+        return ready;
+    }
+
+    call(method, args) {
+        if (this.closed) {
+            return Promise.reject(new Error("the signer pool is closed"));
+        }
+        if (this.workers.length === 0) {
+            return Promise.reject(new Error("no signer worker is alive"));
+        }
+        // post the task to the least busy worker
+        const entry = this.workers.reduce((a, b) => (b.pending.size < a.pending.size ? b : a));
+        const id = this.nextTaskId++;
+        return new Promise((resolve, reject) => {
+            this.tasks.set(id, { resolve, reject });
+            entry.pending.add(id);
+            entry.worker.postMessage({ id, method, args });
+        });
+    }
+
+    onMessage(entry, { id, result, error }) {
+        const task = this.tasks.get(id);
+        if (task === undefined) {
+            return;
+        }
+        this.tasks.delete(id);
+        entry.pending.delete(id);
+        error === undefined ? task.resolve(result) : task.reject(error);
+    }
+
+    /** Remove the crashed worker and reject its pending tasks, the `exit` follows the `error`. */
+    onExit(entry, error) {
+        const index = this.workers.indexOf(entry);
+        if (index === -1) {
+            return;
+        }
+        this.workers.splice(index, 1);
+        for (const id of entry.pending) {
+            this.tasks.get(id)?.reject(error);
+            this.tasks.delete(id);
+        }
+        entry.pending.clear();
+        // the worker failing to start isn't respawned, it would fail again
+        if (!this.closed && entry.ready) {
+            this.spawn().catch(() => {});
+        }
+    }
+}
+
+module.exports = { SignerPool };
@@ -0,0 +1,29 @@
+// The worker of `SignerPool`, which signs the plain tx objects posted by the pool.
+const { parentPort, workerData } = require("worker_threads");
+const { Signer, L1Type } = require("./zklink-sdk-node");
+const { initParams } = require("./params");
+
+let signer;
+try {
+    const { paramsFile, privateKey, l1Type, starknetChainId, starknetAddr } = workerData;
+    // load the precomputed params before the first task, computing them is the slow part of
+    // creating the signer
+    initParams(paramsFile);
+    signer = new Signer(privateKey, l1Type ?? L1Type.Eth, starknetChainId, starknetAddr);
+    parentPort.postMessage({ ready: true });
+} catch (error) {
+    parentPort.postMessage({ ready: false, error: String(error) });
+    // the worker exits without listening to the tasks, then the pool removes it
+    process.exitCode = 1;
+}
+
+if (signer !== undefined) {
+    parentPort.on("message", ({ id, method, args }) => {
+        try {
+            const result = signer[`${method}FromJson`](...args);
+            parentPort.postMessage({ id, result });
+        } catch (error) {
+            parentPort.postMessage({ id, error: String(error) });
+        }
+    });
+}
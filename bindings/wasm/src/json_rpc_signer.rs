@@ -11,6 +11,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 use zklink_sdk_interface::json_rpc_signer::JsonRpcAccountSigner;
 use zklink_sdk_interface::json_rpc_signer::JsonRpcSigner as InterfaceJsonRpcSigner;
+use zklink_sdk_interface::SigningContext;
 use zklink_sdk_signers::eth_signer::json_rpc_signer::Signer as EthereumSigner;
 use zklink_sdk_signers::starknet_signer::starknet_json_rpc_signer::Signer as StarknetSigner;
 use zklink_sdk_types::tx_type::change_pubkey::ChangePubKey as TxChangePubKey;
@@ -25,6 +26,7 @@ use zklink_sdk_types::tx_type::order_matching::{
 };
 use zklink_sdk_types::tx_type::transfer::Transfer as TxTransfer;
 use zklink_sdk_types::tx_type::withdraw::Withdraw as TxWithdraw;
+use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 
 #[wasm_bindgen]
 pub struct JsonRpcSigner {
@@ -79,6 +81,16 @@ impl JsonRpcSigner {
         hex::encode(self.inner.signature_seed())
     }
 
+    /// Sign the zklink tx of any type, `tx` is the json of `ZkLinkTx` tagged by `type`, e.g.
+    /// `{"type":"Transfer",...}`, `ctx` is the optional signing context, e.g.
+    /// `{"tokenSymbol":"USDC"}` or `{"changePubkeyAuth":{"type":"EthECDSA"}}`.
+    pub async fn sign(&self, tx: JsValue, ctx: JsValue) -> Result<JsValue, JsValue> {
+        let tx: ZkLinkTx = serde_wasm_bindgen::from_value(tx)?;
+        let ctx: Option<SigningContext> = serde_wasm_bindgen::from_value(ctx)?;
+        let signature = self.inner.sign(tx, ctx.unwrap_or_default()).await?;
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+
     #[wasm_bindgen(js_name=signChangePubkeyWithOnchain)]
     pub fn sign_change_pubkey_with_onchain(&self, tx: ChangePubKey) -> Result<JsValue, JsValue> {
         let inner_tx = tx.json_value()?;
@@ -1,12 +1,16 @@
 #![cfg(target_arch = "wasm32")]
 #[cfg(feature = "web")]
 pub mod json_rpc_signer;
+#[cfg(all(feature = "node", not(feature = "web")))]
+pub mod node;
 pub mod rpc_client;
+pub mod rpc_response;
 pub mod rpc_type_converter;
 #[cfg(not(feature = "web"))]
 pub mod signer;
 pub mod tx_types;
 pub mod utils;
 pub mod wallet;
+pub mod ws_client;
 
 extern crate getrandom;
@@ -0,0 +1,82 @@
+use crate::signer::Signer;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+use zklink_sdk_signers::zklink_signer::init_params as init_zklink_signer_params;
+use zklink_sdk_types::tx_type::contract::{
+    Contract as TxContract, ContractMatching as TxContractMatching,
+};
+use zklink_sdk_types::tx_type::order_matching::{
+    Order as TxOrder, OrderMatching as TxOrderMatching,
+};
+use zklink_sdk_types::tx_type::transfer::Transfer as TxTransfer;
+use zklink_sdk_types::tx_type::withdraw::Withdraw as TxWithdraw;
+
+/// Initialize the params of the zklink signer, otherwise they are initialized by the first `Signer`,
+/// which blocks for a while. The workers of `SignerPool` call this when they start.
+#[wasm_bindgen(js_name=initParams)]
+pub fn init_params() {
+    init_zklink_signer_params();
+}
+
+/// The signing methods taking the plain tx objects (the `json_value` of the tx types) instead of
+/// the wasm tx types, which can't be posted to the worker threads.
+#[wasm_bindgen]
+impl Signer {
+    #[wasm_bindgen(js_name=createSignedOrderFromJson)]
+    pub fn create_signed_order_from_json(&self, order: JsValue) -> Result<JsValue, JsValue> {
+        let order: TxOrder = serde_wasm_bindgen::from_value(order)?;
+        let signed_order = self.inner.create_signed_order(&order)?;
+        Ok(serde_wasm_bindgen::to_value(&signed_order)?)
+    }
+
+    #[wasm_bindgen(js_name=createSignedContractFromJson)]
+    pub fn create_signed_contract_from_json(&self, contract: JsValue) -> Result<JsValue, JsValue> {
+        let contract: TxContract = serde_wasm_bindgen::from_value(contract)?;
+        let signed_contract = self.inner.create_signed_contract(&contract)?;
+        Ok(serde_wasm_bindgen::to_value(&signed_contract)?)
+    }
+
+    #[wasm_bindgen(js_name=signOrderMatchingFromJson)]
+    pub fn sign_order_matching_from_json(&self, tx: JsValue) -> Result<JsValue, JsValue> {
+        let order_matching: TxOrderMatching = serde_wasm_bindgen::from_value(tx)?;
+        let signature = self.inner.sign_order_matching(order_matching)?;
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+
+    #[wasm_bindgen(js_name=signContractMatchingFromJson)]
+    pub fn sign_contract_matching_from_json(&self, tx: JsValue) -> Result<JsValue, JsValue> {
+        let contract_matching: TxContractMatching = serde_wasm_bindgen::from_value(tx)?;
+        let signature = self.inner.sign_contract_matching(contract_matching)?;
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+
+    #[wasm_bindgen(js_name=signTransferFromJson)]
+    pub fn sign_transfer_from_json(
+        &self,
+        tx: JsValue,
+        token_symbol: &str,
+        chain_id: Option<String>,
+        addr: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let transfer: TxTransfer = serde_wasm_bindgen::from_value(tx)?;
+        let signature = self
+            .inner
+            .sign_transfer(transfer, token_symbol, chain_id, addr)?;
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+
+    #[wasm_bindgen(js_name=signWithdrawFromJson)]
+    pub fn sign_withdraw_from_json(
+        &self,
+        tx: JsValue,
+        token_symbol: &str,
+        chain_id: Option<String>,
+        addr: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let withdraw: TxWithdraw = serde_wasm_bindgen::from_value(tx)?;
+        let signature = self
+            .inner
+            .sign_withdraw(withdraw, token_symbol, chain_id, addr)?;
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::rpc_response::*;
 use crate::rpc_type_converter::{
     AccountQuery, TxLayer1Signature, TxOracleSignature, TxZkLinkSignature,
 };
@@ -6,25 +7,36 @@ use jsonrpsee::core::params::ArrayParams;
 use jsonrpsee::core::traits::ToRpcParams;
 use jsonrpsee::types::request::Request;
 use jsonrpsee::types::Id;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::str::FromStr;
 use wasm_bindgen::prelude::wasm_bindgen;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue};
 use zklink_sdk_provider::error::RpcError;
 use zklink_sdk_provider::network::Network;
-use zklink_sdk_provider::response::{AccountQuery as RpcAccountQuery, OracleSignature};
+use zklink_sdk_provider::response::{
+    AccountInfoResp, AccountQuery as RpcAccountQuery, AccountSnapshotResp, BlockNumberResp,
+    BlockOnChainResp, BlockResp, ChainResp, ForwardTxResp, OracleSignature, Page,
+    SubAccountBalances, SubAccountOrders, TokenResp, TxHashOrDetailResp, TxResp, WithdrawTxResp,
+    ZkLinkTxHistory,
+};
+use zklink_sdk_provider::web_socket::proto::event::Event;
 use zklink_sdk_provider::web_socket::ws_message::message::request::ClientOffset;
+use zklink_sdk_provider::web_socket::ws_message::message::response::{
+    decode_topic_event, TxTopicEvent,
+};
 use zklink_sdk_provider::web_socket::ws_message::topic::Topic;
 use zklink_sdk_signers::zklink_signer::ZkLinkSignature;
 use zklink_sdk_types::basic_types::tx_hash::TxHash;
-use zklink_sdk_types::basic_types::{AccountId, BlockNumber, SubAccountId, TokenId};
-use zklink_sdk_types::prelude::ZkLinkAddress;
+use zklink_sdk_types::basic_types::{AccountId, BlockNumber, ChainId, SubAccountId, TokenId};
+use zklink_sdk_types::prelude::{BigUintSerdeWrapper, ZkLinkAddress};
 use zklink_sdk_types::signatures::TxLayer1Signature as TypesTxLayer1Signature;
 use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTxType;
 
-macro_rules! rpc_request {
-    ($method:expr,$builder:expr, $server_url:expr, $resp_type: ty) => {{
+macro_rules! rpc_call {
+    ($method:expr,$builder:expr, $server_url:expr) => {{
         let params = $builder
             .to_rpc_params()
             .map_err(RpcError::InvalidArgument)?;
@@ -42,7 +54,14 @@ macro_rules! rpc_request {
             .json::<serde_json::Value>()
             .await
             .map_err(RpcError::ResponseError)?;
-        Ok(serde_wasm_bindgen::to_value(&res.to_string())?)
+        parse_rpc_response(res)?
+    }};
+}
+
+macro_rules! rpc_request {
+    ($method:expr,$builder:expr, $server_url:expr, $resp_type: ty) => {{
+        let result: $resp_type = rpc_call!($method, $builder, $server_url);
+        Ok(to_js_value(&result)?.unchecked_into())
     }};
 }
 
@@ -58,6 +77,25 @@ pub fn uuid_str() -> String {
     uuid.to_string()
 }
 
+/// Get the events of the websocket topic, used to backfill the events missed by the `WsClient`.
+pub(crate) async fn websocket_events(
+    server_url: &str,
+    topic: Topic,
+    offset: ClientOffset,
+) -> Result<Vec<TxTopicEvent>, JsValue> {
+    let mut builder = ArrayParams::new();
+    let _ = builder.insert(topic);
+    let _ = builder.insert(offset);
+    // the data of the events is decoded by the topic like the events pushed by the server
+    let events: Vec<Event<Topic, Value>> = rpc_call!("getWebSocketEvents", builder, server_url);
+    let events = events
+        .into_iter()
+        .map(decode_topic_event)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| RpcError::ParseJsonError(e.to_string()))?;
+    Ok(events)
+}
+
 #[wasm_bindgen]
 pub struct RpcClient {
     server_url: String,
@@ -75,8 +113,12 @@ impl RpcClient {
         Ok(RpcClient { server_url })
     }
 
+    pub(crate) fn server_url(&self) -> &str {
+        &self.server_url
+    }
+
     #[wasm_bindgen(js_name=getSupportTokens)]
-    pub async fn tokens(&self) -> Result<JsValue, JsValue> {
+    pub async fn tokens(&self) -> Result<TokenRespMap, JsValue> {
         let builder = ArrayParams::new();
         rpc_request!("getSupportTokens",builder,&self.server_url,HashMap<TokenId, TokenResp>)
     }
@@ -87,7 +129,7 @@ impl RpcClient {
         account_query: AccountQuery,
         sub_account_id: Option<u8>,
         block_number: Option<u32>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<JsAccountSnapshotResp, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(RpcAccountQuery::from(account_query));
         let _ = builder.insert(sub_account_id.map(|id| SubAccountId(id)));
@@ -107,7 +149,7 @@ impl RpcClient {
         l1_signature: Option<TxLayer1Signature>,
         l2_signature: Option<TxZkLinkSignature>,
         oracle_signature: Option<TxOracleSignature>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<JsTxHash, JsValue> {
         let mut builder = ArrayParams::new();
         let zklink_tx: ZkLinkTx =
             serde_wasm_bindgen::from_value(tx).map_err(|_e| RpcError::InvalidInputParameter)?;
@@ -129,7 +171,7 @@ impl RpcClient {
     }
 
     #[wasm_bindgen(js_name=getSupportChains)]
-    pub async fn get_support_chains(&self) -> Result<JsValue, JsValue> {
+    pub async fn get_support_chains(&self) -> Result<ChainRespArray, JsValue> {
         let builder = ArrayParams::new();
         rpc_request!(
             "getSupportChains",
@@ -140,7 +182,7 @@ impl RpcClient {
     }
 
     #[wasm_bindgen(js_name=getLatestBlockNumber)]
-    pub async fn block_info(&self) -> Result<JsValue, JsValue> {
+    pub async fn block_info(&self) -> Result<JsBlockNumberResp, JsValue> {
         let builder = ArrayParams::new();
         rpc_request!(
             "getLatestBlockNumber",
@@ -156,7 +198,7 @@ impl RpcClient {
         block_number: Option<u32>,
         include_tx: bool,
         include_update: bool,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<JsBlockResp, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(block_number.map(|b| BlockNumber(b)));
         let _ = builder.insert(include_tx);
@@ -171,7 +213,7 @@ impl RpcClient {
         include_tx: bool,
         include_update: bool,
         limit: Option<usize>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<TxHashOrDetailRespArray, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(last_tx_timestamp_micro);
         let _ = builder.insert(include_tx);
@@ -186,7 +228,10 @@ impl RpcClient {
     }
 
     #[wasm_bindgen(js_name=getBlockOnChainByNumber)]
-    pub async fn block_onchain_detail(&self, block_number: u32) -> Result<JsValue, JsValue> {
+    pub async fn block_onchain_detail(
+        &self,
+        block_number: u32,
+    ) -> Result<JsBlockOnChainResp, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(BlockNumber(block_number));
         rpc_request!(
@@ -198,7 +243,10 @@ impl RpcClient {
     }
 
     #[wasm_bindgen(js_name=getAccount)]
-    pub async fn account_info(&self, account_query: AccountQuery) -> Result<JsValue, JsValue> {
+    pub async fn account_info(
+        &self,
+        account_query: AccountQuery,
+    ) -> Result<JsAccountInfoResp, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(RpcAccountQuery::from(account_query));
         rpc_request!("getAccount", builder, &self.server_url, AccountInfoResp)
@@ -209,7 +257,7 @@ impl RpcClient {
         &self,
         account_id: u32,
         sub_account_id: Option<u8>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<JsSubAccountBalances, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(AccountId(account_id));
         let _ = builder.insert(sub_account_id.map(|id| SubAccountId(id)));
@@ -226,7 +274,7 @@ impl RpcClient {
         &self,
         account_id: u32,
         sub_account_id: Option<u8>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<JsSubAccountOrders, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(AccountId(account_id));
         let _ = builder.insert(sub_account_id.map(|id| SubAccountId(id)));
@@ -239,7 +287,11 @@ impl RpcClient {
     }
 
     #[wasm_bindgen(js_name=getTokenReserve)]
-    pub async fn token_remain(&self, token_id: u32, mapping: bool) -> Result<JsValue, JsValue> {
+    pub async fn token_remain(
+        &self,
+        token_id: u32,
+        mapping: bool,
+    ) -> Result<TokenReserveMap, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(TokenId(token_id));
         let _ = builder.insert(mapping);
@@ -247,7 +299,7 @@ impl RpcClient {
     }
 
     #[wasm_bindgen(js_name=getTransactionByHash)]
-    pub async fn tx_info(&self, hash: String, include_update: bool) -> Result<JsValue, JsValue> {
+    pub async fn tx_info(&self, hash: String, include_update: bool) -> Result<JsTxResp, JsValue> {
         let hash = TxHash::from_hex(&hash).map_err(|_e| RpcError::InvalidInputParameter)?;
         let mut builder = ArrayParams::new();
         let _ = builder.insert(hash);
@@ -262,7 +314,7 @@ impl RpcClient {
         address: String,
         page_index: u64,
         page_size: u32,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<ZkLinkTxHistoryPage, JsValue> {
         let address =
             ZkLinkAddress::from_hex(&address).map_err(|_e| RpcError::InvalidInputParameter)?;
         let mut builder = ArrayParams::new();
@@ -283,7 +335,7 @@ impl RpcClient {
         &self,
         last_tx_timestamp: u64,
         max_txs: u32,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<WithdrawTxRespArray, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(last_tx_timestamp);
         let _ = builder.insert(max_txs);
@@ -291,7 +343,7 @@ impl RpcClient {
             "getWithdrawTxs",
             builder,
             &self.server_url,
-            Vec<FastWithdrawTxResp>
+            Vec<WithdrawTxResp>
         )
     }
 
@@ -301,7 +353,7 @@ impl RpcClient {
         sub_account_id: u8,
         offset_id: i64,
         limit: i64,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<ForwardTxRespArray, JsValue> {
         let mut builder = ArrayParams::new();
         let _ = builder.insert(SubAccountId(sub_account_id));
         let _ = builder.insert(offset_id);
@@ -320,15 +372,13 @@ impl RpcClient {
         topic: String,
         from_topic_index_included: f64,
         limit: Option<usize>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<TxTopicEventArray, JsValue> {
         let topic = Topic::from_str(&topic).map_err(|_e| RpcError::InvalidInputParameter)?;
         let client_offset = ClientOffset {
             from_topic_index_included: from_topic_index_included as i64,
             limit,
         };
-        let mut builder = ArrayParams::new();
-        let _ = builder.insert(topic);
-        let _ = builder.insert(client_offset);
-        rpc_request!("getWebSocketEvents", builder, &self.server_url, bool)
+        let events = websocket_events(&self.server_url, topic, client_offset).await?;
+        Ok(to_js_value(&events)?.unchecked_into())
     }
 }
@@ -0,0 +1,146 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+use zklink_sdk_provider::error::RpcError;
+
+/// The interfaces of the responses are generated by the `Tsify` derives of the provider, the
+/// types serialized by the custom serde impls are declared here.
+#[wasm_bindgen(typescript_custom_section)]
+const RPC_RESPONSE_TYPES: &'static str = r#"
+/** The error member of the JSON-RPC response, the promise is rejected with it. */
+export interface RpcErrorObject {
+    code: number;
+    message: string;
+    data?: any;
+}
+
+export type AccountId = number;
+export type BlockNumber = number;
+export type ChainId = number;
+export type MarginId = number;
+export type Nonce = number;
+export type PairId = number;
+export type SlotId = number;
+export type SubAccountId = number;
+export type TokenId = number;
+
+/** decimal string */
+export type BigDecimal = string;
+export type BigIntSerdeWrapper = string;
+export type BigUintSerdeWrapper = string;
+/** hex string */
+export type U256 = string;
+export type H256 = string;
+export type TxHash = string;
+export type PubKeyHash = string;
+export type ZkLinkAddress = string;
+/** e.g. `txExecuteResult@1` */
+export type Topic = string;
+
+/** The zklink transaction, tagged by `type`. */
+export type ZkLinkTx = { type: string; [field: string]: any };
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "string")]
+    pub type JsTxHash;
+    #[wasm_bindgen(typescript_type = "ChainResp[]")]
+    pub type ChainRespArray;
+    #[wasm_bindgen(typescript_type = "Record<number, TokenResp>")]
+    pub type TokenRespMap;
+    #[wasm_bindgen(typescript_type = "BlockNumberResp")]
+    pub type JsBlockNumberResp;
+    #[wasm_bindgen(typescript_type = "BlockResp")]
+    pub type JsBlockResp;
+    #[wasm_bindgen(typescript_type = "TxHashOrDetailResp[]")]
+    pub type TxHashOrDetailRespArray;
+    #[wasm_bindgen(typescript_type = "BlockOnChainResp")]
+    pub type JsBlockOnChainResp;
+    #[wasm_bindgen(typescript_type = "AccountInfoResp")]
+    pub type JsAccountInfoResp;
+    #[wasm_bindgen(typescript_type = "SubAccountBalances")]
+    pub type JsSubAccountBalances;
+    #[wasm_bindgen(typescript_type = "SubAccountOrders")]
+    pub type JsSubAccountOrders;
+    #[wasm_bindgen(typescript_type = "Record<number, string>")]
+    pub type TokenReserveMap;
+    #[wasm_bindgen(typescript_type = "AccountSnapshotResp")]
+    pub type JsAccountSnapshotResp;
+    #[wasm_bindgen(typescript_type = "TxResp")]
+    pub type JsTxResp;
+    #[wasm_bindgen(typescript_type = "Page<ZkLinkTxHistory>")]
+    pub type ZkLinkTxHistoryPage;
+    #[wasm_bindgen(typescript_type = "WithdrawTxResp[]")]
+    pub type WithdrawTxRespArray;
+    #[wasm_bindgen(typescript_type = "ForwardTxResp[]")]
+    pub type ForwardTxRespArray;
+    #[wasm_bindgen(typescript_type = "TxTopicEvent[]")]
+    pub type TxTopicEventArray;
+}
+
+/// Convert to the plain js object, the maps are converted to objects instead of `Map`.
+pub(crate) fn to_js_value<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    Ok(value.serialize(&serializer)?)
+}
+
+/// Get the `result` of the JSON-RPC response, the `error` member is returned as the
+/// `RpcErrorObject` to reject the promise.
+pub(crate) fn parse_rpc_response<T: DeserializeOwned>(mut response: Value) -> Result<T, JsValue> {
+    if let Some(error) = response.get_mut("error").map(Value::take) {
+        return Err(to_js_value(&error)?);
+    }
+    let result = response
+        .get_mut("result")
+        .map(Value::take)
+        .ok_or_else(|| RpcError::ParseJsonError("missing result".into()))?;
+    let result =
+        serde_json::from_value(result).map_err(|e| RpcError::ParseJsonError(e.to_string()))?;
+    Ok(result)
+}
+
+#[cfg(test)]
+#[cfg(target_arch = "wasm32")]
+mod test {
+    use super::*;
+    use js_sys::Reflect;
+    use serde_json::json;
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use zklink_sdk_provider::response::BlockNumberResp;
+
+    #[wasm_bindgen_test]
+    fn test_parse_rpc_response() {
+        let response = json!({
+            "jsonrpc": "2.0",
+            "result": {"lastBlockNumber": 10, "timestamp": 1693472232, "committed": 10, "verified": 9},
+            "id": 1
+        });
+        let result: BlockNumberResp = parse_rpc_response(response).unwrap();
+        assert_eq!(result.last_block_number, 10);
+        assert_eq!(result.verified, 9);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_rpc_response_error() {
+        // the error object rejects the promise
+        let response = json!({
+            "jsonrpc": "2.0",
+            "error": {"code": 20, "message": "Account is not exist"},
+            "id": 1
+        });
+        let error = parse_rpc_response::<BlockNumberResp>(response).unwrap_err();
+        let code = Reflect::get(&error, &JsValue::from_str("code")).unwrap();
+        assert_eq!(code.as_f64(), Some(20.0));
+        let message = Reflect::get(&error, &JsValue::from_str("message")).unwrap();
+        assert_eq!(message.as_string().unwrap(), "Account is not exist");
+
+        let response = json!({"jsonrpc": "2.0", "id": 1});
+        assert!(parse_rpc_response::<BlockNumberResp>(response).is_err());
+
+        let response = json!({"jsonrpc": "2.0", "result": {"lastBlockNumber": "10"}, "id": 1});
+        assert!(parse_rpc_response::<BlockNumberResp>(response).is_err());
+    }
+}
@@ -11,6 +11,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 use zklink_sdk_interface::signer::L1SignerType;
 use zklink_sdk_interface::signer::Signer as InterfaceSigner;
+use zklink_sdk_interface::SigningContext;
 use zklink_sdk_types::tx_type::change_pubkey::ChangePubKey as TxChangePubKey;
 use zklink_sdk_types::tx_type::change_pubkey::Create2Data as ChangePubKeyCreate2Data;
 use zklink_sdk_types::tx_type::contract::{
@@ -23,10 +24,11 @@ use zklink_sdk_types::tx_type::order_matching::{
 };
 use zklink_sdk_types::tx_type::transfer::Transfer as TxTransfer;
 use zklink_sdk_types::tx_type::withdraw::Withdraw as TxWithdraw;
+use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 
 #[wasm_bindgen]
 pub struct Signer {
-    inner: InterfaceSigner,
+    pub(crate) inner: InterfaceSigner,
 }
 
 #[wasm_bindgen]
@@ -56,6 +58,16 @@ impl Signer {
         Ok(Signer { inner })
     }
 
+    /// Sign the zklink tx of any type, `tx` is the json of `ZkLinkTx` tagged by `type`, e.g.
+    /// `{"type":"Transfer",...}`, `ctx` is the optional signing context, e.g.
+    /// `{"tokenSymbol":"USDC"}` or `{"changePubkeyAuth":{"type":"EthECDSA"}}`.
+    pub fn sign(&self, tx: JsValue, ctx: JsValue) -> Result<JsValue, JsValue> {
+        let tx: ZkLinkTx = serde_wasm_bindgen::from_value(tx)?;
+        let ctx: Option<SigningContext> = serde_wasm_bindgen::from_value(ctx)?;
+        let signature = self.inner.sign(tx, ctx.unwrap_or_default())?;
+        Ok(serde_wasm_bindgen::to_value(&signature)?)
+    }
+
     #[wasm_bindgen(js_name=getPubkey)]
     pub fn get_pubkey(&self) -> String {
         self.inner.public_key().as_hex()
@@ -6,6 +6,7 @@ use zklink_sdk_types::basic_types::pack::{
 };
 use zklink_sdk_types::basic_types::BigUint;
 use zklink_sdk_types::error::TypeError;
+use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 
 #[wasm_bindgen(js_name=closestPackableTransactionAmount)]
 pub fn closest_packable_transaction_amount(amount: &str) -> Result<String, JsValue> {
@@ -21,3 +22,17 @@ pub fn closest_packable_transaction_fee(fee: &str) -> Result<String, JsValue> {
     let packable_fee = closest_packable_fee_amount(&fee);
     Ok(packable_fee.to_string())
 }
+
+/// Returns the bytes signed by the zklink signer of the tx, `tx` is the json of `ZkLinkTx`
+#[wasm_bindgen(js_name=getZkLinkTxBytes)]
+pub fn get_zklink_tx_bytes(tx: JsValue) -> Result<Vec<u8>, JsValue> {
+    let tx: ZkLinkTx = serde_wasm_bindgen::from_value(tx)?;
+    Ok(tx.get_bytes())
+}
+
+/// Returns the hex string of the hash of the tx, `tx` is the json of `ZkLinkTx`
+#[wasm_bindgen(js_name=getZkLinkTxHash)]
+pub fn get_zklink_tx_hash(tx: JsValue) -> Result<String, JsValue> {
+    let tx: ZkLinkTx = serde_wasm_bindgen::from_value(tx)?;
+    Ok(tx.tx_hash().as_hex())
+}
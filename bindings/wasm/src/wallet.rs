@@ -1,8 +1,10 @@
 use std::str::FromStr;
+use std::time::Duration;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 use zklink_sdk_types::basic_types::BigUint;
 use zklink_sdk_types::prelude::{PubKeyHash, ZkLinkAddress, H256};
+use zklink_sdk_wallet::confirmation::WaitConfig;
 use zklink_sdk_wallet::eth::EthTxOption as InnerEthTxOption;
 use zklink_sdk_wallet::wallet::{WaitForTxStatus, Wallet as InnerWallet};
 
@@ -29,6 +31,13 @@ impl std::fmt::Display for BlockNumber {
     }
 }
 
+fn parse_optional_biguint(value: Option<String>) -> Result<Option<BigUint>, JsValue> {
+    value
+        .map(|v| BigUint::from_str(&v))
+        .transpose()
+        .map_err(|error| JsValue::from_str(&format!("error: {error}")))
+}
+
 #[wasm_bindgen]
 pub struct EthTxOption {
     inner: InnerEthTxOption,
@@ -44,6 +53,8 @@ impl EthTxOption {
         value: Option<String>,
         gas: Option<f64>,
         gas_price: Option<String>,
+        max_fee_per_gas: Option<String>,
+        max_priority_fee_per_gas: Option<String>,
     ) -> Result<EthTxOption, JsValue> {
         let value = if let Some(v) = value {
             Some(
@@ -70,6 +81,8 @@ impl EthTxOption {
             value,
             gas: gas.map(|g| g as u64),
             gas_price,
+            max_fee_per_gas: parse_optional_biguint(max_fee_per_gas)?,
+            max_priority_fee_per_gas: parse_optional_biguint(max_priority_fee_per_gas)?,
         };
         Ok(EthTxOption { inner })
     }
@@ -88,7 +101,7 @@ pub struct Wallet {
 impl Wallet {
     #[wasm_bindgen(constructor)]
     pub fn new(url: &str, private_key: &str) -> Result<Wallet, JsValue> {
-        let inner = InnerWallet::new(url, private_key);
+        let inner = InnerWallet::new(url, private_key)?;
         Ok(Wallet { inner })
     }
 
@@ -133,6 +146,34 @@ impl Wallet {
         Ok(status)
     }
 
+    /// Wait until the transaction has `confirmations` confirmations, returns the receipt and the
+    /// events emitted by the zklink contract, the `timeout` is in seconds.
+    #[wasm_bindgen(js_name=waitForConfirmedTransaction)]
+    pub async fn wait_for_confirmed_transaction(
+        &self,
+        zklink_addr: String,
+        tx_hash: String,
+        confirmations: Option<u32>,
+        timeout: Option<u32>,
+    ) -> Result<JsValue, JsValue> {
+        let zklink_addr = ZkLinkAddress::from_str(&zklink_addr)
+            .map_err(|error| JsValue::from_str(&format!("error: {error}")))?;
+        let tx_hash = H256::from_str(&tx_hash)
+            .map_err(|error| JsValue::from_str(&format!("error: {error}")))?;
+        let mut config = WaitConfig::default();
+        if let Some(confirmations) = confirmations {
+            config.confirmations = confirmations as u64;
+        }
+        if let Some(timeout) = timeout {
+            config.timeout = Duration::from_secs(timeout as u64);
+        }
+        let confirmed = self
+            .inner
+            .wait_for_confirmed_transaction(zklink_addr, tx_hash, &config)
+            .await?;
+        Ok(serde_wasm_bindgen::to_value(&confirmed)?)
+    }
+
     #[wasm_bindgen(js_name=approveERC20)]
     pub async fn approve_erc20(
         &self,
@@ -257,4 +298,20 @@ impl Wallet {
             .await?;
         Ok(hex::encode(tx_hash.as_bytes()))
     }
+
+    #[wasm_bindgen(js_name=speedUp)]
+    pub async fn speed_up(&self, tx_hash: String) -> Result<String, JsValue> {
+        let tx_hash = H256::from_str(&tx_hash)
+            .map_err(|error| JsValue::from_str(&format!("error: {error}")))?;
+        let tx_hash = self.inner.speed_up(tx_hash).await?;
+        Ok(hex::encode(tx_hash.as_bytes()))
+    }
+
+    #[wasm_bindgen(js_name=cancel)]
+    pub async fn cancel(&self, tx_hash: String) -> Result<String, JsValue> {
+        let tx_hash = H256::from_str(&tx_hash)
+            .map_err(|error| JsValue::from_str(&format!("error: {error}")))?;
+        let tx_hash = self.inner.cancel(tx_hash).await?;
+        Ok(hex::encode(tx_hash.as_bytes()))
+    }
 }
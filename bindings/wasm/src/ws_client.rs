@@ -0,0 +1,542 @@
+use crate::rpc_client::{websocket_events, RpcClient};
+use crate::rpc_response::to_js_value;
+use js_sys::{Function, Promise};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+use std::str::FromStr;
+use wasm_bindgen::prelude::{wasm_bindgen, Closure};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{CloseEvent, MessageEvent, WebSocket};
+use zklink_sdk_provider::error::RpcError;
+use zklink_sdk_provider::web_socket::proto::request::TopicsRequest;
+use zklink_sdk_provider::web_socket::proto::topic::TopicMethod;
+use zklink_sdk_provider::web_socket::ws_message::message::request::ClientOffset;
+use zklink_sdk_provider::web_socket::ws_message::message::response::{ServerMessage, TxTopicEvent};
+use zklink_sdk_provider::web_socket::ws_message::topic::Topic;
+
+/// The max limit of the events returned by `getWebSocketEvents`.
+const MAX_EVENTS_LIMIT: usize = 100;
+const INITIAL_RECONNECT_DELAY_MS: i32 = 1000;
+const MAX_RECONNECT_DELAY_MS: i32 = 30_000;
+
+#[wasm_bindgen]
+extern "C" {
+    // `setTimeout` of the global scope, which is available in both the window and the worker
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &JsValue, timeout: i32) -> JsValue;
+}
+
+struct WsHandlers {
+    _on_open: Closure<dyn FnMut()>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+    _on_error: Closure<dyn FnMut(JsValue)>,
+}
+
+struct WsState {
+    ws_url: String,
+    server_url: String,
+    socket: Option<WebSocket>,
+    handlers: Option<WsHandlers>,
+    topics: Vec<Topic>,
+    /// The index of the last delivered event of each topic, the missed events are backfilled
+    /// from it after reconnecting
+    last_topic_index: HashMap<Topic, i64>,
+    on_event: Option<Function>,
+    on_error: Option<Function>,
+    /// The events which are not consumed by `nextEvent` yet
+    events: VecDeque<JsValue>,
+    /// The `resolve` and `reject` of the pending `nextEvent` promises
+    waiters: VecDeque<(Function, Function)>,
+    /// The live events are buffered while backfilling to keep the order of the topic index
+    backfilling: usize,
+    buffered: Vec<TxTopicEvent>,
+    reconnect_delay: i32,
+    request_id: usize,
+    closed: bool,
+}
+
+impl WsState {
+    fn subscribe_message(&mut self, topics: Vec<Topic>) -> Result<String, JsValue> {
+        let request = TopicsRequest {
+            method: TopicMethod::Subscribe,
+            topics,
+            id: self.request_id,
+        };
+        self.request_id += 1;
+        Ok(serde_json::to_string(&request).map_err(RpcError::InvalidArgument)?)
+    }
+
+    fn open_socket(&self) -> Option<WebSocket> {
+        self.socket
+            .clone()
+            .filter(|socket| socket.ready_state() == WebSocket::OPEN)
+    }
+
+    /// The delay of the next reconnection, which is doubled after every reconnection until the
+    /// connection is opened.
+    fn next_reconnect_delay(&mut self) -> i32 {
+        let delay = self.reconnect_delay;
+        self.reconnect_delay = (delay * 2).min(MAX_RECONNECT_DELAY_MS);
+        delay
+    }
+
+    fn detach(&mut self) {
+        if let Some(socket) = self.socket.take() {
+            socket.set_onopen(None);
+            socket.set_onmessage(None);
+            socket.set_onclose(None);
+            socket.set_onerror(None);
+            let _ = socket.close();
+        }
+    }
+}
+
+impl Drop for WsState {
+    fn drop(&mut self) {
+        self.detach();
+    }
+}
+
+/// `WsClient` subscribes the topics of the zklink websocket server, the events are delivered to
+/// the `onEvent` callback if it's set, otherwise they are queued for `nextEvent`.
+///
+/// The client reconnects with backoff after the connection is closed, and backfills the events
+/// missed during the disconnection by `getWebSocketEvents`.
+#[wasm_bindgen]
+pub struct WsClient {
+    state: Rc<RefCell<WsState>>,
+}
+
+#[wasm_bindgen]
+impl WsClient {
+    /// The `rpc_client` is used to backfill the missed events.
+    #[wasm_bindgen(constructor)]
+    pub fn new(ws_url: String, rpc_client: &RpcClient) -> WsClient {
+        let state = WsState {
+            ws_url,
+            server_url: rpc_client.server_url().to_string(),
+            socket: None,
+            handlers: None,
+            topics: Vec::new(),
+            last_topic_index: HashMap::new(),
+            on_event: None,
+            on_error: None,
+            events: VecDeque::new(),
+            waiters: VecDeque::new(),
+            backfilling: 0,
+            buffered: Vec::new(),
+            reconnect_delay: INITIAL_RECONNECT_DELAY_MS,
+            request_id: 0,
+            closed: false,
+        };
+        WsClient {
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+
+    /// The callback is called with the `TxTopicEvent`.
+    #[wasm_bindgen(js_name=onEvent)]
+    pub fn on_event(&self, callback: Function) {
+        self.state.borrow_mut().on_event = Some(callback);
+    }
+
+    #[wasm_bindgen(js_name=onError)]
+    pub fn on_error(&self, callback: Function) {
+        self.state.borrow_mut().on_error = Some(callback);
+    }
+
+    /// Subscribe the topic, e.g. `txExecuteResult@1`, the events from
+    /// `from_topic_index_included` are backfilled if it's given.
+    pub fn subscribe(
+        &self,
+        topic: String,
+        from_topic_index_included: Option<f64>,
+    ) -> Result<(), JsValue> {
+        let topic = Topic::from_str(&topic).map_err(|_e| RpcError::InvalidInputParameter)?;
+        let mut state = self.state.borrow_mut();
+        if let Some(from) = from_topic_index_included {
+            state
+                .last_topic_index
+                .insert(topic.clone(), from as i64 - 1);
+        }
+        if !state.topics.contains(&topic) {
+            state.topics.push(topic.clone());
+        }
+        // the topics are subscribed when the connection is opened otherwise
+        if let Some(socket) = state.open_socket() {
+            let message = state.subscribe_message(vec![topic.clone()])?;
+            drop(state);
+            socket.send_with_str(&message)?;
+            spawn_local(backfill(self.state.clone(), vec![topic]));
+        }
+        Ok(())
+    }
+
+    pub fn connect(&self) -> Result<(), JsValue> {
+        self.state.borrow_mut().closed = false;
+        connect(&self.state)
+    }
+
+    /// Get the next event, the promise is rejected if the client is closed.
+    #[wasm_bindgen(js_name=nextEvent)]
+    pub fn next_event(&self) -> Promise {
+        let mut state = self.state.borrow_mut();
+        if let Some(event) = state.events.pop_front() {
+            return Promise::resolve(&event);
+        }
+        if state.closed {
+            return Promise::reject(&JsValue::from_str("error: websocket is closed"));
+        }
+        Promise::new(&mut |resolve, reject| state.waiters.push_back((resolve, reject)))
+    }
+
+    /// The index of the last delivered event of the topic.
+    #[wasm_bindgen(js_name=lastTopicIndex)]
+    pub fn last_topic_index(&self, topic: String) -> Result<Option<f64>, JsValue> {
+        let topic = Topic::from_str(&topic).map_err(|_e| RpcError::InvalidInputParameter)?;
+        let state = self.state.borrow();
+        Ok(state
+            .last_topic_index
+            .get(&topic)
+            .map(|index| *index as f64))
+    }
+
+    pub fn close(&self) {
+        let waiters = {
+            let mut state = self.state.borrow_mut();
+            state.closed = true;
+            state.detach();
+            std::mem::take(&mut state.waiters)
+        };
+        let error = JsValue::from_str("error: websocket is closed");
+        for (_, reject) in waiters {
+            let _ = reject.call1(&JsValue::NULL, &error);
+        }
+    }
+}
+
+fn connect(state: &Rc<RefCell<WsState>>) -> Result<(), JsValue> {
+    let socket = WebSocket::new(&state.borrow().ws_url)?;
+    // the handlers only hold the weak reference of the state, or the state is never dropped
+    let weak = Rc::downgrade(state);
+    let on_open = {
+        let weak = weak.clone();
+        Closure::<dyn FnMut()>::new(move || {
+            if let Some(state) = weak.upgrade() {
+                handle_open(&state);
+            }
+        })
+    };
+    let on_message = {
+        let weak = weak.clone();
+        Closure::<dyn FnMut(MessageEvent)>::new(move |event| {
+            if let Some(state) = weak.upgrade() {
+                handle_message(&state, event);
+            }
+        })
+    };
+    let on_close = {
+        let weak = weak.clone();
+        Closure::<dyn FnMut(CloseEvent)>::new(move |_event| {
+            if let Some(state) = weak.upgrade() {
+                handle_close(&state);
+            }
+        })
+    };
+    let on_error = Closure::<dyn FnMut(JsValue)>::new(move |error| {
+        if let Some(state) = weak.upgrade() {
+            report_error(&state, error);
+        }
+    });
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+    socket.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+    let mut state = state.borrow_mut();
+    state.detach();
+    state.socket = Some(socket);
+    state.handlers = Some(WsHandlers {
+        _on_open: on_open,
+        _on_message: on_message,
+        _on_close: on_close,
+        _on_error: on_error,
+    });
+    Ok(())
+}
+
+fn handle_open(state: &Rc<RefCell<WsState>>) {
+    let (socket, topics, message) = {
+        let mut state = state.borrow_mut();
+        state.reconnect_delay = INITIAL_RECONNECT_DELAY_MS;
+        if state.topics.is_empty() {
+            return;
+        }
+        let Some(socket) = state.open_socket() else {
+            return;
+        };
+        let topics = state.topics.clone();
+        let message = state.subscribe_message(topics.clone());
+        (socket, topics, message)
+    };
+    if let Err(e) = message.and_then(|message| socket.send_with_str(&message)) {
+        return report_error(state, e);
+    }
+    spawn_local(backfill(state.clone(), topics));
+}
+
+fn handle_message(state: &Rc<RefCell<WsState>>, event: MessageEvent) {
+    if let Some(text) = event.data().as_string() {
+        handle_text(state, &text);
+    }
+}
+
+fn handle_text(state: &Rc<RefCell<WsState>>, text: &str) {
+    match ServerMessage::from_str(text) {
+        Ok(ServerMessage::Event(event)) => {
+            let mut state_mut = state.borrow_mut();
+            if state_mut.backfilling > 0 {
+                state_mut.buffered.push(event);
+            } else {
+                drop(state_mut);
+                deliver(state, event);
+            }
+        }
+        Ok(ServerMessage::Response(resp)) if resp.error_code != 0 => report_error(
+            state,
+            JsValue::from_str(&format!("error: {}", resp.error_msg)),
+        ),
+        Ok(ServerMessage::Response(_)) => {}
+        Err(e) => report_error(state, JsValue::from_str(&format!("error: {e}"))),
+    }
+}
+
+fn handle_close(state: &Rc<RefCell<WsState>>) {
+    let delay = {
+        let mut state = state.borrow_mut();
+        state.detach();
+        if state.closed {
+            return;
+        }
+        state.next_reconnect_delay()
+    };
+    let weak = Rc::downgrade(state);
+    let reconnect = Closure::once_into_js(move || {
+        let Some(state) = weak.upgrade() else {
+            return;
+        };
+        if state.borrow().closed {
+            return;
+        }
+        if let Err(e) = connect(&state) {
+            report_error(&state, e);
+        }
+    });
+    set_timeout(&reconnect, delay);
+}
+
+/// Get the events after the last delivered event of the topics from the rpc, the live events
+/// are buffered until all the backfills are done.
+async fn backfill(state: Rc<RefCell<WsState>>, topics: Vec<Topic>) {
+    let server_url = {
+        let mut state = state.borrow_mut();
+        state.backfilling += 1;
+        state.server_url.clone()
+    };
+    for topic in topics {
+        loop {
+            let Some(last_index) = state.borrow().last_topic_index.get(&topic).copied() else {
+                break;
+            };
+            let offset = ClientOffset {
+                from_topic_index_included: last_index + 1,
+                limit: Some(MAX_EVENTS_LIMIT),
+            };
+            match websocket_events(&server_url, topic.clone(), offset).await {
+                Ok(events) => {
+                    let fetched = events.len();
+                    for event in events {
+                        deliver(&state, event);
+                    }
+                    if fetched < MAX_EVENTS_LIMIT {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    report_error(&state, e);
+                    break;
+                }
+            }
+        }
+    }
+    finish_backfill(&state);
+}
+
+/// Deliver the buffered live events after the last backfill is done.
+fn finish_backfill(state: &Rc<RefCell<WsState>>) {
+    let buffered = {
+        let mut state = state.borrow_mut();
+        state.backfilling -= 1;
+        if state.backfilling > 0 {
+            return;
+        }
+        std::mem::take(&mut state.buffered)
+    };
+    for event in buffered {
+        deliver(state, event);
+    }
+}
+
+/// Deliver the event if it's not delivered yet, the callbacks are called without borrowing the
+/// state, so they can call the methods of the client.
+fn deliver(state: &Rc<RefCell<WsState>>, event: TxTopicEvent) {
+    {
+        let mut state = state.borrow_mut();
+        let last_index = state.last_topic_index.get(&event.topic);
+        if last_index.is_some_and(|last_index| event.topic_index <= *last_index) {
+            return;
+        }
+        state
+            .last_topic_index
+            .insert(event.topic.clone(), event.topic_index);
+    }
+    let event = match to_js_value(&event) {
+        Ok(event) => event,
+        Err(e) => return report_error(state, e),
+    };
+    let receiver = {
+        let mut state = state.borrow_mut();
+        if let Some(on_event) = state.on_event.clone() {
+            on_event
+        } else if let Some((resolve, _)) = state.waiters.pop_front() {
+            resolve
+        } else {
+            state.events.push_back(event);
+            return;
+        }
+    };
+    let _ = receiver.call1(&JsValue::NULL, &event);
+}
+
+fn report_error(state: &Rc<RefCell<WsState>>, error: JsValue) {
+    let on_error = state.borrow().on_error.clone();
+    if let Some(on_error) = on_error {
+        let _ = on_error.call1(&JsValue::NULL, &error);
+    } else {
+        web_sys::console::error_1(&error);
+    }
+}
+
+#[cfg(test)]
+#[cfg(target_arch = "wasm32")]
+mod test {
+    use super::*;
+    use js_sys::Reflect;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn event(topic_index: i64) -> String {
+        format!(
+            r#"{{"topic":"priorityEvent@1","topic_index":{topic_index},"data":{{"txHash":"0x0cbeabac1a2257fb095c2465e148570e32793345442b39bf64cad4ed87475f9b","tx":{{"type":"Deposit","fromChainId":1,"from":"0xafaff3ad1a0425d792432d9ecd1c3e26ef2c42e9","subAccountId":1,"l2TargetToken":18,"l1SourceToken":18,"to":"0xafaff3ad1a0425d792432d9ecd1c3e26ef2c42e9","amount":"100","serialId":{topic_index},"l2Hash":"0xe35f3a39d542f6d276c2f203e8fd64fcb8bf5db062b71ccacf45d5ecd9d456f3","ethHash":null}}}},"timestamp":1696743981000000}}"#
+        )
+    }
+
+    fn topic_event(topic_index: i64) -> TxTopicEvent {
+        match ServerMessage::from_str(&event(topic_index)).unwrap() {
+            ServerMessage::Event(event) => event,
+            ServerMessage::Response(_) => panic!("expect event"),
+        }
+    }
+
+    fn client() -> WsClient {
+        let rpc_client = RpcClient::new("testnet", None).unwrap();
+        WsClient::new("ws://127.0.0.1:1".to_string(), &rpc_client)
+    }
+
+    /// The topic indexes of the events queued for `nextEvent`.
+    fn queued(client: &WsClient) -> Vec<i64> {
+        client
+            .state
+            .borrow()
+            .events
+            .iter()
+            .map(|event| {
+                Reflect::get(event, &JsValue::from_str("topic_index"))
+                    .unwrap()
+                    .as_f64()
+                    .unwrap() as i64
+            })
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_dedupe_events() {
+        let client = client();
+        for topic_index in [1, 2, 2, 1, 3] {
+            handle_text(&client.state, &event(topic_index));
+        }
+        assert_eq!(queued(&client), vec![1, 2, 3]);
+        let last_index = client.last_topic_index("priorityEvent@1".to_string());
+        assert_eq!(last_index.unwrap(), Some(3.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_buffer_live_events_while_backfilling() {
+        let client = client();
+        client
+            .subscribe("priorityEvent@1".to_string(), Some(3.0))
+            .unwrap();
+        client.state.borrow_mut().backfilling = 1;
+        // the live events are buffered until the backfill is done
+        handle_text(&client.state, &event(5));
+        handle_text(&client.state, &event(4));
+        assert!(queued(&client).is_empty());
+        assert_eq!(client.state.borrow().buffered.len(), 2);
+
+        // the backfilled events are delivered in order and the buffered duplicates are dropped
+        deliver(&client.state, topic_event(3));
+        deliver(&client.state, topic_event(4));
+        finish_backfill(&client.state);
+        assert_eq!(queued(&client), vec![3, 4, 5]);
+        assert!(client.state.borrow().buffered.is_empty());
+        assert_eq!(client.state.borrow().backfilling, 0);
+
+        handle_text(&client.state, &event(6));
+        assert_eq!(queued(&client), vec![3, 4, 5, 6]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_reconnect() {
+        let client = client();
+        client
+            .subscribe("priorityEvent@1".to_string(), Some(10.0))
+            .unwrap();
+        // the backfill after reconnecting starts from the given index
+        let last_index = client.last_topic_index("priorityEvent@1".to_string());
+        assert_eq!(last_index.unwrap(), Some(9.0));
+
+        let delays: Vec<_> = (0..7)
+            .map(|_| client.state.borrow_mut().next_reconnect_delay())
+            .collect();
+        assert_eq!(delays, vec![1000, 2000, 4000, 8000, 16000, 30000, 30000]);
+
+        // the delay is reset after the connection is opened
+        handle_open(&client.state);
+        assert_eq!(
+            client.state.borrow().reconnect_delay,
+            INITIAL_RECONNECT_DELAY_MS
+        );
+
+        // the closed client doesn't reconnect
+        client.close();
+        handle_close(&client.state);
+        assert!(client.state.borrow().socket.is_none());
+        assert_eq!(
+            client.state.borrow().reconnect_delay,
+            INITIAL_RECONNECT_DELAY_MS
+        );
+        assert!(client.state.borrow().closed);
+    }
+}
@@ -0,0 +1,52 @@
+package main
+
+import (
+	"fmt"
+	sdk "github.com/zkLinkProtocol/zklink_sdk/go_example/generated/uniffi/zklink_sdk"
+	"math/big"
+)
+
+func HighLevelDeposit() {
+	privateKey := "0xbe725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4"
+	// the layer1 rpc url and the zklink main contract on it
+	l1Url := "https://rpc.ankr.com/eth_goerli"
+	mainContract := sdk.ZkLinkAddress("0x5505a8cD4594Dbf79d8C59C0Df1414AB871CA896")
+	depositTo := sdk.ZkLinkAddress("0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9")
+	subAccountId := uint8(1)
+
+	wallet, err := sdk.NewWallet(l1Url, privateKey)
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	balance, err := wallet.GetBalance()
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	fmt.Println("layer1 balance:", balance)
+
+	value := *big.NewInt(1000000000000000)
+	ethParams := sdk.EthTxOption{
+		IsSupportEip1559: true,
+		To:               mainContract,
+		Value:            &value,
+	}
+	txHash, err := wallet.DepositEth(subAccountId, depositTo, ethParams, false)
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	fmt.Println("deposit tx hash:", txHash)
+	retries := uint32(60)
+	status, err := wallet.WaitForTransaction(txHash, &retries)
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	fmt.Println("deposit tx status:", status)
+}
+
+func main() {
+	HighLevelDeposit()
+}
@@ -0,0 +1,42 @@
+package main
+
+import (
+	"fmt"
+	sdk "github.com/zkLinkProtocol/zklink_sdk/go_example/generated/uniffi/zklink_sdk"
+	"math/big"
+)
+
+func HighLevelClient() {
+	privateKey := "0xbe725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4"
+	// the client fetches the account id and the nonce of the signer's address
+	client, err := sdk.NewZkLinkClient(privateKey, sdk.L1SignerTypeEth{}, sdk.NetworkTestNet, nil)
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	toAddress := sdk.ZkLinkAddress("0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9")
+	txHash, err := client.Transfer(sdk.SubAccountId(1), toAddress, sdk.SubAccountId(1), sdk.TokenId(18), *big.NewInt(1000000), *big.NewInt(1000))
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	fmt.Println("transfer tx hash:", txHash)
+
+	txHash, err = client.Withdraw(sdk.SubAccountId(1), sdk.ChainId(5), toAddress, sdk.TokenId(17), sdk.TokenId(17), *big.NewInt(1000000), *big.NewInt(1000), true, uint16(50))
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	fmt.Println("withdraw tx hash:", txHash)
+
+	accountInfo, err := client.AccountInfo()
+	if err != nil {
+		fmt.Println(err)
+		return
+	}
+	fmt.Println("account nonce:", accountInfo.Nonce)
+}
+
+func main() {
+	HighLevelClient()
+}
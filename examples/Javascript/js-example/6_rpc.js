@@ -49,11 +49,12 @@ async function main() {
         // 14.getAccountTransactionHistory
         let history = await client.getAccountTransactionHistory(wasm.ZkLinkTxType.Deposit,"0x12aFF993702B5d623977A9044686Fa1A2B0c2147",0n,5);
         console.log(history);
-        // 15.getFastWithdrawTxs
-        let fast_withdraw_txs = await client.getFastWithdrawTxs(1696743981000n,10);
-        console.log(fast_withdraw_txs);
+        // 15.getWithdrawTxs
+        let withdraw_txs = await client.getWithdrawTxs(1696743981000n,10);
+        console.log(withdraw_txs);
         // 16.pullForwardTxs
     } catch (error) {
+        // the json rpc error is rejected as `{code, message, data}`
         console.error(error);
     }
 
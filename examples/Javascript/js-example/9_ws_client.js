@@ -0,0 +1,29 @@
+import init, *  as wasm  from "./web-dist/zklink-sdk-web.js";
+async function main() {
+    await init();
+    try {
+        let rpc_client = new wasm.RpcClient("testnet");
+        // replace with the url of the zklink websocket server
+        let ws_url = "ws://127.0.0.1:8080";
+        // the rpc client is used to backfill the events missed while reconnecting
+        let ws_client = new wasm.WsClient(ws_url, rpc_client);
+        ws_client.onError((error) => console.error(error));
+        // subscribe the executed txs of the sub account 1 from the topic index 0
+        ws_client.subscribe("txExecuteResult@1", 0);
+        ws_client.subscribe("priorityEvent@1");
+        ws_client.connect();
+
+        // the events are queued for `nextEvent` if the `onEvent` callback is not set
+        for (let i = 0; i < 10; i++) {
+            let event = await ws_client.nextEvent();
+            console.log(event.topic, event.topic_index, event.data.type);
+        }
+        console.log(ws_client.lastTopicIndex("txExecuteResult@1"));
+        ws_client.close();
+    } catch (error) {
+        console.error(error);
+    }
+
+}
+
+main();
@@ -0,0 +1,31 @@
+const {Order} = require('./node-dist/zklink-sdk-node');
+const {SignerPool} = require('./node-dist/signer_pool');
+
+async function testSignerPool() {
+    const private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+    // the workers sign the orders off the event loop
+    const pool = new SignerPool(private_key, { size: 4 });
+    try {
+        await pool.init();
+        let orders = [];
+        for (let nonce = 1; nonce <= 1000; nonce++) {
+            let order = new Order(5,20,1,nonce,18,17,"10000000000000","10000000000",true,5,3,false);
+            // the wasm objects can't be posted to the workers, post the plain objects
+            orders.push(order.json_value());
+        }
+        const start = Date.now();
+        let signed_orders = await Promise.all(orders.map((order) => pool.createSignedOrder(order)));
+        console.log(`signed ${signed_orders.length} orders in ${Date.now() - start}ms`);
+        console.log(signed_orders[0]);
+    } catch (error) {
+        console.error(error);
+    } finally {
+        await pool.close();
+    }
+}
+
+async function main() {
+    await testSignerPool();
+}
+
+main();
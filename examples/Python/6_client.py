@@ -0,0 +1,21 @@
+import zklink_sdk as sdk
+
+def main():
+    private_key = "0xbe725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4"
+    # the client fetches the account id and the nonce of the signer's address
+    client = sdk.ZkLinkClient(private_key, sdk.L1SignerType.ETH(), sdk.Network.TEST_NET, None)
+    to_address = "0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9"
+
+    tx_hash = client.transfer(1, to_address, 1, 18, "1000000", "1000")
+    print("transfer tx hash:", tx_hash)
+
+    tx_hash = client.withdraw(1, 5, to_address, 17, 17, "1000000", "1000", True, 50)
+    print("withdraw tx hash:", tx_hash)
+
+    tx_hash = client.change_pubkey(1, 17, "1000", sdk.ChangePubKeyAuthRequest.ETH_ECDSA())
+    print("change pubkey tx hash:", tx_hash)
+
+    print(client.account_info())
+
+if __name__ == "__main__":
+    main()
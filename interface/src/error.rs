@@ -15,6 +15,8 @@ pub enum SignError {
     StarkSigningError(#[from] StarkSignerError),
     #[error("Incorrect tx format")]
     IncorrectTx,
+    #[error("Missing signing context: {0}")]
+    MissingSigningContext(&'static str),
 }
 
 #[cfg(target_arch = "wasm32")]
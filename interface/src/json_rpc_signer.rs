@@ -6,6 +6,7 @@ use crate::sign_forced_exit::sign_forced_exit;
 use crate::sign_order_matching::sign_order_matching;
 use crate::sign_transfer::{sign_eth_transfer, sign_starknet_transfer};
 use crate::sign_withdraw::{sign_eth_withdraw, sign_starknet_withdraw};
+use crate::{ChangePubKeyAuthRequest, SigningContext};
 use zklink_sdk_signers::eth_signer::json_rpc_signer::{
     JsonRpcSigner as EthJsonRpcSigner, Signer as EthereumAccountSigner,
 };
@@ -31,6 +32,7 @@ use zklink_sdk_types::tx_type::forced_exit::ForcedExit;
 use zklink_sdk_types::tx_type::order_matching::{Order, OrderMatching};
 use zklink_sdk_types::tx_type::transfer::Transfer;
 use zklink_sdk_types::tx_type::withdraw::Withdraw;
+use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 use zklink_sdk_types::tx_type::ZkSignatureTrait;
 
 pub enum JsonRpcAccountSigner {
@@ -237,6 +239,39 @@ impl JsonRpcSigner {
         Ok(signature)
     }
 
+    /// Sign the `ZkLinkTx` of any type, the layer1 signature is signed too if the tx requires it.
+    ///
+    /// The fields of `ctx` required by the tx must be set, otherwise
+    /// `SignError::MissingSigningContext` is returned. The starknet chain id and address are not
+    /// required since they are known by the starknet json rpc signer.
+    pub async fn sign(&self, tx: ZkLinkTx, ctx: SigningContext) -> Result<TxSignature, SignError> {
+        match tx {
+            ZkLinkTx::ChangePubKey(tx) => match ctx.change_pubkey_auth()? {
+                ChangePubKeyAuthRequest::Onchain => {
+                    self.sign_change_pubkey_with_onchain_auth_data(*tx)
+                }
+                ChangePubKeyAuthRequest::EthECDSA => {
+                    self.sign_change_pubkey_with_eth_ecdsa_auth(*tx).await
+                }
+                ChangePubKeyAuthRequest::EthCreate2 { data } => {
+                    self.sign_change_pubkey_with_create2data_auth(*tx, data)
+                }
+            },
+            ZkLinkTx::Transfer(tx) => self.sign_transfer(*tx, ctx.token_symbol()?).await,
+            ZkLinkTx::Withdraw(tx) => self.sign_withdraw(*tx, ctx.token_symbol()?).await,
+            ZkLinkTx::ForcedExit(tx) => self.sign_forced_exit(*tx),
+            ZkLinkTx::OrderMatching(tx) => self.sign_order_matching(*tx),
+            ZkLinkTx::AutoDeleveraging(tx) => self.sign_auto_deleveraging(*tx),
+            ZkLinkTx::ContractMatching(tx) => self.sign_contract_matching(*tx),
+            ZkLinkTx::Funding(tx) => self.sign_funding(*tx),
+            ZkLinkTx::Liquidation(tx) => self.sign_liquidation(*tx),
+            // the priority txs and the global var updates are not signed by the zklink signer
+            ZkLinkTx::Deposit(_) | ZkLinkTx::FullExit(_) | ZkLinkTx::UpdateGlobalVar(_) => {
+                Err(SignError::IncorrectTx)
+            }
+        }
+    }
+
     #[inline]
     pub fn create_signed_contract(&self, contract: &Contract) -> Result<Contract, SignError> {
         let mut contract = contract.clone();
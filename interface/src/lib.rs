@@ -1,3 +1,5 @@
+use crate::error::SignError;
+use serde::Deserialize;
 use zklink_sdk_types::tx_type::change_pubkey::Create2Data;
 
 pub mod error;
@@ -15,8 +17,38 @@ pub mod sign_withdraw;
 #[cfg(not(feature = "web"))]
 pub mod signer;
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
 pub enum ChangePubKeyAuthRequest {
     Onchain,
     EthECDSA,
     EthCreate2 { data: Create2Data },
 }
+
+/// The context of signing the `ZkLinkTx` by `sign`, only the fields required by the tx are needed.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SigningContext {
+    /// The token symbol in the layer1 signature of `Transfer` and `Withdraw`.
+    pub token_symbol: Option<String>,
+    /// The chain id of the starknet signer, required by `Transfer` and `Withdraw` if the layer1
+    /// signer is a starknet signer.
+    pub starknet_chain_id: Option<String>,
+    /// The account address of the starknet signer, required together with `starknet_chain_id`.
+    pub starknet_addr: Option<String>,
+    /// The auth of the new public key, required by `ChangePubKey`.
+    pub change_pubkey_auth: Option<ChangePubKeyAuthRequest>,
+}
+
+impl SigningContext {
+    pub(crate) fn token_symbol(&self) -> Result<&str, SignError> {
+        self.token_symbol
+            .as_deref()
+            .ok_or(SignError::MissingSigningContext("token_symbol"))
+    }
+
+    pub(crate) fn change_pubkey_auth(self) -> Result<ChangePubKeyAuthRequest, SignError> {
+        self.change_pubkey_auth
+            .ok_or(SignError::MissingSigningContext("change_pubkey_auth"))
+    }
+}
@@ -4,7 +4,8 @@ use crate::sign_forced_exit::sign_forced_exit;
 use crate::sign_liquidation::sign_liquidation;
 use crate::sign_transfer::{sign_eth_transfer, sign_starknet_transfer};
 use crate::sign_withdraw::{sign_eth_withdraw, sign_starknet_withdraw};
-use zklink_sdk_types::prelude::{PubKeyHash, TxSignature};
+use crate::{ChangePubKeyAuthRequest, SigningContext};
+use zklink_sdk_types::prelude::{PubKeyHash, TxSignature, ZkLinkTx};
 
 use crate::sign_change_pubkey::{
     do_sign_change_pubkey_with_create2data_auth, do_sign_change_pubkey_with_eth_ecdsa_auth,
@@ -244,6 +245,77 @@ impl Signer {
         Ok(signature)
     }
 
+    /// Sign the `ZkLinkTx` of any type, the layer1 signature is signed too if the tx requires it.
+    ///
+    /// The fields of `ctx` required by the tx must be set, otherwise
+    /// `SignError::MissingSigningContext` is returned.
+    pub fn sign(&self, tx: ZkLinkTx, ctx: SigningContext) -> Result<TxSignature, SignError> {
+        match tx {
+            ZkLinkTx::ChangePubKey(tx) => match ctx.change_pubkey_auth()? {
+                ChangePubKeyAuthRequest::Onchain => {
+                    do_sign_change_pubkey_with_onchain_auth_data(*tx, &self.zklink_signer)
+                }
+                ChangePubKeyAuthRequest::EthECDSA => match &self.layer1_signer {
+                    Layer1Sginer::EthSigner(signer) => {
+                        do_sign_change_pubkey_with_eth_ecdsa_auth(signer, &self.zklink_signer, *tx)
+                    }
+                    Layer1Sginer::StarknetSigner(_) => Err(EthSignerError::InvalidEthSigner.into()),
+                },
+                ChangePubKeyAuthRequest::EthCreate2 { data } => {
+                    do_sign_change_pubkey_with_create2data_auth(*tx, data, &self.zklink_signer)
+                }
+            },
+            ZkLinkTx::Transfer(tx) => {
+                let token_symbol = ctx.token_symbol()?;
+                match &self.layer1_signer {
+                    Layer1Sginer::EthSigner(signer) => {
+                        sign_eth_transfer(signer, &self.zklink_signer, *tx, token_symbol)
+                    }
+                    Layer1Sginer::StarknetSigner(signer) => {
+                        let (chain_id, addr) = starknet_account(&ctx)?;
+                        sign_starknet_transfer(
+                            signer,
+                            &self.zklink_signer,
+                            *tx,
+                            token_symbol,
+                            chain_id,
+                            addr,
+                        )
+                    }
+                }
+            }
+            ZkLinkTx::Withdraw(tx) => {
+                let token_symbol = ctx.token_symbol()?;
+                match &self.layer1_signer {
+                    Layer1Sginer::EthSigner(signer) => {
+                        sign_eth_withdraw(signer, &self.zklink_signer, *tx, token_symbol)
+                    }
+                    Layer1Sginer::StarknetSigner(signer) => {
+                        let (chain_id, addr) = starknet_account(&ctx)?;
+                        sign_starknet_withdraw(
+                            signer,
+                            &self.zklink_signer,
+                            *tx,
+                            token_symbol,
+                            chain_id,
+                            addr,
+                        )
+                    }
+                }
+            }
+            ZkLinkTx::ForcedExit(tx) => Ok(sign_forced_exit(&self.zklink_signer, *tx)?),
+            ZkLinkTx::OrderMatching(tx) => Ok(sign_order_matching(&self.zklink_signer, *tx)?),
+            ZkLinkTx::AutoDeleveraging(tx) => Ok(sign_auto_deleveraging(&self.zklink_signer, *tx)?),
+            ZkLinkTx::ContractMatching(tx) => Ok(sign_contract_matching(&self.zklink_signer, *tx)?),
+            ZkLinkTx::Funding(tx) => Ok(sign_funding(&self.zklink_signer, *tx)?),
+            ZkLinkTx::Liquidation(tx) => Ok(sign_liquidation(&self.zklink_signer, *tx)?),
+            // the priority txs and the global var updates are not signed by the zklink signer
+            ZkLinkTx::Deposit(_) | ZkLinkTx::FullExit(_) | ZkLinkTx::UpdateGlobalVar(_) => {
+                Err(SignError::IncorrectTx)
+            }
+        }
+    }
+
     #[inline]
     #[cfg(not(feature = "ffi"))]
     pub fn create_signed_order(&self, order: &Order) -> Result<Order, SignError> {
@@ -260,3 +332,120 @@ impl Signer {
         Ok(contract)
     }
 }
+
+fn starknet_account(ctx: &SigningContext) -> Result<(&str, &str), SignError> {
+    let chain_id = ctx
+        .starknet_chain_id
+        .as_deref()
+        .ok_or(SignError::MissingSigningContext("starknet_chain_id"))?;
+    let addr = ctx
+        .starknet_addr
+        .as_deref()
+        .ok_or(SignError::MissingSigningContext("starknet_addr"))?;
+    Ok((chain_id, addr))
+}
+
+#[cfg(not(feature = "ffi"))]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+    use zklink_sdk_types::basic_types::BigUint;
+    use zklink_sdk_types::prelude::*;
+
+    #[test]
+    fn test_sign_zklink_tx() {
+        let private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let signer = Signer::new(private_key, L1SignerType::Eth).unwrap();
+        let tx = TransferBuilder {
+            account_id: AccountId(1),
+            from_sub_account_id: SubAccountId(1),
+            to_sub_account_id: SubAccountId(1),
+            to_address: ZkLinkAddress::from_str("0x0000000000000000000000000000000000000000")
+                .unwrap(),
+            token: TokenId(1),
+            amount: BigUint::from_str("1000000000000000000").unwrap(),
+            fee: BigUint::from_str("10000000000").unwrap(),
+            nonce: Nonce(1),
+            timestamp: TimeStamp(1646101085),
+        }
+        .build();
+
+        // the layer1 signature of the transfer requires the token symbol
+        let err = signer
+            .sign(tx.clone().into(), SigningContext::default())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SignError::MissingSigningContext("token_symbol")
+        ));
+
+        let ctx = SigningContext {
+            token_symbol: Some("USD".to_string()),
+            ..Default::default()
+        };
+        let signature = signer.sign(tx.clone().into(), ctx).unwrap();
+        let expected = signer.sign_transfer(tx.clone(), "USD", None, None).unwrap();
+        assert_eq!(signature.layer1_signature, expected.layer1_signature);
+        match (signature.tx, expected.tx) {
+            (ZkLinkTx::Transfer(tx), ZkLinkTx::Transfer(expected)) => {
+                assert_eq!(
+                    tx.signature.signature.as_hex(),
+                    expected.signature.signature.as_hex()
+                )
+            }
+            _ => panic!("must be transfer"),
+        }
+
+        // the priority txs can't be signed
+        let deposit = Deposit::default();
+        assert!(matches!(
+            signer.sign(deposit.into(), SigningContext::default()),
+            Err(SignError::IncorrectTx)
+        ));
+
+        // the change pubkey requires the auth
+        let change_pubkey = ChangePubKey::default();
+        assert!(matches!(
+            signer.sign(change_pubkey.clone().into(), SigningContext::default()),
+            Err(SignError::MissingSigningContext("change_pubkey_auth"))
+        ));
+
+        let stark_signer = Signer::new(
+            "0x02c5dbad71c92a45cc4b40573ae661f8147869a91d57b8d9b8f48c8af7f83159",
+            L1SignerType::Starknet {
+                chain_id: "SN_GOERLI".to_string(),
+                address: "0x04A69b67bcaBfA7D3CCb96e1d25C2e6fC93589fE24A6fD04566B8700ff97a71a"
+                    .to_string(),
+            },
+        )
+        .unwrap();
+        // the starknet signer requires the starknet chain id and address
+        let ctx = SigningContext {
+            token_symbol: Some("USD".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            stark_signer.sign(tx.clone().into(), ctx.clone()),
+            Err(SignError::MissingSigningContext("starknet_chain_id"))
+        ));
+        let ctx = SigningContext {
+            starknet_chain_id: Some("SN_GOERLI".to_string()),
+            ..ctx
+        };
+        assert!(matches!(
+            stark_signer.sign(tx.into(), ctx),
+            Err(SignError::MissingSigningContext("starknet_addr"))
+        ));
+
+        // the ethereum ECDSA auth can't be signed by the starknet signer
+        let ctx = SigningContext {
+            change_pubkey_auth: Some(ChangePubKeyAuthRequest::EthECDSA),
+            ..Default::default()
+        };
+        assert!(matches!(
+            stark_signer.sign(change_pubkey.into(), ctx),
+            Err(SignError::EthSigningError(EthSignerError::InvalidEthSigner))
+        ));
+    }
+}
@@ -17,6 +17,21 @@ pub enum RpcError {
     ParseJsonError(String),
     #[error("Get error result: {0}")]
     GetErrorResult(String),
+    #[error("Rpc client error: {0}")]
+    ClientError(String),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<jsonrpsee::core::client::Error> for RpcError {
+    fn from(error: jsonrpsee::core::client::Error) -> Self {
+        use jsonrpsee::core::client::Error;
+        match error {
+            // the error object returned by the server, the same as the `error` of the response
+            Error::Call(error) => RpcError::GetErrorResult(serde_json::json!(error).to_string()),
+            Error::ParseError(error) => RpcError::ParseJsonError(error.to_string()),
+            error => RpcError::ClientError(error.to_string()),
+        }
+    }
 }
 
 impl From<RpcError> for JsValue {
@@ -5,12 +5,17 @@ pub mod response;
 mod rpc;
 pub mod web_socket;
 
-#[cfg(not(any(feature = "ffi", target_arch = "wasm32")))]
-mod not_ffi {
-    pub use crate::rpc::{ZkLinkRpcClient, ZkLinkRpcServer};
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::rpc::{ZkLinkRpcClient, ZkLinkRpcServer};
 
+#[cfg(not(target_arch = "wasm32"))]
+mod http_provider {
+    use crate::error::RpcError;
     use crate::network::Network;
+    use jsonrpsee::core::client::ClientT;
+    use jsonrpsee::core::params::ArrayParams;
     use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+    use serde_json::Value;
     use std::ops::Deref;
     use std::time::Duration;
 
@@ -29,15 +34,99 @@ mod not_ffi {
 
     impl ZkLinkRpcProvider {
         pub fn new(network: Network, timeout: Option<Duration>) -> Self {
+            Self::new_with_url(network.url(), timeout).unwrap()
+        }
+
+        pub fn new_with_url(url: &str, timeout: Option<Duration>) -> Result<Self, RpcError> {
             let mut builder = HttpClientBuilder::default();
             if let Some(timeout) = timeout {
                 builder = builder.request_timeout(timeout);
             }
-            let client = builder.build(network.url()).unwrap();
-            Self(client)
+            let client = builder.build(url)?;
+            Ok(Self(client))
+        }
+
+        /// Send the request of any rpc `method` with the positional `params`, the result is
+        /// returned as json.
+        pub async fn raw_request(
+            &self,
+            method: &str,
+            params: Vec<Value>,
+        ) -> Result<Value, RpcError> {
+            let mut array_params = ArrayParams::new();
+            for param in params {
+                array_params
+                    .insert(param)
+                    .map_err(RpcError::InvalidArgument)?;
+            }
+            Ok(self.0.request(method, array_params).await?)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use crate::ZkLinkRpcClient;
+        use jsonrpsee::server::{RpcModule, Server};
+        use jsonrpsee::types::ErrorObjectOwned;
+        use serde_json::json;
+
+        async fn mock_server() -> (String, jsonrpsee::server::ServerHandle) {
+            let mut module = RpcModule::new(());
+            module
+                .register_method("getLatestBlockNumber", |_, _| {
+                    Ok::<_, ErrorObjectOwned>(json!({
+                        "lastBlockNumber": 10,
+                        "timestamp": 1696595303,
+                        "committed": 9,
+                        "verified": 8
+                    }))
+                })
+                .unwrap();
+            module
+                .register_method("getAccount", |_, _| {
+                    Err::<Value, _>(ErrorObjectOwned::owned(
+                        -32602,
+                        "account not found",
+                        None::<()>,
+                    ))
+                })
+                .unwrap();
+            let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", server.local_addr().unwrap());
+            (url, server.start(module))
+        }
+
+        #[tokio::test]
+        async fn test_provider() {
+            let (url, _handle) = mock_server().await;
+            let provider = ZkLinkRpcProvider::new_with_url(&url, None).unwrap();
+
+            let block_info = provider.block_info().await.unwrap();
+            assert_eq!(block_info.last_block_number, 10);
+            assert_eq!(block_info.verified, 8);
+            let block_info = provider
+                .raw_request("getLatestBlockNumber", vec![])
+                .await
+                .unwrap();
+            assert_eq!(block_info["committed"], 9);
+
+            // the error object of the server
+            let err = provider
+                .raw_request("getAccount", vec![json!(1)])
+                .await
+                .unwrap_err();
+            assert!(matches!(err, RpcError::GetErrorResult(e) if e.contains("account not found")));
+            // the method is not registered by the server
+            let err = provider.get_eth_property().await.unwrap_err();
+            assert!(
+                matches!(RpcError::from(err), RpcError::GetErrorResult(e) if e.contains("-32601"))
+            );
+
+            assert!(ZkLinkRpcProvider::new_with_url("invalid url", None).is_err());
         }
     }
 }
 
-#[cfg(not(any(feature = "ffi", target_arch = "wasm32")))]
-pub use not_ffi::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use http_provider::*;
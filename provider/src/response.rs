@@ -3,6 +3,8 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::{declare, Tsify};
 
 use chrono::serde::{ts_microseconds, ts_microseconds_option};
 use pythnet_sdk::wire::v1::AccumulatorUpdateData;
@@ -14,15 +16,23 @@ use zklink_sdk_types::prelude::{
 };
 use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type SubAccountNonces = HashMap<SubAccountId, Nonce>;
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type SubAccountBalances = HashMap<SubAccountId, HashMap<TokenId, BigIntSerdeWrapper>>;
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type SubAccountPositions = HashMap<SubAccountId, HashMap<PairId, ResponsePosition>>;
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type SubAccountOrders = HashMap<SubAccountId, HashMap<SlotId, ResponseTidyOrder>>;
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type SubAccountGlobalVars = HashMap<SubAccountId, GlobalVarsResp>;
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type MarginParams = HashMap<MarginId, ResponseMarginParams>;
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type ContractParams = HashMap<PairId, ResponseContractParams>;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ChainResp {
     pub chain_id: ChainId,
@@ -34,6 +44,7 @@ pub struct ChainResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct TokenResp {
     /// id is used for tx signature and serialization
@@ -47,6 +58,7 @@ pub struct TokenResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ChainTokenResp {
     /// chains is used to mark which chain(s) the token can be used
@@ -60,6 +72,7 @@ pub struct ChainTokenResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct BlockNumberResp {
     pub last_block_number: u32,
@@ -69,6 +82,7 @@ pub struct BlockNumberResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct BlockResp {
     pub number: BlockNumber,
@@ -78,11 +92,13 @@ pub struct BlockResp {
     pub block_size: u64,
     pub ops_composition_number: u64,
     #[serde(with = "ts_microseconds")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
     pub timestamp: DateTime<Utc>,
     pub transactions: Vec<TxHashOrDetailResp>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(untagged)]
 pub enum TxHashOrDetailResp {
     Hash(TxHash),
@@ -90,6 +106,7 @@ pub enum TxHashOrDetailResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct BlockOnChainResp {
     pub committed: Vec<OnChainResp>,
@@ -98,6 +115,7 @@ pub struct BlockOnChainResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct OnChainResp {
     pub chain_id: ChainId,
@@ -112,6 +130,7 @@ pub enum AccountQuery {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInfoResp {
     pub id: AccountId,
@@ -122,6 +141,7 @@ pub struct AccountInfoResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct GlobalVarsResp {
     pub sub_account_id: SubAccountId,
@@ -132,6 +152,7 @@ pub struct GlobalVarsResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseMarginParams {
     pub token_id: TokenId,
@@ -141,6 +162,7 @@ pub struct ResponseMarginParams {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseContractParams {
     pub symbol: String,
@@ -151,6 +173,7 @@ pub struct ResponseContractParams {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseTidyOrder {
     pub nonce: Nonce,
@@ -164,6 +187,7 @@ impl ResponseTidyOrder {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ResponsePosition {
     pub direction: bool,
@@ -192,6 +216,7 @@ impl ResponsePosition {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct AccountSnapshotResp {
     pub id: AccountId,
@@ -206,6 +231,7 @@ pub struct AccountSnapshotResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(tag = "stateUpdateType")]
 pub enum StateUpdateResp {
     AccountUpdate(AccountUpdateResp),
@@ -234,6 +260,7 @@ impl StateUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(tag = "accountUpdateType")]
 pub enum AccountUpdateResp {
     AccountCreate(AccountCreateResp),
@@ -256,6 +283,7 @@ impl AccountUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(tag = "globalVarUpdate")]
 pub enum GlobalVarsUpdateResp {
     FeeAccountUpdate(FeeAccountUpdateResp),
@@ -276,6 +304,7 @@ impl GlobalVarsUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct FeeAccountUpdateResp {
     pub update_id: i32,
@@ -285,6 +314,7 @@ pub struct FeeAccountUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct InsuranceFundAccountUpdateResp {
     pub update_id: i32,
@@ -294,6 +324,7 @@ pub struct InsuranceFundAccountUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct MarginParamsUpdateResp {
     pub update_id: i32,
@@ -310,6 +341,7 @@ pub struct MarginParamsUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ContractParamsUpdateResp {
     pub update_id: i32,
@@ -328,6 +360,7 @@ pub struct ContractParamsUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct AccountCreateResp {
     pub update_id: i32,
@@ -336,6 +369,7 @@ pub struct AccountCreateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct AccountChangePubkeyUpdateResp {
     pub update_id: i32,
@@ -347,6 +381,7 @@ pub struct AccountChangePubkeyUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceUpdateResp {
     pub update_id: i32,
@@ -360,6 +395,7 @@ pub struct BalanceUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct OrderUpdateResp {
     pub update_id: i32,
@@ -371,6 +407,7 @@ pub struct OrderUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct PositionUpdateResp {
     pub update_id: i32,
@@ -382,6 +419,7 @@ pub struct PositionUpdateResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct TxResp {
     pub tx_hash: TxHash,
@@ -391,10 +429,12 @@ pub struct TxResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct TxReceiptResp {
     pub executed: bool,
     #[serde(with = "ts_microseconds_option")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "number | null"))]
     pub executed_timestamp: Option<DateTime<Utc>>,
     pub success: bool,
     pub fail_reason: Option<String>,
@@ -403,25 +443,30 @@ pub struct TxReceiptResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct BlockTxResp {
     pub tx_hash: TxHash,
     pub tx: ZkLinkTx,
     #[serde(with = "ts_microseconds")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
     pub executed_timestamp: DateTime<Utc>,
     pub updates: Vec<StateUpdateResp>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawTxResp {
     pub tx_hash: TxHash,
     pub tx: ZkLinkTx,
     #[serde(with = "ts_microseconds")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
     pub executed_timestamp: DateTime<Utc>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ForwardTxResp {
     pub tx_id: i64,
@@ -433,6 +478,7 @@ pub struct ForwardTxResp {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct Page<T> {
     pub total_page_num: u64,
@@ -442,6 +488,7 @@ pub struct Page<T> {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct ZkLinkTxHistory {
     pub chain_id: ChainId,
@@ -453,6 +500,7 @@ pub struct ZkLinkTxHistory {
     pub tx_hash: TxHash,
     pub tx_receipt: TxReceiptResp,
     #[serde(with = "ts_microseconds")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
     pub created_at: DateTime<Utc>,
 }
 
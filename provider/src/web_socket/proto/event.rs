@@ -2,8 +2,11 @@ use crate::web_socket::proto::topic::TopicTrait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 pub struct Event<T, D> {
     /// topic
     pub topic: T,
@@ -1,6 +1,4 @@
 pub mod event;
-#[cfg(not(target_arch = "wasm32"))]
 pub mod request;
-#[cfg(not(target_arch = "wasm32"))]
 pub mod response;
 pub mod topic;
@@ -1,6 +1,7 @@
 use crate::web_socket::proto::topic::TopicMethod;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+#[cfg(not(target_arch = "wasm32"))]
 use tokio_tungstenite::tungstenite::Message;
 
 /// this request is used to subscribe/unsubscribe topics
@@ -18,6 +19,7 @@ pub enum ClientMessage<M, T> {
     Query(M),
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<M, T> ClientMessage<M, T>
 where
     T: Serialize,
@@ -1,17 +1,23 @@
 use crate::response::TxResp;
 use crate::web_socket::proto::event::Event;
+use crate::web_socket::proto::response::WsResponse;
 use crate::web_socket::ws_message::message::response::ServerEvent::{
     PriorityEvent, TxExecuteResult,
 };
 use crate::web_socket::ws_message::topic::{Topic, TopicType};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::str::FromStr;
+#[cfg(target_arch = "wasm32")]
+use tsify::{declare, Tsify};
 use zklink_sdk_types::basic_types::tx_hash::TxHash;
 use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
 
+#[cfg_attr(target_arch = "wasm32", declare)]
 pub type TxTopicEvent = Event<Topic, ServerEvent>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(tag = "type")]
 pub enum ServerEvent {
     PriorityEvent(PriorityEventResp),
@@ -19,6 +25,7 @@ pub enum ServerEvent {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[serde(rename_all = "camelCase")]
 pub struct PriorityEventResp {
     pub tx_hash: TxHash,
@@ -33,3 +40,87 @@ impl ServerEvent {
         }
     }
 }
+
+/// The message pushed by the websocket server, the event of the subscribed topics or the
+/// response of the topics request.
+#[derive(Debug, Clone)]
+pub enum ServerMessage {
+    Event(TxTopicEvent),
+    Response(WsResponse<Value>),
+}
+
+impl FromStr for ServerMessage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: Value = serde_json::from_str(s)?;
+        if value.get("topic").is_none() {
+            return Ok(Self::Response(serde_json::from_value(value)?));
+        }
+        let event: Event<Topic, Value> = serde_json::from_value(value)?;
+        Ok(Self::Event(decode_topic_event(event)?))
+    }
+}
+
+/// Decode the data of the event by the type of the topic, the data is not tagged by the type of
+/// the `ServerEvent`. Both the pushed events and the events of `getWebSocketEvents` are decoded
+/// by it.
+pub fn decode_topic_event(event: Event<Topic, Value>) -> anyhow::Result<TxTopicEvent> {
+    let data = ServerEvent::from_topic_msg(event.topic.get_type(), event.data)?;
+    Ok(Event {
+        topic: event.topic,
+        topic_index: event.topic_index,
+        data,
+        timestamp: event.timestamp,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use zklink_sdk_types::basic_types::SubAccountId;
+
+    #[test]
+    fn test_server_message() {
+        let s = r#"{"topic":"priorityEvent@1","topic_index":5,"data":{"txHash":"0x0cbeabac1a2257fb095c2465e148570e32793345442b39bf64cad4ed87475f9b","tx":{"type":"ChangePubKey","chainId":1,"accountId":2,"subAccountId":4,"newPkHash":"0xd8d5fb6a6caef06aa3dc2abdcdc240987e5330fe","feeToken":1,"fee":"100","nonce":100,"signature":{"pubKey":"0x7b173e25e484eed3461091430f81b2a5bd7ae792f69701dcb073cb903f812510","signature":"8ae9ee90a7c19ced45bb70cf9cee0b392659cea87891a5021fe558f8e18b8680865ca2f008f75ba69146e7b5412c223d1339443aa4be18a9f62142fdefb79600"},"ethAuthData":{"type":"EthECDSA","ethSignature":"0x66eeec379a192c64ac44bf3b2cafbb0ebb2fca8c7c1699095599e8173d618e860dae34989661497834cd89bf5e5772bda322050a4d8d958011d192eda69df8dc1b"},"ts":1695105758}},"timestamp":1696743981000000}"#;
+        let message = ServerMessage::from_str(s).unwrap();
+        let ServerMessage::Event(event) = message else {
+            panic!("expect event");
+        };
+        assert_eq!(
+            event.topic,
+            Topic::PriorityEvent {
+                sub_account_id: SubAccountId(1)
+            }
+        );
+        assert_eq!(event.topic_index, 5);
+        assert!(matches!(event.data, PriorityEvent(_)));
+
+        let s = r#"{"result":{"topics":["priorityEvent@1"]},"error_code":0,"error_msg":"","id":1}"#;
+        let message = ServerMessage::from_str(s).unwrap();
+        assert!(matches!(message, ServerMessage::Response(resp) if resp.error_code == 0));
+
+        assert!(ServerMessage::from_str(r#"{"topic":"unknown@1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_decode_topic_event() {
+        let s = r#"{"topic":"txExecuteResult@1","topic_index":7,"data":{"txHash":"0x0cbeabac1a2257fb095c2465e148570e32793345442b39bf64cad4ed87475f9b","tx":{"type":"Deposit","fromChainId":1,"from":"0xafaff3ad1a0425d792432d9ecd1c3e26ef2c42e9","subAccountId":1,"l2TargetToken":18,"l1SourceToken":18,"to":"0xafaff3ad1a0425d792432d9ecd1c3e26ef2c42e9","amount":"100","serialId":1,"l2Hash":"0xe35f3a39d542f6d276c2f203e8fd64fcb8bf5db062b71ccacf45d5ecd9d456f3","ethHash":null},"receipt":{"executed":true,"executedTimestamp":1696743981000000,"success":true,"failReason":null,"block":10,"index":0},"updates":[]},"timestamp":1696743981000000}"#;
+        // the event pushed by the websocket server
+        let ServerMessage::Event(pushed) = ServerMessage::from_str(s).unwrap() else {
+            panic!("expect event");
+        };
+        // the same event returned by `getWebSocketEvents`
+        let events: Vec<Event<Topic, Value>> = serde_json::from_str(&format!("[{s}]")).unwrap();
+        let fetched = decode_topic_event(events[0].clone()).unwrap();
+        assert!(matches!(fetched.data, TxExecuteResult(_)));
+        assert_eq!(fetched.topic, pushed.topic);
+        assert_eq!(fetched.topic_index, 7);
+        assert_eq!(
+            serde_json::to_value(&fetched).unwrap(),
+            serde_json::to_value(&pushed).unwrap()
+        );
+        // the data isn't tagged, so it can't be decoded as the `ServerEvent` directly
+        assert!(serde_json::from_str::<Vec<TxTopicEvent>>(&format!("[{s}]")).is_err());
+    }
+}
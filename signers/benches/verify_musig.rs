@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use zklink_sdk_signers::zklink_signer::{ZkLinkSignature, ZkLinkSigner};
+
+fn signatures(n: usize) -> Vec<(ZkLinkSignature, Vec<u8>)> {
+    let zk_signer = ZkLinkSigner::new().unwrap();
+    (0..n)
+        .map(|i| {
+            let msg = (i as u64).to_be_bytes().repeat(4);
+            (zk_signer.sign_musig(&msg).unwrap(), msg)
+        })
+        .collect()
+}
+
+fn bench_verify_musig(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify_musig");
+    group.sample_size(10);
+    for n in [100, 1000] {
+        let items = signatures(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("loop", n), &items, |b, items| {
+            b.iter(|| {
+                black_box(items)
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (signature, msg))| !signature.verify_musig(msg))
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", n), &items, |b, items| {
+            b.iter(|| ZkLinkSignature::par_verify_musig(black_box(items)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_verify_musig);
+criterion_main!(benches);
@@ -1,7 +1,7 @@
 use crate::eth_signer::error::EthSignerError;
 use crate::eth_signer::H256;
 use ethers::utils::keccak256;
-use ethers_primitives::{Address, U256};
+use ethers_primitives::{Address, BytesM, U256};
 use serde::{Deserialize, Serialize};
 pub use serde_eip712::*;
 use std::collections::HashMap;
@@ -49,11 +49,44 @@ impl EIP712Domain {
     }
 }
 
+/// The signing domain of an arbitrary `eth_signTypedData_v4` request, all the fields are optional
+/// and only the ones declared in `types.EIP712Domain` are taken into account when hashing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawEIP712Domain {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifying_contract: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<BytesM<32>>,
+}
+
+impl From<EIP712Domain> for RawEIP712Domain {
+    fn from(domain: EIP712Domain) -> Self {
+        RawEIP712Domain {
+            name: Some(domain.name),
+            version: Some(domain.version),
+            chain_id: Some(domain.chain_id),
+            verifying_contract: Some(domain.verifying_contract),
+            salt: None,
+        }
+    }
+}
+
+/// The typed data parsed from an arbitrary `eth_signTypedData_v4` json request, e.g. an ERC-2612 permit.
+pub type RawTypedData = TypedData<serde_json::Value, RawEIP712Domain>;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct TypedData<M>
+pub struct TypedData<M, D = EIP712Domain>
 where
     M: Serialize,
+    D: Serialize,
 {
     /// The custom types used by signing message.
     pub types: HashMap<String, TypeDefinition>,
@@ -62,17 +95,38 @@ where
     /// Signing domain metadata. The signing domain is the intended context for the signature (e.g.
     /// the dapp, protocol, etc. that it's intended for). This data is used to construct the domain
     /// separator of the message.
-    pub domain: EIP712Domain,
+    pub domain: D,
     /// The message to be signed.
     pub message: M,
 }
 
-impl<M> TypedData<M>
+impl RawTypedData {
+    /// Parse the `eth_signTypedData_v4` json request.
+    pub fn from_json(json: &str) -> Result<Self, EthSignerError> {
+        let typed_data: RawTypedData =
+            serde_json::from_str(json).map_err(|e| EthSignerError::Eip712Failed(e.to_string()))?;
+        if !typed_data.types.contains_key("EIP712Domain") {
+            return Err(EthSignerError::Eip712Failed(
+                "missing EIP712Domain type definition".into(),
+            ));
+        }
+        if !typed_data.types.contains_key(&typed_data.primary_type) {
+            return Err(EthSignerError::Eip712Failed(format!(
+                "missing {} type definition",
+                typed_data.primary_type
+            )));
+        }
+        Ok(typed_data)
+    }
+}
+
+impl<M, D> TypedData<M, D>
 where
     M: Serialize,
+    D: Serialize,
 {
     /// Create eth_signTypedData payload.
-    pub fn new(domain: EIP712Domain, value: M) -> Result<TypedData<M>, EthSignerError> {
+    pub fn new(domain: D, value: M) -> Result<TypedData<M, D>, EthSignerError> {
         // Get primary type.
 
         let encode_type =
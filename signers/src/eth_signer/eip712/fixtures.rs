@@ -0,0 +1,15 @@
+//! The ERC-2612 permit of USDC on the ethereum mainnet, shared by the tests of the EIP-712 signing
+//! and the permit helpers of the wallet.
+
+/// The typed data of the permit, the same json as the request of `eth_signTypedData_v4`.
+pub const PERMIT_TYPED_DATA: &str = include_str!("../../../tests/permit.json");
+/// `DOMAIN_SEPARATOR()` of the USDC contract.
+pub const PERMIT_DOMAIN_SEPARATOR: &str =
+    "06c37168a7db5138defc7866392bb87a741f9b3d104deb5094588ce041cae335";
+/// The EIP-712 digest of the permit.
+pub const PERMIT_DIGEST: &str = "f89ddb9d7a2f3dbd75c7da383dca0ed8ef65db2970a19c2c4e2dbeeb34422d8d";
+/// The private key of the owner of the permit.
+pub const PERMIT_OWNER_PRIVATE_KEY: &str =
+    "0xb32593e347bf09436b058fbeabc17ebd2c7c1fa42e542f5f78fc3580faef83b7";
+/// The signature of the permit signed by the owner.
+pub const PERMIT_SIGNATURE: &str = "0xea3a7158dbae11e45b2b7f612a1f505d5a530b0c1f9badb6a3c8b1919bbc7d796fca8270beb3697be3a26aecd4b040206f4148b531015b16355acb48047d6aa21c";
@@ -31,6 +31,8 @@ pub enum EthSignerError {
     CryptoError,
     #[error("Invalid eth signature string")]
     InvalidSignatureStr,
+    #[error("Invalid eth signature v: {0}")]
+    InvalidSignatureV(u64),
     #[error("{0}")]
     CustomError(String),
     #[error("{0}")]
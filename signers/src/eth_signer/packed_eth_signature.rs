@@ -1,7 +1,9 @@
+use crate::eth_signer::eip712::eip712::TypedData;
 use crate::eth_signer::error::EthSignerError;
 use crate::eth_signer::Address;
-use ethers::types::Signature;
+use ethers::types::{Signature, U256};
 use ethers::utils::keccak256;
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zklink_sdk_utils::serde::ZeroPrefixHexSerde;
 /// Struct used for working with ethereum signatures created using eth_sign (using geth, ethers.js, etc)
@@ -50,12 +52,67 @@ impl PackedEthSignature {
         ))
     }
 
+    /// Serialize the signature to the 64 bytes compact representation of [EIP-2098](https://eips.ethereum.org/EIPS/eip-2098),
+    /// the y parity is encoded in the highest bit of `s`.
+    pub fn serialize_compact(&self) -> Result<[u8; 64], EthSignerError> {
+        let y_parity = Self::normalize_v(self.0.v)? - 27;
+        let mut bytes = [0u8; 64];
+        self.0.r.to_big_endian(&mut bytes[0..32]);
+        self.0.s.to_big_endian(&mut bytes[32..64]);
+        bytes[32] |= (y_parity as u8) << 7;
+        Ok(bytes)
+    }
+
+    /// Deserialize the 64 bytes compact representation of [EIP-2098](https://eips.ethereum.org/EIPS/eip-2098).
+    pub fn deserialize_compact(bytes: &[u8]) -> Result<Self, EthSignerError> {
+        if bytes.len() != 64 {
+            return Err(EthSignerError::LengthMismatched);
+        }
+        let mut y_parity_and_s = [0u8; 32];
+        y_parity_and_s.copy_from_slice(&bytes[32..64]);
+        let y_parity = y_parity_and_s[0] >> 7;
+        y_parity_and_s[0] &= 0x7f;
+        Ok(PackedEthSignature(Signature {
+            r: U256::from_big_endian(&bytes[0..32]),
+            s: U256::from_big_endian(&y_parity_and_s),
+            v: 27 + y_parity as u64,
+        }))
+    }
+
+    /// Normalize v to 27 or 28, accepts the recovery id(0, 1), the legacy v(27, 28)
+    /// and the [EIP-155](https://eips.ethereum.org/EIPS/eip-155) v(`chain_id * 2 + 35 + recovery_id`).
+    pub fn normalize_v(v: u64) -> Result<u64, EthSignerError> {
+        match v {
+            0 | 1 => Ok(v + 27),
+            27 | 28 => Ok(v),
+            v if v >= 35 => Ok((v - 35) % 2 + 27),
+            _ => Err(EthSignerError::InvalidSignatureV(v)),
+        }
+    }
+
+    /// Returns the signature with v normalized to 27 or 28.
+    pub fn normalized(&self) -> Result<Self, EthSignerError> {
+        let mut signature = self.0;
+        signature.v = Self::normalize_v(signature.v)?;
+        Ok(PackedEthSignature(signature))
+    }
+
+    /// Deserialize the 65 bytes packed signature from the hex string, use `from_compact_hex`
+    /// for the 64 bytes compact signature.
     pub fn from_hex(s: &str) -> Result<Self, EthSignerError> {
         let s = s.strip_prefix("0x").unwrap_or(s);
         let raw = hex::decode(s).map_err(|_e| EthSignerError::InvalidSignatureStr)?;
         Self::deserialize_packed(&raw)
     }
 
+    /// Deserialize the 64 bytes compact signature of [EIP-2098](https://eips.ethereum.org/EIPS/eip-2098)
+    /// from the hex string.
+    pub fn from_compact_hex(s: &str) -> Result<Self, EthSignerError> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        let raw = hex::decode(s).map_err(|_e| EthSignerError::InvalidSignatureStr)?;
+        Self::deserialize_compact(&raw)
+    }
+
     pub fn as_hex(&self) -> String {
         let raw = self.serialize_packed();
         format!("0x{}", hex::encode(raw))
@@ -81,6 +138,42 @@ impl PackedEthSignature {
             .map_err(|err| EthSignerError::RecoverAddress(err.to_string()))?;
         Ok(address)
     }
+
+    /// Recover the secp256k1 public key of the signer from the 32 bytes message digest.
+    pub fn recover_public_key(&self, digest: &[u8]) -> Result<VerifyingKey, EthSignerError> {
+        let y_parity = Self::normalize_v(self.0.v)? - 27;
+        let recovery_id = RecoveryId::from_byte(y_parity as u8)
+            .ok_or(EthSignerError::InvalidSignatureV(self.0.v))?;
+        let mut bytes = [0u8; 64];
+        self.0.r.to_big_endian(&mut bytes[0..32]);
+        self.0.s.to_big_endian(&mut bytes[32..64]);
+        let signature = EcdsaSignature::from_slice(&bytes)
+            .map_err(|e| EthSignerError::RecoverAddress(e.to_string()))?;
+        VerifyingKey::recover_from_prehash(digest, &signature, recovery_id)
+            .map_err(|e| EthSignerError::RecoverAddress(e.to_string()))
+    }
+
+    /// Checks whether the message signed by `eth_sign`(personal-sign) is signed by the address.
+    pub fn verify(&self, address: &Address, msg: &[u8]) -> Result<bool, EthSignerError> {
+        let recovered = self.signature_recover_signer(msg)?;
+        Ok(recovered == *address)
+    }
+
+    /// Checks whether the EIP-712 encoded message(the `"\x19\x01" ‖ domainSeparator ‖ hashStruct(message)`)
+    /// is signed by the address.
+    pub fn verify_eip712(&self, address: &Address, msg: &[u8]) -> Result<bool, EthSignerError> {
+        let recovered = self.eip712_signature_recover_signer(msg)?;
+        Ok(recovered == *address)
+    }
+
+    /// Checks the signature of the EIP-712 typed data and returns ethereum address of the signer.
+    pub fn typed_data_recover_signer<M: Serialize, D: Serialize>(
+        &self,
+        typed_data: &TypedData<M, D>,
+    ) -> Result<Address, EthSignerError> {
+        let msg = typed_data.encode()?;
+        self.eip712_signature_recover_signer(&msg)
+    }
 }
 
 impl Serialize for PackedEthSignature {
@@ -127,4 +220,69 @@ mod tests {
         let sign_address = Address::from_str("0xdec58607c3f5a0f8bc51ca50cc2578ab282865fc").unwrap();
         assert_eq!(address, sign_address);
     }
+
+    #[test]
+    fn test_compact_eth_signature() {
+        let private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let msg = vec![1, 2, 3, 4, 5];
+        let pk = EthSigner::try_from(private_key).unwrap();
+        let signature = pk.sign_message(&msg).unwrap();
+
+        let compact = signature.serialize_compact().unwrap();
+        let compact_signature = PackedEthSignature::deserialize_compact(&compact).unwrap();
+        assert_eq!(compact_signature, signature);
+        let hex_signature = PackedEthSignature::from_compact_hex(&hex::encode(compact)).unwrap();
+        assert_eq!(hex_signature, signature);
+        // the packed signature is strict about the length
+        assert_eq!(
+            PackedEthSignature::from_hex(&hex::encode(compact)),
+            Err(EthSignerError::LengthMismatched)
+        );
+        assert_eq!(
+            PackedEthSignature::from_compact_hex(&signature.as_hex()),
+            Err(EthSignerError::LengthMismatched)
+        );
+        assert!(compact_signature.verify(&pk.get_address(), &msg).unwrap());
+
+        // the example of https://eips.ethereum.org/EIPS/eip-2098
+        let signature = PackedEthSignature::from_hex("0x9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f5507931c").unwrap();
+        assert_eq!(
+            hex::encode(signature.serialize_compact().unwrap()),
+            "9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793"
+        );
+    }
+
+    #[test]
+    fn test_normalize_v() {
+        assert_eq!(PackedEthSignature::normalize_v(0).unwrap(), 27);
+        assert_eq!(PackedEthSignature::normalize_v(1).unwrap(), 28);
+        assert_eq!(PackedEthSignature::normalize_v(27).unwrap(), 27);
+        assert_eq!(PackedEthSignature::normalize_v(28).unwrap(), 28);
+        // EIP-155 with chain id 1
+        assert_eq!(PackedEthSignature::normalize_v(37).unwrap(), 27);
+        assert_eq!(PackedEthSignature::normalize_v(38).unwrap(), 28);
+        assert_eq!(
+            PackedEthSignature::normalize_v(2),
+            Err(EthSignerError::InvalidSignatureV(2))
+        );
+    }
+
+    #[test]
+    fn test_recover_public_key() {
+        use ethers::utils::hash_message;
+        use ethers::utils::public_key_to_address;
+
+        let private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let msg = b"hello world";
+        let pk = EthSigner::try_from(private_key).unwrap();
+        let signature = pk.sign_message(msg).unwrap();
+        let public_key = signature
+            .recover_public_key(hash_message(msg).as_bytes())
+            .unwrap();
+        assert_eq!(
+            public_key_to_address(&public_key).as_bytes(),
+            pk.get_address().as_bytes()
+        );
+        assert!(!signature.verify(&Address::zero(), msg).unwrap());
+    }
 }
@@ -1,12 +1,14 @@
 use super::packed_eth_signature::PackedEthSignature;
 use super::EthSignerError;
 
+use crate::eth_signer::eip712::eip712::TypedData;
 use crate::eth_signer::{Address, H256};
 use ethers::signers::{LocalWallet, Signer};
 use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::TxHash;
 use ethers::utils::hash_message;
 use k256::ecdsa::SigningKey;
+use serde::Serialize;
 
 #[derive(Clone)]
 pub struct EthSigner {
@@ -67,6 +69,15 @@ impl EthSigner {
             .map_err(|err| EthSignerError::SigningFailed(err.to_string()))?;
         Ok(PackedEthSignature(signature))
     }
+
+    /// Signs the EIP-712 typed data, results are identical to `eth_signTypedData_v4`.
+    pub fn sign_typed_data<M: Serialize, D: Serialize>(
+        &self,
+        typed_data: &TypedData<M, D>,
+    ) -> Result<PackedEthSignature, EthSignerError> {
+        let hash = typed_data.sign_hash()?;
+        self.sign_hash(hash.as_bytes())
+    }
 }
 
 impl TryFrom<&str> for EthSigner {
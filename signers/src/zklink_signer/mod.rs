@@ -23,6 +23,15 @@ thread_local! {
     pub(crate) static RESCUE_PARAMS: Bn256RescueParams = Bn256RescueParams::new_checked_2_into_1();
 }
 
+/// Initialize the jubjub and rescue params of the current thread.
+///
+/// The params are initialized lazily by the first use in each thread, which is slow. Call this when
+/// the thread starts to keep the initialization out of the first signing.
+pub fn init_params() {
+    JUBJUB_PARAMS.with(|_| {});
+    RESCUE_PARAMS.with(|_| {});
+}
+
 pub use error::ZkSignerError;
 pub use pk_signer::ZkLinkSigner;
 pub use pubkey_hash::PubKeyHash;
@@ -91,6 +91,17 @@ impl ZkLinkSigner {
         }
     }
 
+    /// Derive the `index`th zkLink key from the seed, the index 0 is the same as `new_from_seed`.
+    /// So that multiple keys can be recovered from one layer1 key signature.
+    pub fn new_from_seed_with_index(seed: &[u8], index: u32) -> Result<Self, Error> {
+        if index == 0 {
+            return Self::new_from_seed(seed);
+        }
+        let mut indexed_seed = seed.to_vec();
+        indexed_seed.extend_from_slice(&index.to_be_bytes());
+        Self::new_from_seed(&indexed_seed)
+    }
+
     pub fn new_from_hex_eth_signer(eth_hex_private_key: &str) -> Result<Self, Error> {
         let eth_signer = EthSigner::try_from(eth_hex_private_key)?;
         let signature = eth_signer.sign_message(Self::SIGN_MESSAGE.as_bytes())?;
@@ -113,11 +124,39 @@ impl ZkLinkSigner {
         Self::new_from_seed(&seed)
     }
 
+    /// create the `index`th zkLink signer from eth signer, the index 0 is the same as `new_from_eth_signer`
+    pub fn new_from_eth_signer_with_index(
+        eth_signer: &EthSigner,
+        index: u32,
+    ) -> Result<Self, Error> {
+        let signature = eth_signer.sign_message(Self::SIGN_MESSAGE.as_bytes())?;
+        let seed = signature.serialize_packed();
+        Self::new_from_seed_with_index(&seed, index)
+    }
+
+    pub fn new_from_hex_eth_signer_with_index(
+        eth_hex_private_key: &str,
+        index: u32,
+    ) -> Result<Self, Error> {
+        let eth_signer = EthSigner::try_from(eth_hex_private_key)?;
+        Self::new_from_eth_signer_with_index(&eth_signer, index)
+    }
+
     /// create zkLink signer from starknet signer
     pub fn new_from_starknet_signer(
         starknet_signer: &StarkSigner,
         addr: &str,
         chain_id: &str,
+    ) -> Result<Self, Error> {
+        Self::new_from_starknet_signer_with_index(starknet_signer, addr, chain_id, 0)
+    }
+
+    /// create the `index`th zkLink signer from starknet signer, the index 0 is the same as `new_from_starknet_signer`
+    pub fn new_from_starknet_signer_with_index(
+        starknet_signer: &StarkSigner,
+        addr: &str,
+        chain_id: &str,
+        index: u32,
     ) -> Result<Self, Error> {
         let message = Message {
             data: Self::STARKNET_SIGN_MESSAGE.to_string(),
@@ -128,7 +167,17 @@ impl ZkLinkSigner {
         );
         let signature = starknet_signer.sign_message(&typed_data, addr)?;
         let seed = signature.signature.to_bytes_be();
-        Self::new_from_seed(&seed)
+        Self::new_from_seed_with_index(&seed, index)
+    }
+
+    pub fn new_from_hex_stark_signer_with_index(
+        hex_private_key: &str,
+        addr: &str,
+        chain_id: &str,
+        index: u32,
+    ) -> Result<Self, Error> {
+        let stark_signer = StarkSigner::new_from_hex_str(hex_private_key)?;
+        Self::new_from_starknet_signer_with_index(&stark_signer, addr, chain_id, index)
     }
 
     #[cfg(feature = "web")]
@@ -256,4 +305,34 @@ mod test {
         );
         assert_eq!(serde_json::to_value(signature).unwrap(), expect_signature);
     }
+
+    #[test]
+    fn test_zklink_signer_with_index() {
+        let eth_private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let eth_signer = EthSigner::try_from(eth_private_key).unwrap();
+        let zk_signer = ZkLinkSigner::new_from_eth_signer_with_index(&eth_signer, 0).unwrap();
+        assert_eq!(
+            zk_signer.public_key().as_hex(),
+            "0x7b173e25e484eed3461091430f81b2a5bd7ae792f69701dcb073cb903f812510"
+        );
+
+        let zk_signer1 =
+            ZkLinkSigner::new_from_hex_eth_signer_with_index(eth_private_key, 1).unwrap();
+        let zk_signer2 = ZkLinkSigner::new_from_eth_signer_with_index(&eth_signer, 2).unwrap();
+        assert_ne!(
+            zk_signer1.public_key().as_hex(),
+            zk_signer.public_key().as_hex()
+        );
+        assert_ne!(
+            zk_signer1.public_key().as_hex(),
+            zk_signer2.public_key().as_hex()
+        );
+        assert_eq!(
+            ZkLinkSigner::new_from_eth_signer_with_index(&eth_signer, 1)
+                .unwrap()
+                .public_key()
+                .as_hex(),
+            zk_signer1.public_key().as_hex()
+        );
+    }
 }
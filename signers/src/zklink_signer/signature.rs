@@ -169,10 +169,41 @@ impl ZkLinkSignature {
             })
         })
     }
+
+    /// Verify the `(signature, msg)` pairs in parallel(by rayon) on the native targets and return
+    /// the indices of the failed ones, an empty result means all the signatures are valid.
+    ///
+    /// Every signature is verified by `verify_musig`, it's not a randomized batch verification:
+    /// the rescue challenge of the musig signature is private to `franklin_crypto`.
+    pub fn par_verify_musig<M>(items: &[(ZkLinkSignature, M)]) -> Vec<usize>
+    where
+        M: AsRef<[u8]> + Sync,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use rayon::prelude::*;
+            items
+                .par_iter()
+                .enumerate()
+                .filter(|(_, (signature, msg))| !signature.verify_musig(msg.as_ref()))
+                .map(|(index, _)| index)
+                .collect()
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(_, (signature, msg))| !signature.verify_musig(msg.as_ref()))
+                .map(|(index, _)| index)
+                .collect()
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use super::ZkLinkSignature;
     use crate::zklink_signer::pk_signer::ZkLinkSigner;
 
     #[test]
@@ -184,4 +215,20 @@ mod test {
         let verify = signature.verify_musig(msg);
         assert!(verify);
     }
+
+    #[test]
+    fn test_par_verify_musig() {
+        let zk_signer = ZkLinkSigner::new().unwrap();
+        let mut items = (0..8u8)
+            .map(|i| {
+                let msg = vec![i; 32];
+                (zk_signer.sign_musig(&msg).unwrap(), msg)
+            })
+            .collect::<Vec<_>>();
+        assert!(ZkLinkSignature::par_verify_musig(&items).is_empty());
+
+        items[2].1 = vec![0xff; 32];
+        items[5].0 = items[6].0.clone();
+        assert_eq!(ZkLinkSignature::par_verify_musig(&items), vec![2, 5]);
+    }
 }
@@ -2,7 +2,12 @@ use ethers_primitives::Address;
 use serde::{Deserialize, Serialize};
 use serde_eip712::{eip712_encode_type, eip712_hash_struct, eip712_type_definitions};
 use serde_json::json;
-use zklink_sdk_signers::eth_signer::eip712::eip712::{EIP712Domain, TypedData};
+use zklink_sdk_signers::eth_signer::eip712::eip712::{EIP712Domain, RawTypedData, TypedData};
+use zklink_sdk_signers::eth_signer::eip712::fixtures::{
+    PERMIT_DIGEST, PERMIT_DOMAIN_SEPARATOR, PERMIT_OWNER_PRIVATE_KEY, PERMIT_SIGNATURE,
+    PERMIT_TYPED_DATA,
+};
+use zklink_sdk_signers::eth_signer::EthSigner;
 
 #[test]
 fn test_mail() {
@@ -77,3 +82,39 @@ fn test_mail() {
 
     expect_request.sign_hash().unwrap();
 }
+
+#[test]
+fn test_raw_typed_data() {
+    let typed_data = RawTypedData::from_json(include_str!("./eip712.json")).unwrap();
+    assert_eq!(
+        hex::encode(typed_data.sign_hash().unwrap()),
+        "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+    );
+
+    let signer = EthSigner::try_from(PERMIT_OWNER_PRIVATE_KEY).unwrap();
+    let signature = signer.sign_typed_data(&typed_data).unwrap();
+    assert_eq!(signature.as_hex(), "0xbf24877c59766e95717686e71a0402ba12f5db4a8aa93ac6c30b5742925ebfc26c91d6b6bb949a2b0578c397e296830dde9cc3531adbb259c4b4b06441b1a9c51b");
+    assert_eq!(
+        signature.typed_data_recover_signer(&typed_data).unwrap(),
+        signer.get_address()
+    );
+
+    // the signature of the example in EIP-712, signed by the key `keccak256("cow")`
+    let cow_key = "0xc85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4";
+    let cow = EthSigner::try_from(cow_key).unwrap();
+    let signature = cow.sign_typed_data(&typed_data).unwrap();
+    assert_eq!(signature.as_hex(), "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c");
+
+    // not a zklink domain, e.g. ERC-2612 permit of USDC on the ethereum mainnet
+    let permit = RawTypedData::from_json(PERMIT_TYPED_DATA).unwrap();
+    let encoded = permit.encode().unwrap();
+    assert_eq!(hex::encode(&encoded[2..34]), PERMIT_DOMAIN_SEPARATOR);
+    assert_eq!(hex::encode(permit.sign_hash().unwrap()), PERMIT_DIGEST);
+    let signature = signer.sign_typed_data(&permit).unwrap();
+    assert_eq!(signature.as_hex(), PERMIT_SIGNATURE);
+    let address = signature.eip712_signature_recover_signer(&encoded).unwrap();
+    assert_eq!(address, signer.get_address());
+
+    let invalid = r#"{"types":{},"primaryType":"Permit","domain":{},"message":{}}"#;
+    assert!(RawTypedData::from_json(invalid).is_err());
+}
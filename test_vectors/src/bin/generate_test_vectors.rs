@@ -0,0 +1,12 @@
+use zklink_sdk_test_vectors::{generate, CORPUS_PATH};
+
+fn main() -> anyhow::Result<()> {
+    let path = std::env::args().nth(1);
+    let path = path.as_deref().unwrap_or(CORPUS_PATH);
+    let corpus = generate()?;
+    let mut s = serde_json::to_string_pretty(&corpus)?;
+    s.push('\n');
+    std::fs::write(path, s)?;
+    println!("test vectors are written to {path}");
+    Ok(())
+}
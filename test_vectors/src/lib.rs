@@ -0,0 +1,583 @@
+//! The conformance test vectors shared by the rust sdk and the golang, python and javascript
+//! bindings.
+//!
+//! `vectors.json` is generated by `generate()`, run
+//! `cargo run -p zklink_sdk_test_vectors --bin generate_test_vectors` to regenerate it. Every
+//! binding loads the same file, packs and signs the txs of it and compares the results with the
+//! corpus, so a change of the packing or the encoding is caught by all the bindings.
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use zklink_sdk_interface::signer::{L1SignerType, Signer};
+use zklink_sdk_interface::SigningContext;
+use zklink_sdk_signers::eth_signer::pk_signer::EthSigner;
+use zklink_sdk_signers::eth_signer::H256;
+use zklink_sdk_signers::zklink_signer::pk_signer::ZkLinkSigner;
+use zklink_sdk_signers::zklink_signer::signature::ZkLinkSignature;
+use zklink_sdk_types::basic_types::BigUint;
+use zklink_sdk_types::prelude::*;
+
+/// The version of the corpus format, bump it if the fields of the corpus are changed.
+pub const CORPUS_VERSION: u32 = 2;
+/// The path of the corpus.
+pub const CORPUS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/vectors.json");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Corpus {
+    pub version: u32,
+    pub keys: Vec<KeyVector>,
+    pub txs: Vec<TxVector>,
+}
+
+/// The keys derived from the layer1 private key.
+///
+/// The zklink key of a starknet private key is derived by the starknet account of
+/// `starknet_chain_id` and `starknet_addr`, the `eth_address` only exists for the ethereum keys.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyVector {
+    pub name: String,
+    pub private_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eth_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starknet_chain_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starknet_addr: Option<String>,
+    pub public_key: String,
+    pub pubkey_hash: String,
+}
+
+impl KeyVector {
+    /// The starknet chain id and account address of a starknet key.
+    pub fn starknet_account(&self) -> Option<(&str, &str)> {
+        self.starknet_chain_id
+            .as_deref()
+            .zip(self.starknet_addr.as_deref())
+    }
+}
+
+/// The packing and the signatures of a tx, the signatures only exist if the `signer` is set.
+///
+/// The tx is signed by the starknet signer if the `context` has the starknet chain id and address,
+/// otherwise by the ethereum signer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxVector {
+    pub name: String,
+    /// The name of the key in `keys` that signs the tx.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+    /// The json of the `SigningContext`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<Value>,
+    /// The json of the unsigned `ZkLinkTx`.
+    pub tx: Value,
+    pub bytes: String,
+    pub tx_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zk_signature: Option<ZkSignatureVector>,
+    /// The ethereum message of the layer1 signature, the starknet typed data isn't included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_message: Option<String>,
+    /// The `type` of the `TxLayer1Signature`, it's not set if the layer1 signature is the
+    /// `ethAuthData` of the signed `ChangePubKey`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_signature_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_signature: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkSignatureVector {
+    pub pub_key: String,
+    pub signature: String,
+}
+
+struct TxInput {
+    name: &'static str,
+    signer: Option<&'static str>,
+    context: Option<Value>,
+    tx: ZkLinkTx,
+}
+
+/// The name, the private key and the starknet chain id and address of the starknet keys.
+const KEYS: [(&str, &str, Option<(&str, &str)>); 3] = [
+    (
+        "eth_key_1",
+        "0xbe725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4",
+        None,
+    ),
+    (
+        "eth_key_2",
+        "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        None,
+    ),
+    (
+        "stark_key_1",
+        "0x02c5dbad71c92a45cc4b40573ae661f8147869a91d57b8d9b8f48c8af7f83159",
+        Some((STARKNET_CHAIN_ID, STARKNET_ADDR)),
+    ),
+];
+const STARKNET_CHAIN_ID: &str = "SN_GOERLI";
+const STARKNET_ADDR: &str = "0x04A69b67bcaBfA7D3CCb96e1d25C2e6fC93589fE24A6fD04566B8700ff97a71a";
+
+fn address() -> ZkLinkAddress {
+    ZkLinkAddress::from_str("0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9").unwrap()
+}
+
+fn zklink_signer() -> ZkLinkSigner {
+    ZkLinkSigner::new_from_hex_eth_signer(KEYS[0].1).unwrap()
+}
+
+fn oracle_prices() -> OraclePrices {
+    OraclePrices {
+        contract_prices: vec![ContractPrice {
+            pair_id: PairId(1),
+            market_price: BigUint::from(1500000000000000000000u128),
+        }],
+        margin_prices: vec![SpotPriceInfo {
+            token_id: TokenId(17),
+            price: BigUint::from(1000000000000000000u128),
+        }],
+    }
+}
+
+/// The orders are signed by the maker and the taker, the same key signs both here.
+fn signed_order(slot_id: u32, nonce: u32, is_sell: bool) -> Order {
+    let mut order = Order {
+        account_id: AccountId(5),
+        sub_account_id: SubAccountId(1),
+        slot_id: SlotId(slot_id),
+        nonce: Nonce(nonce),
+        base_token_id: TokenId(18),
+        quote_token_id: TokenId(17),
+        amount: BigUint::from(10000000000000u64),
+        price: BigUint::from(10000000000u64),
+        is_sell: is_sell as u8,
+        has_subsidy: 0,
+        fee_rates: [5, 3],
+        signature: Default::default(),
+    };
+    order.signature = zklink_signer().sign_musig(&order.get_bytes()).unwrap();
+    order
+}
+
+fn signed_contract(slot_id: u32, nonce: u32, direction: bool) -> Contract {
+    let mut contract = ContractBuilder {
+        account_id: AccountId(5),
+        sub_account_id: SubAccountId(1),
+        slot_id: SlotId(slot_id),
+        nonce: Nonce(nonce),
+        pair_id: PairId(1),
+        size: BigUint::from(10000000000000u64),
+        price: BigUint::from(1500000000000000000000u128),
+        direction,
+        maker_fee_rate: 5,
+        taker_fee_rate: 3,
+        has_subsidy: false,
+    }
+    .build();
+    contract.signature = zklink_signer().sign_musig(&contract.get_bytes()).unwrap();
+    contract
+}
+
+fn tx_inputs() -> Vec<TxInput> {
+    let ts = 1693472232u32;
+    let transfer = TransferBuilder {
+        account_id: AccountId(1),
+        to_address: address(),
+        from_sub_account_id: SubAccountId(1),
+        to_sub_account_id: SubAccountId(1),
+        token: TokenId(18),
+        amount: BigUint::from(100000u32),
+        fee: BigUint::from(100u32),
+        nonce: Nonce(1),
+        timestamp: ts.into(),
+    }
+    .build();
+    let withdraw = WithdrawBuilder {
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        to_chain_id: ChainId(1),
+        to_address: address(),
+        l2_source_token: TokenId(18),
+        l1_target_token: TokenId(18),
+        amount: BigUint::from(10000u32),
+        data_hash: None,
+        fee: BigUint::from(3u32),
+        nonce: Nonce(1),
+        withdraw_to_l1: false,
+        withdraw_fee_ratio: 0,
+        timestamp: ts.into(),
+    }
+    .build();
+    let forced_exit = ForcedExitBuilder {
+        to_chain_id: ChainId(1),
+        initiator_account_id: AccountId(10),
+        initiator_sub_account_id: SubAccountId(1),
+        target: address(),
+        target_sub_account_id: SubAccountId(1),
+        l2_source_token: TokenId(18),
+        l1_target_token: TokenId(18),
+        initiator_nonce: Nonce(1),
+        exit_amount: BigUint::from(10000u32),
+        withdraw_to_l1: false,
+        timestamp: ts.into(),
+    }
+    .build();
+    let change_pubkey = ChangePubKeyBuilder {
+        chain_id: ChainId(1),
+        account_id: AccountId(1),
+        sub_account_id: SubAccountId(1),
+        new_pubkey_hash: zklink_signer().public_key().public_key_hash(),
+        fee_token: TokenId(18),
+        fee: BigUint::from(100u32),
+        nonce: Nonce(1),
+        eth_signature: None,
+        timestamp: ts.into(),
+    }
+    .build();
+    let deposit = DepositBuilder {
+        from_address: address(),
+        to_address: address(),
+        from_chain_id: ChainId(1),
+        sub_account_id: SubAccountId(1),
+        l2_target_token: TokenId(18),
+        l1_source_token: TokenId(18),
+        amount: BigUint::from(100u32),
+        serial_id: 32001,
+        eth_hash: None,
+        l2_hash: H256::from_str(
+            "0xe35f3a39d542f6d276c2f203e8fd64fcb8bf5db062b71ccacf45d5ecd9d456f3",
+        )
+        .unwrap(),
+    }
+    .build();
+    let update_global_var = UpdateGlobalVarBuilder {
+        from_chain_id: ChainId(1),
+        sub_account_id: SubAccountId(1),
+        parameter: Parameter::FeeAccount {
+            account_id: AccountId(10),
+        },
+        serial_id: 0,
+    }
+    .build();
+    // the builders of the matchings take the `Arc` of the orders with the `ffi` feature
+    let order_matching = OrderMatching {
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        taker: signed_order(1, 1, true),
+        maker: signed_order(2, 1, false),
+        fee: BigUint::from(100u32),
+        fee_token: TokenId(17),
+        oracle_prices: oracle_prices(),
+        expect_base_amount: BigUint::from(10000000000000u64),
+        expect_quote_amount: BigUint::from(100000000000u64),
+        signature: Default::default(),
+    };
+    let contract_matching = ContractMatching {
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        taker: signed_contract(1, 1, true),
+        maker: vec![signed_contract(2, 1, false), signed_contract(3, 1, false)],
+        fee: BigUint::from(100u32),
+        fee_token: TokenId(17),
+        oracle_prices: oracle_prices(),
+        signature: Default::default(),
+    };
+    let liquidation = LiquidationBuilder {
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        sub_account_nonce: Nonce(1),
+        contract_prices: oracle_prices().contract_prices,
+        margin_prices: oracle_prices().margin_prices,
+        liquidation_account_id: AccountId(5),
+        fee: BigUint::from(100u32),
+        fee_token: TokenId(17),
+    }
+    .build();
+    let auto_deleveraging = AutoDeleveragingBuilder {
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        sub_account_nonce: Nonce(1),
+        contract_prices: oracle_prices().contract_prices,
+        margin_prices: oracle_prices().margin_prices,
+        adl_account_id: AccountId(5),
+        pair_id: PairId(1),
+        adl_size: BigUint::from(10000000000000u64),
+        adl_price: BigUint::from(1500000000000000000000u128),
+        fee: BigUint::from(100u32),
+        fee_token: TokenId(17),
+    }
+    .build();
+    let funding = FundingBuilder {
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        sub_account_nonce: Nonce(1),
+        funding_account_ids: vec![AccountId(5), AccountId(6)],
+        fee: BigUint::from(100u32),
+        fee_token: TokenId(17),
+    }
+    .build();
+    let full_exit = FullExitBuilder {
+        to_chain_id: ChainId(1),
+        account_id: AccountId(10),
+        sub_account_id: SubAccountId(1),
+        exit_address: address(),
+        l2_source_token: TokenId(18),
+        l1_target_token: TokenId(18),
+        contract_prices: oracle_prices().contract_prices,
+        margin_prices: oracle_prices().margin_prices,
+        serial_id: 32002,
+        l2_hash: H256::from_str(
+            "0xe35f3a39d542f6d276c2f203e8fd64fcb8bf5db062b71ccacf45d5ecd9d456f3",
+        )
+        .unwrap(),
+    }
+    .build();
+
+    vec![
+        TxInput {
+            name: "transfer",
+            signer: Some(KEYS[0].0),
+            context: Some(json!({ "tokenSymbol": "USDC" })),
+            tx: transfer.clone().into(),
+        },
+        TxInput {
+            name: "transfer_starknet",
+            signer: Some(KEYS[2].0),
+            context: Some(json!({
+                "tokenSymbol": "USDC",
+                "starknetChainId": STARKNET_CHAIN_ID,
+                "starknetAddr": STARKNET_ADDR,
+            })),
+            tx: transfer.into(),
+        },
+        TxInput {
+            name: "withdraw",
+            signer: Some(KEYS[1].0),
+            context: Some(json!({ "tokenSymbol": "USDC" })),
+            tx: withdraw.clone().into(),
+        },
+        TxInput {
+            name: "withdraw_starknet",
+            signer: Some(KEYS[2].0),
+            context: Some(json!({
+                "tokenSymbol": "USDC",
+                "starknetChainId": STARKNET_CHAIN_ID,
+                "starknetAddr": STARKNET_ADDR,
+            })),
+            tx: withdraw.into(),
+        },
+        TxInput {
+            name: "forced_exit",
+            signer: None,
+            context: None,
+            tx: forced_exit.into(),
+        },
+        TxInput {
+            name: "change_pubkey",
+            signer: Some(KEYS[0].0),
+            context: Some(json!({ "changePubkeyAuth": { "type": "EthECDSA" } })),
+            tx: change_pubkey.clone().into(),
+        },
+        TxInput {
+            name: "change_pubkey_onchain",
+            signer: Some(KEYS[0].0),
+            context: Some(json!({ "changePubkeyAuth": { "type": "Onchain" } })),
+            tx: change_pubkey.into(),
+        },
+        TxInput {
+            name: "deposit",
+            signer: None,
+            context: None,
+            tx: deposit.into(),
+        },
+        TxInput {
+            name: "update_global_var",
+            signer: None,
+            context: None,
+            tx: update_global_var.into(),
+        },
+        TxInput {
+            name: "order_matching",
+            signer: Some(KEYS[0].0),
+            context: None,
+            tx: order_matching.into(),
+        },
+        TxInput {
+            name: "contract_matching",
+            signer: Some(KEYS[0].0),
+            context: None,
+            tx: contract_matching.into(),
+        },
+        TxInput {
+            name: "liquidation",
+            signer: Some(KEYS[0].0),
+            context: None,
+            tx: liquidation.into(),
+        },
+        TxInput {
+            name: "auto_deleveraging",
+            signer: Some(KEYS[0].0),
+            context: None,
+            tx: auto_deleveraging.into(),
+        },
+        TxInput {
+            name: "funding",
+            signer: Some(KEYS[0].0),
+            context: None,
+            tx: funding.into(),
+        },
+        TxInput {
+            name: "full_exit",
+            signer: None,
+            context: None,
+            tx: full_exit.into(),
+        },
+    ]
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Derives the keys from the private key, it's a starknet private key if the starknet chain id
+/// and address are set, otherwise an ethereum private key.
+pub fn key_vector(
+    name: &str,
+    private_key: &str,
+    starknet_account: Option<(&str, &str)>,
+) -> anyhow::Result<KeyVector> {
+    let (eth_address, zklink_signer) = match starknet_account {
+        Some((chain_id, addr)) => (
+            None,
+            ZkLinkSigner::new_from_hex_stark_signer(private_key, addr, chain_id)?,
+        ),
+        None => {
+            let eth_signer = EthSigner::try_from(private_key)?;
+            (
+                Some(format!("{:?}", eth_signer.get_address())),
+                ZkLinkSigner::new_from_hex_eth_signer(private_key)?,
+            )
+        }
+    };
+    Ok(KeyVector {
+        name: name.to_string(),
+        private_key: private_key.to_string(),
+        eth_address,
+        starknet_chain_id: starknet_account.map(|(chain_id, _)| chain_id.to_string()),
+        starknet_addr: starknet_account.map(|(_, addr)| addr.to_string()),
+        public_key: zklink_signer.public_key().as_hex(),
+        pubkey_hash: zklink_signer.public_key().public_key_hash().as_hex(),
+    })
+}
+
+/// Packs the tx and signs it by the `signer` if it's set.
+pub fn tx_vector(
+    name: &str,
+    tx: ZkLinkTx,
+    signer: Option<&KeyVector>,
+    context: Option<Value>,
+) -> anyhow::Result<TxVector> {
+    let mut tx_json = serde_json::to_value(&tx)?;
+    // the signature of the unsigned tx is meaningless
+    if let Some(tx_json) = tx_json.as_object_mut() {
+        tx_json.remove("signature");
+    }
+    let mut vector = TxVector {
+        name: name.to_string(),
+        signer: signer.map(|key| key.name.clone()),
+        context: context.clone(),
+        tx: tx_json,
+        bytes: to_hex(&tx.get_bytes()),
+        tx_hash: tx.tx_hash().as_hex(),
+        zk_signature: None,
+        l1_message: None,
+        l1_signature_type: None,
+        l1_signature: None,
+    };
+    let Some(key) = signer else {
+        return Ok(vector);
+    };
+    let ctx: SigningContext = match context {
+        Some(context) => serde_json::from_value(context)?,
+        None => SigningContext::default(),
+    };
+    let token_symbol = ctx.token_symbol.clone().unwrap_or_default();
+    let l1_signer_type = match (&ctx.starknet_chain_id, &ctx.starknet_addr) {
+        (Some(chain_id), Some(address)) => L1SignerType::Starknet {
+            chain_id: chain_id.clone(),
+            address: address.clone(),
+        },
+        _ => L1SignerType::Eth,
+    };
+    let signer = Signer::new(&key.private_key, l1_signer_type)?;
+    let signature = signer.sign(tx, ctx)?;
+    let signed_tx = serde_json::to_value(&signature.tx)?;
+    let zk_signature: ZkLinkSignature = serde_json::from_value(signed_tx["signature"].clone())?;
+    vector.zk_signature = Some(ZkSignatureVector {
+        pub_key: zk_signature.pub_key.as_hex(),
+        signature: zk_signature.signature.as_hex(),
+    });
+    match (&signature.layer1_signature, &signature.tx) {
+        (Some(l1_signature), tx) => {
+            // the same json as the layer1 signature returned by the bindings
+            let l1_signature = serde_json::to_value(l1_signature)?;
+            vector.l1_signature_type = l1_signature["type"].as_str().map(str::to_string);
+            vector.l1_signature = l1_signature["signature"].as_str().map(str::to_string);
+            if let Some(TxLayer1Signature::EthereumSignature(_)) = &signature.layer1_signature {
+                vector.l1_message = match tx {
+                    ZkLinkTx::Transfer(tx) => Some(tx.get_eth_sign_msg(&token_symbol)),
+                    ZkLinkTx::Withdraw(tx) => Some(tx.get_eth_sign_msg(&token_symbol)),
+                    _ => None,
+                };
+            }
+        }
+        (None, ZkLinkTx::ChangePubKey(tx)) => {
+            if let ChangePubKeyAuthData::EthECDSA { eth_signature } = &tx.eth_auth_data {
+                vector.l1_message = Some(ChangePubKey::get_eth_sign_msg(
+                    &tx.new_pk_hash,
+                    tx.nonce,
+                    tx.account_id,
+                ));
+                vector.l1_signature = Some(eth_signature.as_hex());
+            }
+        }
+        (None, _) => {}
+    }
+    Ok(vector)
+}
+
+/// Generates the corpus from the keys and the txs defined above.
+pub fn generate() -> anyhow::Result<Corpus> {
+    let keys = KEYS
+        .iter()
+        .map(|(name, private_key, starknet_account)| {
+            key_vector(name, private_key, *starknet_account)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let txs = tx_inputs()
+        .into_iter()
+        .map(|input| {
+            let signer = input
+                .signer
+                .map(|name| keys.iter().find(|key| key.name == name).unwrap());
+            tx_vector(input.name, input.tx, signer, input.context)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(Corpus {
+        version: CORPUS_VERSION,
+        keys,
+        txs,
+    })
+}
+
+/// Loads the corpus from `CORPUS_PATH`.
+pub fn load() -> anyhow::Result<Corpus> {
+    let corpus = std::fs::read_to_string(CORPUS_PATH)?;
+    Ok(serde_json::from_str(&corpus)?)
+}
@@ -0,0 +1,75 @@
+use zklink_sdk_signers::eth_signer::pk_signer::EthSigner;
+use zklink_sdk_test_vectors::{generate, key_vector, load, tx_vector, CORPUS_VERSION};
+use zklink_sdk_types::prelude::ZkLinkTx;
+
+#[test]
+fn test_keys() {
+    let corpus = load().unwrap();
+    assert_eq!(corpus.version, CORPUS_VERSION);
+    for key in corpus.keys {
+        let derived = key_vector(&key.name, &key.private_key, key.starknet_account()).unwrap();
+        assert_eq!(derived, key, "key {}", key.name);
+    }
+}
+
+#[test]
+fn test_txs() {
+    let corpus = load().unwrap();
+    for vector in corpus.txs {
+        let tx: ZkLinkTx = serde_json::from_value(vector.tx.clone()).unwrap();
+        let signer = vector
+            .signer
+            .as_ref()
+            .map(|name| corpus.keys.iter().find(|key| &key.name == name).unwrap());
+        let derived = tx_vector(&vector.name, tx, signer, vector.context.clone()).unwrap();
+        assert_eq!(derived.bytes, vector.bytes, "bytes of {}", vector.name);
+        assert_eq!(
+            derived.tx_hash, vector.tx_hash,
+            "tx hash of {}",
+            vector.name
+        );
+        assert_eq!(
+            derived.zk_signature, vector.zk_signature,
+            "zklink signature of {}",
+            vector.name
+        );
+        assert_eq!(
+            derived.l1_message, vector.l1_message,
+            "l1 message of {}",
+            vector.name
+        );
+        assert_eq!(
+            derived.l1_signature_type, vector.l1_signature_type,
+            "l1 signature type of {}",
+            vector.name
+        );
+        assert_eq!(
+            derived.l1_signature, vector.l1_signature,
+            "l1 signature of {}",
+            vector.name
+        );
+        if let (Some(key), Some(message), Some(l1_signature)) =
+            (signer, &vector.l1_message, &vector.l1_signature)
+        {
+            let eth_signer = EthSigner::try_from(key.private_key.as_str()).unwrap();
+            let signature = eth_signer.sign_message(message.as_bytes()).unwrap();
+            assert_eq!(&signature.as_hex(), l1_signature);
+        }
+    }
+}
+
+#[test]
+fn test_corpus_is_up_to_date() {
+    // the vectors added to `generate()` must be written to the corpus too
+    let corpus = load().unwrap();
+    let generated = generate().unwrap();
+    let names = |corpus: &zklink_sdk_test_vectors::Corpus| {
+        corpus
+            .txs
+            .iter()
+            .map(|tx| tx.name.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&generated), names(&corpus));
+    assert_eq!(generated, corpus);
+}
@@ -82,19 +82,15 @@ impl ChangePubKeyAuthData {
         matches!(self, ChangePubKeyAuthData::EthCreate2 { .. })
     }
 
-    pub fn get_eth_witness(&self) -> Vec<u8> {
-        match self {
+    /// The witness of the layer1 authorization, returns error if the v of the eth signature is
+    /// invalid.
+    pub fn get_eth_witness(&self) -> Result<Vec<u8>, EthSignerError> {
+        let witness = match self {
             ChangePubKeyAuthData::Onchain => vec![],
             ChangePubKeyAuthData::EthECDSA { eth_signature } => {
                 let mut bytes = Vec::new();
                 bytes.push(0x00);
-                bytes.extend_from_slice(&eth_signature.0.to_vec()[..64]);
-                // add 27 to v
-                let mut v = eth_signature.0.to_vec()[64];
-                if v == 0 || v == 1 {
-                    v += 27;
-                }
-                bytes.push(v);
+                bytes.extend_from_slice(&eth_signature.normalized()?.serialize_packed());
                 bytes
             }
             ChangePubKeyAuthData::EthCreate2 { data } => {
@@ -105,7 +101,8 @@ impl ChangePubKeyAuthData {
                 bytes.extend_from_slice(data.code_hash.as_bytes());
                 bytes
             }
-        }
+        };
+        Ok(witness)
     }
 }
 
@@ -337,6 +334,41 @@ mod test {
         assert_eq!(eth_signature.as_hex(), "0xefd0d9c6beb00310535bb51ee58745adb547e7d875d5823892365a6450caf6c559a6a4bfd83bf336ac59cf83e97948dbf607bf2aecd24f6829c3deac20ecdb601b");
     }
 
+    #[test]
+    fn test_get_eth_witness() {
+        let signer = EthSigner::from(H256::from([5; 32]));
+        let eth_signature = signer.sign_message(b"witness").unwrap();
+        let packed = eth_signature.serialize_packed();
+        let witness = ChangePubKeyAuthData::EthECDSA {
+            eth_signature: eth_signature.clone(),
+        }
+        .get_eth_witness()
+        .unwrap();
+        assert_eq!(witness[0], 0x00);
+        assert_eq!(witness[1..], packed);
+
+        // the recovery id is normalized to 27 or 28
+        let mut signature = eth_signature.0;
+        signature.v -= 27;
+        let witness = ChangePubKeyAuthData::EthECDSA {
+            eth_signature: PackedEthSignature(signature),
+        }
+        .get_eth_witness()
+        .unwrap();
+        assert_eq!(witness[1..], packed);
+
+        signature.v = 5;
+        let witness = ChangePubKeyAuthData::EthECDSA {
+            eth_signature: PackedEthSignature(signature),
+        }
+        .get_eth_witness();
+        assert!(matches!(witness, Err(EthSignerError::InvalidSignatureV(5))));
+        assert!(ChangePubKeyAuthData::Onchain
+            .get_eth_witness()
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_change_pubkey_create2() {
         let s = r#"
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationErrors};
 
-use crate::basic_types::{tx_hash::TxHash, Nonce};
+use crate::basic_types::{tx_hash::TxHash, GetBytes, Nonce};
 use crate::prelude::{
     AutoDeleveraging, ContractMatching, Funding, Liquidation, SpotPriceInfo, UpdateGlobalVar,
 };
@@ -178,6 +178,24 @@ impl ZkLinkTx {
         }
     }
 
+    /// Returns the bytes of the transaction that are signed by the zklink signer.
+    pub fn get_bytes(&self) -> Vec<u8> {
+        match self {
+            ZkLinkTx::Transfer(tx) => tx.get_bytes(),
+            ZkLinkTx::Withdraw(tx) => tx.get_bytes(),
+            ZkLinkTx::ChangePubKey(tx) => tx.get_bytes(),
+            ZkLinkTx::ForcedExit(tx) => tx.get_bytes(),
+            ZkLinkTx::Deposit(tx) => tx.get_bytes(),
+            ZkLinkTx::FullExit(tx) => tx.get_bytes(),
+            ZkLinkTx::OrderMatching(tx) => tx.get_bytes(),
+            ZkLinkTx::ContractMatching(tx) => tx.get_bytes(),
+            ZkLinkTx::Liquidation(tx) => tx.get_bytes(),
+            ZkLinkTx::AutoDeleveraging(tx) => tx.get_bytes(),
+            ZkLinkTx::UpdateGlobalVar(tx) => tx.get_bytes(),
+            ZkLinkTx::Funding(tx) => tx.get_bytes(),
+        }
+    }
+
     /// Returns the hash of the transaction.
     pub fn tx_hash(&self) -> TxHash {
         let tx_hash = match self {
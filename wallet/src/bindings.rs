@@ -0,0 +1,18 @@
+//! The typed bindings of the layer1 contracts generated from the bundled abi.
+#![allow(clippy::all)]
+
+pub mod zklink {
+    ethers::contract::abigen!(ZkLink, "./src/abi/Zklink.json");
+}
+
+pub mod l1_gateway {
+    ethers::contract::abigen!(L1Gateway, "./src/abi/L1_gateway.json");
+}
+
+pub mod erc20 {
+    ethers::contract::abigen!(IERC20, "./src/abi/IERC20.json");
+}
+
+pub mod erc1271 {
+    ethers::contract::abigen!(IERC1271, "./src/abi/IERC1271.json");
+}
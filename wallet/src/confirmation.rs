@@ -0,0 +1,295 @@
+use crate::error::WalletError;
+use crate::priority_op::PriorityOp;
+use crate::signer::Layer1Signer;
+use crate::wallet::Wallet;
+use crate::withdrawal::WithdrawalEvent;
+use ethers::providers::Middleware;
+use ethers::types::{Address, TransactionReceipt};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use zklink_sdk_types::prelude::{ZkLinkAddress, H256};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitConfig {
+    /// The number of blocks including the one the transaction mined in, 1 means mined.
+    pub confirmations: u64,
+    /// The interval of the first polling, doubled after each polling until `max_interval`.
+    pub initial_interval: Duration,
+    pub max_interval: Duration,
+    /// The max time of waiting, the time of the requests is not included.
+    pub timeout: Duration,
+}
+
+impl Default for WaitConfig {
+    fn default() -> Self {
+        Self {
+            confirmations: 1,
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(16),
+            timeout: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+/// The events emitted by the zklink contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "event")]
+pub enum ZkLinkEvent {
+    PriorityOp(PriorityOp),
+    Withdrawal(WithdrawalEvent),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedTransaction {
+    pub receipt: TransactionReceipt,
+    /// The confirmations of the block when the transaction is confirmed.
+    pub confirmations: u64,
+    /// The number of times the receipt disappeared or its block was replaced while waiting.
+    pub reorgs: u32,
+    pub events: Vec<ZkLinkEvent>,
+}
+
+impl ConfirmedTransaction {
+    pub fn is_success(&self) -> bool {
+        self.receipt.status.map(|s| s.as_u64()) == Some(1)
+    }
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    /// Decode the zklink events of the logs, the logs of other contracts are ignored.
+    pub fn decode_zklink_events(
+        &self,
+        zklink_addr: Address,
+        receipt: &TransactionReceipt,
+    ) -> Result<Vec<ZkLinkEvent>, WalletError> {
+        let mut events: Vec<ZkLinkEvent> = self
+            .decode_priority_ops(zklink_addr, &receipt.logs)?
+            .into_iter()
+            .map(ZkLinkEvent::PriorityOp)
+            .collect();
+        events.extend(
+            self.decode_withdrawal_events(zklink_addr, &receipt.logs)?
+                .into_iter()
+                .map(ZkLinkEvent::Withdrawal),
+        );
+        Ok(events)
+    }
+
+    /// Wait until the block of the transaction has `config.confirmations` confirmations, the
+    /// waiting restarts if the receipt disappears or the block hash of it changes(reorg).
+    pub async fn wait_for_confirmed_transaction(
+        &self,
+        zklink_addr: ZkLinkAddress,
+        tx_hash: H256,
+        config: &WaitConfig,
+    ) -> Result<ConfirmedTransaction, WalletError> {
+        let zklink_addr = Address::from_slice(zklink_addr.as_bytes());
+        let mut interval = config.initial_interval;
+        let mut waited = Duration::ZERO;
+        let mut mined_block: Option<H256> = None;
+        let mut reorgs = 0;
+        loop {
+            let receipt = self
+                .provider
+                .get_transaction_receipt(tx_hash)
+                .await
+                .map_err(WalletError::from_middleware)?;
+            match receipt {
+                Some(receipt) => {
+                    if let (Some(block_number), Some(block_hash)) =
+                        (receipt.block_number, receipt.block_hash)
+                    {
+                        if mined_block.is_some_and(|h| h != block_hash) {
+                            // mined in another block after reorg
+                            reorgs += 1;
+                            interval = config.initial_interval;
+                        }
+                        mined_block = Some(block_hash);
+                        let current_block = self
+                            .provider
+                            .get_block_number()
+                            .await
+                            .map_err(WalletError::from_middleware)?;
+                        let confirmations =
+                            (current_block.as_u64() + 1).saturating_sub(block_number.as_u64());
+                        if confirmations >= config.confirmations {
+                            // the receipt may be returned by a node which has not seen the reorg yet
+                            let block = self
+                                .provider
+                                .get_block(block_number)
+                                .await
+                                .map_err(WalletError::from_middleware)?;
+                            if block.and_then(|b| b.hash) == Some(block_hash) {
+                                let events = self.decode_zklink_events(zklink_addr, &receipt)?;
+                                return Ok(ConfirmedTransaction {
+                                    receipt,
+                                    confirmations,
+                                    reorgs,
+                                    events,
+                                });
+                            }
+                            reorgs += 1;
+                            mined_block = None;
+                            interval = config.initial_interval;
+                        }
+                    }
+                }
+                None => {
+                    if mined_block.take().is_some() {
+                        // the block of the receipt is reorged, wait for the transaction mined again
+                        reorgs += 1;
+                        interval = config.initial_interval;
+                    }
+                }
+            }
+
+            if waited >= config.timeout {
+                return Err(WalletError::Timeout(format!(
+                    "transaction {tx_hash:?} is not confirmed"
+                )));
+            }
+            async_std::task::sleep(interval).await;
+            waited += interval;
+            interval = (interval * 2).min(config.max_interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::Token;
+    use ethers::providers::MockProvider;
+    use ethers::types::{Block, Log, U256, U64};
+    use serde_json::Value;
+    use std::str::FromStr;
+    use zklink_sdk_types::basic_types::BigUint;
+
+    const ZKLINK_ADDR: &str = "0x5505a8cD4594Dbf79d8C59C0Df1414AB871CA896";
+
+    fn config() -> WaitConfig {
+        WaitConfig {
+            confirmations: 2,
+            initial_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(2),
+            timeout: Duration::from_millis(7),
+        }
+    }
+
+    fn receipt(block_number: u64, block_hash: H256, logs: Vec<Log>) -> Value {
+        serde_json::to_value(TransactionReceipt {
+            block_number: Some(U64::from(block_number)),
+            block_hash: Some(block_hash),
+            status: Some(U64::from(1)),
+            logs,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn block(block_hash: H256) -> Value {
+        serde_json::to_value(Block::<H256> {
+            hash: Some(block_hash),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn block_number(block_number: u64) -> Value {
+        serde_json::to_value(U64::from(block_number)).unwrap()
+    }
+
+    // the responses of the mock provider are popped in the reverse order they are pushed
+    fn push_responses(mock: &MockProvider, responses: Vec<Value>) {
+        for response in responses.into_iter().rev() {
+            mock.push(response).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_confirmed_transaction_after_reorgs() {
+        let (wallet, mock) = Wallet::mocked();
+        let zklink_addr = ZkLinkAddress::from_str(ZKLINK_ADDR).unwrap();
+        let withdrawal = wallet
+            .get_l1_contract(false)
+            .unwrap()
+            .event("Withdrawal")
+            .unwrap()
+            .signature();
+        let log = Log {
+            address: Address::from_slice(zklink_addr.as_bytes()),
+            topics: vec![withdrawal, H256::from_low_u64_be(18)],
+            data: ethers::abi::encode(&[Token::Uint(U256::from(1000))]).into(),
+            ..Default::default()
+        };
+        // the same event emitted by another contract is ignored
+        let other_log = Log {
+            address: Address::repeat_byte(0x11),
+            ..log.clone()
+        };
+        let (hash_a, hash_b, hash_c, hash_d) = (
+            H256::repeat_byte(0xa),
+            H256::repeat_byte(0xb),
+            H256::repeat_byte(0xc),
+            H256::repeat_byte(0xd),
+        );
+        push_responses(
+            &mock,
+            vec![
+                // mined in block 10, not confirmed
+                receipt(10, hash_a, vec![]),
+                block_number(10),
+                // mined again in block 11 after the reorg
+                receipt(11, hash_b, vec![]),
+                block_number(11),
+                // the receipt disappears
+                Value::Null,
+                // confirmed by a node which has not seen the reorg of block 12
+                receipt(12, hash_c, vec![]),
+                block_number(13),
+                block(hash_d),
+                // confirmed
+                receipt(12, hash_d, vec![log, other_log]),
+                block_number(13),
+                block(hash_d),
+            ],
+        );
+        let confirmed = wallet
+            .wait_for_confirmed_transaction(zklink_addr, H256::zero(), &config())
+            .await
+            .unwrap();
+        assert!(confirmed.is_success());
+        assert_eq!(confirmed.receipt.block_hash, Some(hash_d));
+        assert_eq!(confirmed.confirmations, 2);
+        assert_eq!(confirmed.reorgs, 3);
+        assert_eq!(
+            confirmed.events,
+            vec![ZkLinkEvent::Withdrawal(WithdrawalEvent::Withdrawal {
+                token_id: 18,
+                amount: BigUint::from(1000u32),
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_confirmed_transaction_backoff() {
+        let (wallet, mock) = Wallet::mocked();
+        let zklink_addr = ZkLinkAddress::from_str(ZKLINK_ADDR).unwrap();
+        // the intervals are 1ms, 2ms, 2ms and 2ms, the receipt is polled 5 times before the
+        // timeout of 7ms: 4 times if the interval is not capped and 8 times if not doubled
+        push_responses(&mock, vec![Value::Null; 5]);
+        let result = wallet
+            .wait_for_confirmed_transaction(zklink_addr, H256::zero(), &config())
+            .await;
+        assert!(matches!(result, Err(WalletError::Timeout(_))));
+        for _ in 0..5 {
+            mock.assert_request("eth_getTransactionReceipt", [H256::zero()])
+                .unwrap();
+        }
+        assert!(mock
+            .assert_request("eth_getTransactionReceipt", [H256::zero()])
+            .is_err());
+    }
+}
@@ -0,0 +1,416 @@
+use crate::bindings::zklink::ZkLink;
+use crate::error::WalletError;
+use crate::eth::{EthTxOption, EthTxParam};
+use crate::exodus::StoredBlockInfo;
+use crate::signer::Layer1Signer;
+use crate::wallet::{view_call, Wallet};
+use ethers::abi::Address;
+use ethers::providers::Middleware;
+use ethers::types::U256;
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use zklink_sdk_provider::response::BlockNumberResp;
+#[cfg(not(target_arch = "wasm32"))]
+use zklink_sdk_provider::ZkLinkRpcClient;
+use zklink_sdk_types::prelude::H256;
+
+/// The block heights of the zklink main contract.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractBlockHeights {
+    pub total_blocks_committed: u32,
+    pub total_blocks_proven: u32,
+    pub total_blocks_executed: u32,
+    pub total_blocks_synchronized: u32,
+}
+
+/// The priority queue of the zklink main contract.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriorityQueueState {
+    pub first_priority_request_id: u64,
+    pub total_open_priority_requests: u64,
+    pub total_committed_priority_requests: u64,
+}
+
+/// The `tokens` of the zklink main contract.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractToken {
+    pub registered: bool,
+    pub paused: bool,
+    pub token_address: Address,
+    pub decimals: u8,
+}
+
+/// The `bridges` of the zklink main contract.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeInfo {
+    pub bridge: Address,
+    pub enable_bridge_to: bool,
+    pub enable_bridge_from: bool,
+}
+
+/// The block heights reported by the zklink rpc and verified by the layer1 contract.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeightCheck {
+    pub l2: BlockNumberResp,
+    pub l1: ContractBlockHeights,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl BlockHeightCheck {
+    /// The heights are diverged if the committed or the verified height of the zklink rpc is
+    /// different from the committed or the executed height of the contract by more than
+    /// `max_lag` blocks.
+    pub fn is_diverged(&self, max_lag: u32) -> bool {
+        self.l2.committed.abs_diff(self.l1.total_blocks_committed) > max_lag
+            || self.l2.verified.abs_diff(self.l1.total_blocks_executed) > max_lag
+    }
+}
+
+/// The read-only queries of the zklink main contract state, `eth_params.to` must be the
+/// address of the main contract.
+pub struct ZkLinkContractReader<'a, M, S> {
+    wallet: &'a Wallet<M, S>,
+    eth_params: EthTxParam,
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    pub fn contract_reader(
+        &self,
+        eth_params: EthTxOption,
+    ) -> Result<ZkLinkContractReader<'_, M, S>, WalletError> {
+        Ok(ZkLinkContractReader {
+            wallet: self,
+            eth_params: eth_params.try_into()?,
+        })
+    }
+}
+
+impl<'a, M: Middleware, S: Layer1Signer> ZkLinkContractReader<'a, M, S> {
+    fn contract(&self) -> ZkLink<M> {
+        self.wallet.zklink(self.eth_params.to)
+    }
+
+    pub async fn total_blocks_committed(&self) -> Result<u32, WalletError> {
+        view_call(self.contract().total_blocks_committed()).await
+    }
+
+    pub async fn total_blocks_proven(&self) -> Result<u32, WalletError> {
+        view_call(self.contract().total_blocks_proven()).await
+    }
+
+    pub async fn total_blocks_executed(&self) -> Result<u32, WalletError> {
+        view_call(self.contract().total_blocks_executed()).await
+    }
+
+    pub async fn total_blocks_synchronized(&self) -> Result<u32, WalletError> {
+        view_call(self.contract().total_blocks_synchronized()).await
+    }
+
+    pub async fn block_heights(&self) -> Result<ContractBlockHeights, WalletError> {
+        Ok(ContractBlockHeights {
+            total_blocks_committed: self.total_blocks_committed().await?,
+            total_blocks_proven: self.total_blocks_proven().await?,
+            total_blocks_executed: self.total_blocks_executed().await?,
+            total_blocks_synchronized: self.total_blocks_synchronized().await?,
+        })
+    }
+
+    pub async fn priority_queue(&self) -> Result<PriorityQueueState, WalletError> {
+        let contract = self.contract();
+        Ok(PriorityQueueState {
+            first_priority_request_id: view_call(contract.first_priority_request_id()).await?,
+            total_open_priority_requests: view_call(contract.total_open_priority_requests())
+                .await?,
+            total_committed_priority_requests: view_call(
+                contract.total_committed_priority_requests(),
+            )
+            .await?,
+        })
+    }
+
+    /// The hash of the `StoredBlockInfo` of the committed block, zero if the block is not
+    /// committed or reverted.
+    pub async fn stored_block_hash(&self, block_number: u32) -> Result<H256, WalletError> {
+        let hash = view_call(self.contract().stored_block_hashes(block_number)).await?;
+        Ok(H256::from(hash))
+    }
+
+    /// The synchronized progress of the block, the bits of the chains which have synchronized
+    /// the block are set.
+    pub async fn synchronized_progress(
+        &self,
+        stored_block_info: &StoredBlockInfo,
+    ) -> Result<U256, WalletError> {
+        view_call(
+            self.contract()
+                .get_synchronized_progress(stored_block_info.to_contract()),
+        )
+        .await
+    }
+
+    pub async fn token(&self, token_id: u16) -> Result<ContractToken, WalletError> {
+        let (registered, paused, token_address, decimals) =
+            view_call(self.contract().tokens(token_id)).await?;
+        Ok(ContractToken {
+            registered,
+            paused,
+            token_address,
+            decimals,
+        })
+    }
+
+    /// Whether the token is paused, the paused token can not be deposited.
+    pub async fn is_token_paused(&self, token_id: u16) -> Result<bool, WalletError> {
+        Ok(self.token(token_id).await?.paused)
+    }
+
+    /// The token id of the layer1 token address, zero if the token is not registered.
+    pub async fn token_id(&self, token_address: Address) -> Result<u16, WalletError> {
+        view_call(self.contract().token_ids(token_address)).await
+    }
+
+    pub async fn bridge(&self, index: u64) -> Result<BridgeInfo, WalletError> {
+        let (bridge, enable_bridge_to, enable_bridge_from) =
+            view_call(self.contract().bridges(U256::from(index))).await?;
+        Ok(BridgeInfo {
+            bridge,
+            enable_bridge_to,
+            enable_bridge_from,
+        })
+    }
+
+    /// The index of the bridge in `bridges`, zero if the bridge is not added.
+    pub async fn bridge_index(&self, bridge: Address) -> Result<u64, WalletError> {
+        let index = view_call(self.contract().bridge_index(bridge)).await?;
+        Ok(index.as_u64())
+    }
+
+    pub async fn is_validator(&self, validator: Address) -> Result<bool, WalletError> {
+        view_call(self.contract().validators(validator)).await
+    }
+
+    pub async fn is_exodus_mode(&self) -> Result<bool, WalletError> {
+        view_call(self.contract().exodus_mode()).await
+    }
+
+    /// Get the block heights of the zklink rpc and the contract to check whether they are
+    /// diverged.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn check_block_heights<C: ZkLinkRpcClient + Sync>(
+        &self,
+        rpc: &C,
+    ) -> Result<BlockHeightCheck, WalletError> {
+        let l2 = rpc
+            .block_info()
+            .await
+            .map_err(|e| WalletError::ZkLinkRpcError(e.to_string()))?;
+        let l1 = self.block_heights().await?;
+        Ok(BlockHeightCheck { l2, l1 })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::Token;
+    use ethers::contract::ContractCall;
+    use ethers::providers::{MockProvider, Provider};
+    use ethers::types::Bytes;
+    use serde_json::json;
+    use zklink_sdk_types::basic_types::ZkLinkAddress;
+
+    fn main_contract() -> ZkLinkAddress {
+        ZkLinkAddress::from([0x55; 20])
+    }
+
+    fn eth_params() -> EthTxOption {
+        EthTxOption {
+            to: main_contract(),
+            ..Default::default()
+        }
+    }
+
+    fn push_return(mock: &MockProvider, tokens: &[Token]) {
+        mock.push(Bytes::from(ethers::abi::encode(tokens))).unwrap();
+    }
+
+    fn assert_call<D>(mock: &MockProvider, call: ContractCall<Provider<MockProvider>, D>) {
+        mock.assert_request("eth_call", (call.tx, "latest"))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_token() {
+        let (wallet, mock) = Wallet::mocked();
+        let reader = wallet.contract_reader(eth_params()).unwrap();
+        let zklink = wallet.zklink(Address::repeat_byte(0x55));
+        let token_address = Address::repeat_byte(0x44);
+        push_return(
+            &mock,
+            &[
+                Token::Bool(true),
+                Token::Bool(false),
+                Token::Address(token_address),
+                Token::Uint(U256::from(18)),
+            ],
+        );
+        let token = reader.token(17).await.unwrap();
+        assert_eq!(
+            token,
+            ContractToken {
+                registered: true,
+                paused: false,
+                token_address,
+                decimals: 18,
+            }
+        );
+        assert_call(&mock, zklink.tokens(17));
+
+        push_return(
+            &mock,
+            &[
+                Token::Bool(true),
+                Token::Bool(true),
+                Token::Address(token_address),
+                Token::Uint(U256::from(6)),
+            ],
+        );
+        assert!(reader.is_token_paused(18).await.unwrap());
+        assert_call(&mock, zklink.tokens(18));
+
+        // the truncated return data can not be decoded
+        mock.push(Bytes::from(vec![0u8; 32])).unwrap();
+        assert!(matches!(
+            reader.token(17).await,
+            Err(WalletError::GetErrorResult(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bridge() {
+        let (wallet, mock) = Wallet::mocked();
+        let reader = wallet.contract_reader(eth_params()).unwrap();
+        let zklink = wallet.zklink(Address::repeat_byte(0x55));
+        let bridge = Address::repeat_byte(0x66);
+        push_return(
+            &mock,
+            &[
+                Token::Address(bridge),
+                Token::Bool(true),
+                Token::Bool(false),
+            ],
+        );
+        assert_eq!(
+            reader.bridge(1).await.unwrap(),
+            BridgeInfo {
+                bridge,
+                enable_bridge_to: true,
+                enable_bridge_from: false,
+            }
+        );
+        assert_call(&mock, zklink.bridges(U256::from(1)));
+
+        push_return(&mock, &[Token::Uint(U256::from(1))]);
+        assert_eq!(reader.bridge_index(bridge).await.unwrap(), 1);
+        assert_call(&mock, zklink.bridge_index(bridge));
+    }
+
+    #[tokio::test]
+    async fn test_priority_queue() {
+        let (wallet, mock) = Wallet::mocked();
+        let reader = wallet.contract_reader(eth_params()).unwrap();
+        // the responses are popped in the reverse order: first, open and committed
+        push_return(&mock, &[Token::Uint(U256::from(3))]);
+        push_return(&mock, &[Token::Uint(U256::from(5))]);
+        push_return(&mock, &[Token::Uint(U256::from(100))]);
+        assert_eq!(
+            reader.priority_queue().await.unwrap(),
+            PriorityQueueState {
+                first_priority_request_id: 100,
+                total_open_priority_requests: 5,
+                total_committed_priority_requests: 3,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stored_block_hash() {
+        let (wallet, mock) = Wallet::mocked();
+        let reader = wallet.contract_reader(eth_params()).unwrap();
+        let zklink = wallet.zklink(Address::repeat_byte(0x55));
+        let hash = H256::repeat_byte(0x77);
+        push_return(&mock, &[Token::FixedBytes(hash.as_bytes().to_vec())]);
+        assert_eq!(reader.stored_block_hash(10).await.unwrap(), hash);
+        assert_call(&mock, zklink.stored_block_hashes(10));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_check_block_heights() {
+        let (wallet, mock) = Wallet::mocked();
+        let reader = wallet.contract_reader(eth_params()).unwrap();
+        // the responses are popped in the reverse order: committed, proven, executed and
+        // synchronized
+        for height in [6u32, 6, 9, 10] {
+            push_return(&mock, &[Token::Uint(U256::from(height))]);
+        }
+        let block_info = BlockNumberResp {
+            last_block_number: 12,
+            timestamp: 0,
+            committed: 10,
+            verified: 8,
+        };
+        let (rpc, _handle) = crate::wallet::mock_rpc(vec![(
+            "getLatestBlockNumber",
+            vec![Some(json!(block_info))],
+        )])
+        .await;
+        let check = reader.check_block_heights(&rpc).await.unwrap();
+        assert_eq!(check.l2.committed, 10);
+        assert_eq!(check.l2.verified, 8);
+        assert_eq!(
+            check.l1,
+            ContractBlockHeights {
+                total_blocks_committed: 10,
+                total_blocks_proven: 9,
+                total_blocks_executed: 6,
+                total_blocks_synchronized: 6,
+            }
+        );
+        assert!(!check.is_diverged(2));
+
+        // the error of the zklink rpc is not the error of the contract
+        let (rpc, _handle) = crate::wallet::mock_rpc(vec![]).await;
+        assert!(matches!(
+            reader.check_block_heights(&rpc).await,
+            Err(WalletError::ZkLinkRpcError(_))
+        ));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_block_height_check() {
+        let check = BlockHeightCheck {
+            l2: BlockNumberResp {
+                last_block_number: 12,
+                timestamp: 0,
+                committed: 10,
+                verified: 8,
+            },
+            l1: ContractBlockHeights {
+                total_blocks_committed: 10,
+                total_blocks_proven: 9,
+                total_blocks_executed: 6,
+                total_blocks_synchronized: 6,
+            },
+        };
+        assert!(!check.is_diverged(2));
+        assert!(check.is_diverged(1));
+    }
+}
@@ -0,0 +1,586 @@
+use crate::error::WalletError;
+use crate::priority_op::PriorityOp;
+use crate::signer::Layer1Signer;
+use crate::wallet::Wallet;
+use ethers::providers::{Http, Middleware, Provider};
+use ethers::types::{Address, TransactionReceipt};
+use futures::Stream;
+use std::time::{Duration, Instant};
+use zklink_sdk_provider::response::{AccountQuery, ChainResp, TxHashOrDetailResp};
+use zklink_sdk_provider::ZkLinkRpcClient;
+use zklink_sdk_signers::eth_signer::EthSigner;
+use zklink_sdk_types::basic_types::tx_hash::TxHash;
+use zklink_sdk_types::prelude::{BigIntSerdeWrapper, ZkLinkAddress, H256};
+use zklink_sdk_types::tx_type::deposit::Deposit;
+
+/// The progress of a deposit from the layer1 transaction to the zklink execution.
+#[derive(Debug, Clone)]
+pub enum DepositStatus {
+    /// The layer1 transaction is mined.
+    L1Mined { block_number: u64 },
+    /// The number of confirmations of the layer1 transaction block.
+    L1Confirmations { confirmations: u64, required: u64 },
+    /// The priority operation is seen by zklink but not executed.
+    PriorityOpSeen { tx_hash: TxHash },
+    /// The priority operation is executed by zklink, the balance is the credited balance of the
+    /// target token if it can be queried.
+    L2Executed {
+        tx_hash: TxHash,
+        success: bool,
+        fail_reason: Option<String>,
+        balance: Option<BigIntSerdeWrapper>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct DepositTrackerConfig {
+    /// The interval of polling the layer1 provider and the zklink rpc.
+    pub poll_interval: Duration,
+    /// The max time of waiting for the layer1 transaction mined and confirmed.
+    pub l1_timeout: Duration,
+    /// The max time of waiting for the priority operation executed by zklink.
+    pub l2_timeout: Duration,
+}
+
+impl Default for DepositTrackerConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(3),
+            l1_timeout: Duration::from_secs(30 * 60),
+            l2_timeout: Duration::from_secs(30 * 60),
+        }
+    }
+}
+
+enum Stage {
+    L1Pending,
+    L1Confirming {
+        receipt: Box<TransactionReceipt>,
+        confirmations: Option<u64>,
+    },
+    L2Pending {
+        deposit: Box<Deposit>,
+        tx_hash: TxHash,
+        seen: bool,
+        /// The executed timestamp(in microseconds) of the last tx scanned in the pending block.
+        pending_cursor: u64,
+    },
+    Done,
+}
+
+/// Track the deposit from the layer1 transaction to the zklink execution.
+pub struct DepositTracker<'a, C, M = Provider<Http>, S = EthSigner> {
+    wallet: &'a Wallet<M, S>,
+    rpc: &'a C,
+    zklink_addr: Address,
+    l1_tx_hash: H256,
+    required_confirmations: u64,
+    config: DepositTrackerConfig,
+    stage: Stage,
+    deadline: Instant,
+}
+
+impl<'a, C, M, S> DepositTracker<'a, C, M, S>
+where
+    C: ZkLinkRpcClient + Sync,
+    M: Middleware,
+    S: Layer1Signer,
+{
+    /// Create the tracker of the deposit sent to the zklink contract `zklink_addr`.
+    pub fn new(
+        wallet: &'a Wallet<M, S>,
+        rpc: &'a C,
+        zklink_addr: ZkLinkAddress,
+        l1_tx_hash: H256,
+        required_confirmations: u64,
+        config: DepositTrackerConfig,
+    ) -> Self {
+        let deadline = Instant::now() + config.l1_timeout;
+        Self {
+            wallet,
+            rpc,
+            zklink_addr: Address::from_slice(zklink_addr.as_bytes()),
+            l1_tx_hash,
+            required_confirmations,
+            config,
+            stage: Stage::L1Pending,
+            deadline,
+        }
+    }
+
+    /// Create the tracker with the main contract and the `deposit_confirmation` of the chain the
+    /// deposit is sent to.
+    pub fn new_with_chain(
+        wallet: &'a Wallet<M, S>,
+        rpc: &'a C,
+        l1_tx_hash: H256,
+        chain: &ChainResp,
+        config: DepositTrackerConfig,
+    ) -> Self {
+        Self::new(
+            wallet,
+            rpc,
+            chain.main_contract.clone(),
+            l1_tx_hash,
+            chain.deposit_confirmation,
+            config,
+        )
+    }
+
+    fn check_deadline(&self, stage: &str) -> Result<(), WalletError> {
+        if Instant::now() >= self.deadline {
+            Err(WalletError::Timeout(format!(
+                "deposit {:?} {stage}",
+                self.l1_tx_hash
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wait for the next status of the deposit, returns `None` when the deposit is executed by zklink.
+    pub async fn next_status(&mut self) -> Result<Option<DepositStatus>, WalletError> {
+        loop {
+            match &mut self.stage {
+                Stage::L1Pending => {
+                    let receipt = self
+                        .wallet
+                        .provider
+                        .get_transaction_receipt(self.l1_tx_hash)
+                        .await
+                        .map_err(WalletError::from_middleware)?;
+                    if let Some(receipt) = receipt {
+                        if let Some(block_number) = receipt.block_number {
+                            if receipt.status.map(|s| s.as_u64()) == Some(0) {
+                                self.stage = Stage::Done;
+                                return Err(WalletError::GetErrorResult(format!(
+                                    "deposit {:?} failed in layer1",
+                                    self.l1_tx_hash
+                                )));
+                            }
+                            self.stage = Stage::L1Confirming {
+                                receipt: Box::new(receipt),
+                                confirmations: None,
+                            };
+                            return Ok(Some(DepositStatus::L1Mined {
+                                block_number: block_number.as_u64(),
+                            }));
+                        }
+                    }
+                    self.check_deadline("is not mined")?;
+                }
+                Stage::L1Confirming {
+                    receipt,
+                    confirmations,
+                } => {
+                    let mined_block = receipt.block_number.unwrap_or_default().as_u64();
+                    let current_block = self
+                        .wallet
+                        .provider
+                        .get_block_number()
+                        .await
+                        .map_err(WalletError::from_middleware)?
+                        .as_u64();
+                    let current_confirmations = (current_block + 1).saturating_sub(mined_block);
+                    if current_confirmations >= self.required_confirmations {
+                        let deposit = self
+                            .wallet
+                            .decode_priority_ops(self.zklink_addr, &receipt.logs)?
+                            .into_iter()
+                            .find_map(|op| match op {
+                                PriorityOp::Deposit(deposit) => Some(deposit),
+                                PriorityOp::FullExit(_) => None,
+                            })
+                            .ok_or_else(|| {
+                                WalletError::GetErrorResult(format!(
+                                    "no deposit found in {:?}",
+                                    self.l1_tx_hash
+                                ))
+                            })?;
+                        let tx_hash = PriorityOp::Deposit(deposit.clone()).tx_hash();
+                        self.stage = Stage::L2Pending {
+                            deposit,
+                            tx_hash,
+                            seen: false,
+                            pending_cursor: 0,
+                        };
+                        self.deadline = Instant::now() + self.config.l2_timeout;
+                        return Ok(Some(DepositStatus::L1Confirmations {
+                            confirmations: current_confirmations,
+                            required: self.required_confirmations,
+                        }));
+                    }
+                    if *confirmations != Some(current_confirmations) {
+                        *confirmations = Some(current_confirmations);
+                        return Ok(Some(DepositStatus::L1Confirmations {
+                            confirmations: current_confirmations,
+                            required: self.required_confirmations,
+                        }));
+                    }
+                    self.check_deadline("is not confirmed")?;
+                }
+                Stage::L2Pending {
+                    deposit,
+                    tx_hash,
+                    seen,
+                    pending_cursor,
+                } => {
+                    // the priority op is not found until it's synchronized by zklink
+                    if let Ok(tx) = self.rpc.tx_info(*tx_hash, false).await {
+                        if tx.receipt.executed {
+                            let tx_hash = *tx_hash;
+                            let balance = query_balance(self.rpc, deposit).await;
+                            self.stage = Stage::Done;
+                            return Ok(Some(DepositStatus::L2Executed {
+                                tx_hash,
+                                success: tx.receipt.success,
+                                fail_reason: tx.receipt.fail_reason,
+                                balance,
+                            }));
+                        }
+                        if !*seen {
+                            *seen = true;
+                            return Ok(Some(DepositStatus::PriorityOpSeen { tx_hash: *tx_hash }));
+                        }
+                    }
+                    if !*seen && find_in_pending_block(self.rpc, *tx_hash, pending_cursor).await {
+                        *seen = true;
+                        return Ok(Some(DepositStatus::PriorityOpSeen { tx_hash: *tx_hash }));
+                    }
+                    self.check_deadline("is not executed by zklink")?;
+                }
+                Stage::Done => return Ok(None),
+            }
+            async_std::task::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Wait until the deposit is executed by zklink, returns the final status.
+    pub async fn wait_for_executed(mut self) -> Result<DepositStatus, WalletError> {
+        let mut last_status = None;
+        while let Some(status) = self.next_status().await? {
+            last_status = Some(status);
+        }
+        last_status.ok_or_else(|| {
+            WalletError::GetErrorResult(format!(
+                "the tracking of deposit {:?} is already finished",
+                self.l1_tx_hash
+            ))
+        })
+    }
+
+    /// Convert the tracker into a stream of the deposit status updates,
+    /// the stream ends after the deposit is executed by zklink or an error occurred.
+    pub fn into_stream(self) -> impl Stream<Item = Result<DepositStatus, WalletError>> + 'a
+    where
+        C: 'a,
+        M: 'a,
+        S: 'a,
+    {
+        futures::stream::unfold(self, |mut tracker| async move {
+            match tracker.next_status().await {
+                Ok(Some(status)) => Some((Ok(status), tracker)),
+                Ok(None) => None,
+                Err(e) => {
+                    tracker.stage = Stage::Done;
+                    Some((Err(e), tracker))
+                }
+            }
+        })
+    }
+}
+
+/// Scan the txs of the pending block executed after `cursor` for the priority op, the cursor is
+/// moved to the last scanned tx.
+async fn find_in_pending_block<C: ZkLinkRpcClient + Sync>(
+    rpc: &C,
+    tx_hash: TxHash,
+    cursor: &mut u64,
+) -> bool {
+    let Ok(txs) = rpc.pending_block_detail(*cursor, true, false, None).await else {
+        return false;
+    };
+    let mut found = false;
+    for tx in txs {
+        match tx {
+            TxHashOrDetailResp::TxDetail(tx) => {
+                let executed_timestamp = tx.executed_timestamp.timestamp_micros() as u64;
+                *cursor = (*cursor).max(executed_timestamp);
+                found |= tx.tx_hash == tx_hash;
+            }
+            TxHashOrDetailResp::Hash(hash) => found |= hash == tx_hash,
+        }
+    }
+    found
+}
+
+async fn query_balance<C: ZkLinkRpcClient + Sync>(
+    rpc: &C,
+    deposit: &Deposit,
+) -> Option<BigIntSerdeWrapper> {
+    let account = rpc
+        .account_info(AccountQuery::Address(deposit.to.clone()))
+        .await
+        .ok()?;
+    let mut balances = rpc
+        .account_balances(account.id, Some(deposit.sub_account_id))
+        .await
+        .ok()?;
+    balances
+        .get_mut(&deposit.sub_account_id)?
+        .remove(&deposit.l2_target_token)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wallet::mock_rpc;
+    use ethers::abi::Token;
+    use ethers::providers::MockProvider;
+    use ethers::types::{Log, U64};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use zklink_sdk_provider::response::{AccountInfoResp, TxReceiptResp, TxResp};
+    use zklink_sdk_signers::zklink_signer::PubKeyHash;
+    use zklink_sdk_types::basic_types::{AccountId, BigUint, Nonce, SubAccountId, TokenId};
+    use zklink_sdk_types::tx_type::zklink_tx::ZkLinkTx;
+
+    const OWNER: &str = "afaff3ad1a0425d792432d9ecd1c3e26ef2c42e9";
+
+    fn config() -> DepositTrackerConfig {
+        DepositTrackerConfig {
+            poll_interval: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    fn zklink_addr() -> ZkLinkAddress {
+        ZkLinkAddress::from([0x11; 20])
+    }
+
+    fn l1_tx_hash() -> H256 {
+        H256::repeat_byte(0x22)
+    }
+
+    fn receipt(wallet: &Wallet<Provider<MockProvider>>, status: u64) -> TransactionReceipt {
+        let event = wallet
+            .get_l1_contract(false)
+            .unwrap()
+            .event("NewPriorityRequest")
+            .unwrap()
+            .signature();
+        let pubdata = ethers::utils::hex::decode(format!(
+            "0101000000000100120011{:032x}{:0>64}",
+            100u128, OWNER
+        ))
+        .unwrap();
+        let log = Log {
+            address: Address::from_slice(zklink_addr().as_bytes()),
+            topics: vec![event],
+            data: ethers::abi::encode(&[
+                Token::Address(Address::from_str(OWNER).unwrap()),
+                Token::Uint(32001u64.into()),
+                Token::Uint(1u64.into()),
+                Token::Bytes(pubdata),
+                Token::Uint(100u64.into()),
+            ])
+            .into(),
+            transaction_hash: Some(l1_tx_hash()),
+            ..Default::default()
+        };
+        TransactionReceipt {
+            transaction_hash: l1_tx_hash(),
+            block_number: Some(10u64.into()),
+            block_hash: Some(H256::repeat_byte(0x33)),
+            status: Some(status.into()),
+            logs: vec![log],
+            ..Default::default()
+        }
+    }
+
+    fn deposit(receipt: &TransactionReceipt) -> PriorityOp {
+        let (wallet, _mock) = Wallet::mocked();
+        let zklink_addr = Address::from_slice(zklink_addr().as_bytes());
+        wallet
+            .decode_priority_ops(zklink_addr, &receipt.logs)
+            .unwrap()
+            .remove(0)
+    }
+
+    fn tx_resp(deposit: &PriorityOp, success: bool) -> Value {
+        let PriorityOp::Deposit(tx) = deposit else {
+            panic!("not a deposit");
+        };
+        let tx_resp = TxResp {
+            tx_hash: deposit.tx_hash(),
+            tx: ZkLinkTx::from(*tx.clone()),
+            receipt: TxReceiptResp {
+                executed: true,
+                executed_timestamp: None,
+                success,
+                fail_reason: (!success).then(|| "invalid token".to_string()),
+                block: None,
+                index: None,
+            },
+            updates: vec![],
+        };
+        serde_json::to_value(tx_resp).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_track_deposit() {
+        let (wallet, mock) = Wallet::mocked();
+        let receipt = receipt(&wallet, 1);
+        let deposit = deposit(&receipt);
+        let tx_hash = deposit.tx_hash();
+        mock.push(U64::from(11)).unwrap();
+        mock.push(U64::from(10)).unwrap();
+        mock.push(receipt).unwrap();
+
+        let account = AccountInfoResp {
+            id: AccountId(10),
+            address: ZkLinkAddress::from_hex(OWNER).unwrap(),
+            nonce: Nonce(0),
+            pub_key_hash: PubKeyHash::zero(),
+            sub_account_nonces: Default::default(),
+        };
+        let balances = HashMap::from([(
+            SubAccountId(1),
+            HashMap::from([(TokenId(17), BigIntSerdeWrapper::from(BigUint::from(100u32)))]),
+        )]);
+        let pending_block = json!([{
+            "txHash": tx_hash,
+            "tx": tx_resp(&deposit, true)["tx"],
+            "executedTimestamp": 1700000000000000u64,
+            "updates": [],
+        }]);
+        let (rpc, _handle) = mock_rpc(vec![
+            (
+                "getTransactionByHash",
+                vec![None, Some(tx_resp(&deposit, true))],
+            ),
+            ("getPendingBlock", vec![Some(pending_block)]),
+            ("getAccount", vec![Some(json!(account))]),
+            ("getAccountBalances", vec![Some(json!(balances))]),
+        ])
+        .await;
+
+        let mut tracker =
+            DepositTracker::new(&wallet, &rpc, zklink_addr(), l1_tx_hash(), 2, config());
+        let status = tracker.next_status().await.unwrap();
+        assert!(matches!(
+            status,
+            Some(DepositStatus::L1Mined { block_number: 10 })
+        ));
+        let status = tracker.next_status().await.unwrap();
+        assert!(matches!(
+            status,
+            Some(DepositStatus::L1Confirmations {
+                confirmations: 1,
+                required: 2
+            })
+        ));
+        let status = tracker.next_status().await.unwrap();
+        assert!(matches!(
+            status,
+            Some(DepositStatus::L1Confirmations {
+                confirmations: 2,
+                required: 2
+            })
+        ));
+        // the priority op is found in the pending block before it's queried by the hash
+        let status = tracker.next_status().await.unwrap();
+        assert!(
+            matches!(status, Some(DepositStatus::PriorityOpSeen { tx_hash: hash }) if hash == tx_hash)
+        );
+        let Some(DepositStatus::L2Executed {
+            tx_hash: hash,
+            success,
+            fail_reason,
+            balance,
+        }) = tracker.next_status().await.unwrap()
+        else {
+            panic!("the deposit is not executed");
+        };
+        assert_eq!(hash, tx_hash);
+        assert!(success);
+        assert!(fail_reason.is_none());
+        assert_eq!(balance.unwrap().to_string(), "100");
+        assert!(tracker.next_status().await.unwrap().is_none());
+
+        let error = tracker.wait_for_executed().await.unwrap_err();
+        assert!(error.to_string().contains("is already finished"));
+    }
+
+    #[tokio::test]
+    async fn test_track_failed_deposit() {
+        let (wallet, mock) = Wallet::mocked();
+        let (rpc, _handle) = mock_rpc(vec![]).await;
+        mock.push(receipt(&wallet, 0)).unwrap();
+        let mut tracker =
+            DepositTracker::new(&wallet, &rpc, zklink_addr(), l1_tx_hash(), 1, config());
+        let error = tracker.next_status().await.unwrap_err();
+        assert!(error.to_string().contains("failed in layer1"));
+        assert!(tracker.next_status().await.unwrap().is_none());
+
+        let receipt = receipt(&wallet, 1);
+        let deposit = deposit(&receipt);
+        mock.push(U64::from(10)).unwrap();
+        mock.push(receipt).unwrap();
+        let (rpc, _handle) = mock_rpc(vec![(
+            "getTransactionByHash",
+            vec![Some(tx_resp(&deposit, false))],
+        )])
+        .await;
+        let tracker = DepositTracker::new(&wallet, &rpc, zklink_addr(), l1_tx_hash(), 1, config());
+        let status = tracker.wait_for_executed().await.unwrap();
+        let DepositStatus::L2Executed {
+            success,
+            fail_reason,
+            balance,
+            ..
+        } = status
+        else {
+            panic!("the deposit is not executed");
+        };
+        assert!(!success);
+        assert_eq!(fail_reason.as_deref(), Some("invalid token"));
+        // the account is not found
+        assert!(balance.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_track_deposit_timeout() {
+        let (wallet, mock) = Wallet::mocked();
+        let (rpc, _handle) = mock_rpc(vec![]).await;
+        let config = DepositTrackerConfig {
+            l1_timeout: Duration::ZERO,
+            l2_timeout: Duration::ZERO,
+            ..config()
+        };
+
+        mock.push(Option::<TransactionReceipt>::None).unwrap();
+        let mut tracker = DepositTracker::new(
+            &wallet,
+            &rpc,
+            zklink_addr(),
+            l1_tx_hash(),
+            1,
+            config.clone(),
+        );
+        let error = tracker.next_status().await.unwrap_err();
+        assert!(matches!(error, WalletError::Timeout(msg) if msg.contains("is not mined")));
+
+        mock.push(U64::from(10)).unwrap();
+        mock.push(receipt(&wallet, 1)).unwrap();
+        let mut tracker =
+            DepositTracker::new(&wallet, &rpc, zklink_addr(), l1_tx_hash(), 1, config);
+        assert!(tracker.next_status().await.is_ok());
+        assert!(tracker.next_status().await.is_ok());
+        let error = tracker.next_status().await.unwrap_err();
+        assert!(
+            matches!(error, WalletError::Timeout(msg) if msg.contains("is not executed by zklink"))
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::error::WalletError;
+use crate::signer::Layer1Signer;
+use crate::wallet::{view_call, Wallet};
+use ethers::abi::Address;
+use ethers::providers::Middleware;
+use ethers::utils::hash_message;
+use zklink_sdk_signers::eth_signer::EIP1271Signature;
+use zklink_sdk_types::basic_types::ZkLinkAddress;
+use zklink_sdk_types::prelude::H256;
+use zklink_sdk_types::tx_type::transfer::Transfer;
+use zklink_sdk_types::tx_type::withdraw::Withdraw;
+
+/// The `bytes4(keccak256("isValidSignature(bytes32,bytes)"))` returned by the account contract
+/// when the signature is valid.
+pub const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// The result of verifying the `EIP1271Signature` by the account contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EIP1271VerifyResult {
+    /// The account contract returned the magic value.
+    Valid,
+    /// The account contract returned a value other than the magic value.
+    Invalid(Vec<u8>),
+    /// There is no contract code deployed at the account address.
+    NotContract,
+}
+
+impl EIP1271VerifyResult {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, EIP1271VerifyResult::Valid)
+    }
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    /// Calls `isValidSignature(bytes32,bytes)` of the account contract with the hash of the message.
+    pub async fn inner_verify_eip1271_signature(
+        &self,
+        account: Address,
+        hash: H256,
+        signature: &EIP1271Signature,
+    ) -> Result<EIP1271VerifyResult, WalletError> {
+        let code = self
+            .provider
+            .get_code(account, None)
+            .await
+            .map_err(WalletError::from_middleware)?;
+        if code.is_empty() {
+            return Ok(EIP1271VerifyResult::NotContract);
+        }
+        let value = view_call(
+            self.erc1271(account)
+                .is_valid_signature(hash.to_fixed_bytes(), signature.0.clone().into()),
+        )
+        .await?;
+        if value == EIP1271_MAGIC_VALUE {
+            Ok(EIP1271VerifyResult::Valid)
+        } else {
+            Ok(EIP1271VerifyResult::Invalid(value.to_vec()))
+        }
+    }
+
+    /// Verify the `EIP1271Signature` of the message signed by `eth_sign`(the message is hashed with
+    /// the "\x19Ethereum Signed Message:\n" prefix).
+    pub async fn verify_eip1271_signature(
+        &self,
+        account: ZkLinkAddress,
+        message: &[u8],
+        signature: &EIP1271Signature,
+    ) -> Result<EIP1271VerifyResult, WalletError> {
+        // only the layer1 contract accounts(20 bytes) could verify the signature
+        if account.as_bytes().len() != Address::len_bytes() {
+            return Err(WalletError::InvalidInputParameter);
+        }
+        let account = Address::from_slice(account.as_bytes());
+        let hash = hash_message(message);
+        self.inner_verify_eip1271_signature(account, hash, signature)
+            .await
+    }
+
+    pub async fn verify_transfer_eip1271_signature(
+        &self,
+        account: ZkLinkAddress,
+        tx: &Transfer,
+        token_symbol: &str,
+        signature: &EIP1271Signature,
+    ) -> Result<EIP1271VerifyResult, WalletError> {
+        let message = tx.get_eth_sign_msg(token_symbol);
+        self.verify_eip1271_signature(account, message.as_bytes(), signature)
+            .await
+    }
+
+    pub async fn verify_withdraw_eip1271_signature(
+        &self,
+        account: ZkLinkAddress,
+        tx: &Withdraw,
+        l2_source_token_symbol: &str,
+        signature: &EIP1271Signature,
+    ) -> Result<EIP1271VerifyResult, WalletError> {
+        let message = tx.get_eth_sign_msg(l2_source_token_symbol);
+        self.verify_eip1271_signature(account, message.as_bytes(), signature)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::providers::{MockProvider, Provider};
+    use ethers::types::Bytes;
+    use std::str::FromStr;
+    use zklink_sdk_types::basic_types::BigUint;
+    use zklink_sdk_types::prelude::*;
+
+    const ACCOUNT: &str = "0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9";
+
+    fn signature() -> EIP1271Signature {
+        EIP1271Signature(vec![0x11; 65])
+    }
+
+    // the responses of the mock provider are popped in the reverse order they are pushed
+    fn push_responses(mock: &MockProvider, code: &[u8], output: Option<[u8; 4]>) {
+        if let Some(output) = output {
+            let mut bytes = [0; 32];
+            bytes[..4].copy_from_slice(&output);
+            mock.push(Bytes::from(bytes.to_vec())).unwrap();
+        }
+        mock.push(Bytes::from(code.to_vec())).unwrap();
+    }
+
+    fn wallet() -> (Wallet<Provider<MockProvider>>, MockProvider) {
+        Wallet::mocked()
+    }
+
+    #[tokio::test]
+    async fn test_verify_eip1271_signature() {
+        let account = ZkLinkAddress::from_str(ACCOUNT).unwrap();
+        let (wallet, mock) = wallet();
+
+        push_responses(&mock, &[0x60, 0x80], Some(EIP1271_MAGIC_VALUE));
+        let result = wallet
+            .verify_eip1271_signature(account.clone(), b"hello", &signature())
+            .await
+            .unwrap();
+        assert_eq!(result, EIP1271VerifyResult::Valid);
+
+        push_responses(&mock, &[0x60, 0x80], Some([0xff, 0xff, 0xff, 0xff]));
+        let result = wallet
+            .verify_eip1271_signature(account.clone(), b"hello", &signature())
+            .await
+            .unwrap();
+        assert_eq!(result, EIP1271VerifyResult::Invalid(vec![0xff; 4]));
+        assert!(!result.is_valid());
+
+        // the eoa account has no code, the contract is not called
+        push_responses(&mock, &[], None);
+        let result = wallet
+            .verify_eip1271_signature(account, b"hello", &signature())
+            .await
+            .unwrap();
+        assert_eq!(result, EIP1271VerifyResult::NotContract);
+    }
+
+    #[tokio::test]
+    async fn test_verify_eip1271_signature_request() {
+        let account = Address::from_str(ACCOUNT).unwrap();
+        let (wallet, mock) = wallet();
+        push_responses(&mock, &[0x60, 0x80], Some(EIP1271_MAGIC_VALUE));
+        let hash = hash_message(b"hello");
+        wallet
+            .inner_verify_eip1271_signature(account, hash, &signature())
+            .await
+            .unwrap();
+        mock.assert_request("eth_getCode", (account, "latest"))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_eip1271_signature_of_invalid_account() {
+        let (wallet, _mock) = wallet();
+        let account = ZkLinkAddress::from([0x11; 32]);
+        let result = wallet
+            .verify_eip1271_signature(account, b"hello", &signature())
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+    }
+
+    #[tokio::test]
+    async fn test_verify_tx_eip1271_signature() {
+        let account = ZkLinkAddress::from_str(ACCOUNT).unwrap();
+        let (wallet, mock) = wallet();
+        let ts = 1693472232u32;
+        let transfer = TransferBuilder {
+            account_id: AccountId(1),
+            to_address: account.clone(),
+            from_sub_account_id: SubAccountId(1),
+            to_sub_account_id: SubAccountId(1),
+            token: TokenId(18),
+            amount: BigUint::from(100000u32),
+            fee: BigUint::from(100u32),
+            nonce: Nonce(1),
+            timestamp: ts.into(),
+        }
+        .build();
+        push_responses(&mock, &[0x60, 0x80], Some(EIP1271_MAGIC_VALUE));
+        let result = wallet
+            .verify_transfer_eip1271_signature(account.clone(), &transfer, "USDC", &signature())
+            .await
+            .unwrap();
+        assert!(result.is_valid());
+
+        let withdraw = WithdrawBuilder {
+            account_id: AccountId(10),
+            sub_account_id: SubAccountId(1),
+            to_chain_id: ChainId(1),
+            to_address: account.clone(),
+            l2_source_token: TokenId(18),
+            l1_target_token: TokenId(18),
+            amount: BigUint::from(10000u32),
+            data_hash: None,
+            fee: BigUint::from(3u32),
+            nonce: Nonce(1),
+            withdraw_to_l1: false,
+            withdraw_fee_ratio: 0,
+            timestamp: ts.into(),
+        }
+        .build();
+        push_responses(&mock, &[0x60, 0x80], Some([0; 4]));
+        let result = wallet
+            .verify_withdraw_eip1271_signature(account, &withdraw, "USDC", &signature())
+            .await
+            .unwrap();
+        assert_eq!(result, EIP1271VerifyResult::Invalid(vec![0; 4]));
+    }
+}
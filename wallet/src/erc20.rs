@@ -0,0 +1,422 @@
+use crate::error::WalletError;
+use crate::eth::{EthTxOption, EthTxParam};
+use crate::signer::Layer1Signer;
+use crate::utils::{biguint_to_u256, u256_to_biguint};
+use crate::wallet::{calldata, view_call, WaitForTxStatus, Wallet};
+use ethers::abi::Address;
+use ethers::providers::Middleware;
+use ethers::types::U256;
+use ethers::utils::parse_units;
+use serde::{Deserialize, Serialize};
+use zklink_sdk_signers::eth_signer::eip712::eip712::RawTypedData;
+use zklink_sdk_types::basic_types::{BigUint, ZkLinkAddress};
+use zklink_sdk_types::prelude::H256;
+
+/// The signed EIP-2612 permit, anyone can submit it to the token contract to approve the spender.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Erc20Permit {
+    pub owner: Address,
+    pub spender: Address,
+    pub value: U256,
+    pub deadline: U256,
+    pub v: u8,
+    pub r: H256,
+    pub s: H256,
+}
+
+/// Convert the amount in token units(e.g. "1.5") to the amount in the smallest units.
+pub fn parse_token_units(amount: &str, decimals: u8) -> Result<BigUint, WalletError> {
+    let amount: U256 = parse_units(amount, decimals as u32)
+        .map_err(|_| WalletError::InvalidInputParameter)?
+        .into();
+    Ok(u256_to_biguint(amount))
+}
+
+/// Build the EIP-2612 `Permit` typed data of the token.
+#[allow(clippy::too_many_arguments)]
+pub fn permit_typed_data(
+    token_name: &str,
+    version: &str,
+    chain_id: u64,
+    token: Address,
+    owner: Address,
+    spender: Address,
+    value: U256,
+    nonce: U256,
+    deadline: U256,
+) -> Result<RawTypedData, WalletError> {
+    let typed_data = serde_json::json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Permit": [
+                { "name": "owner", "type": "address" },
+                { "name": "spender", "type": "address" },
+                { "name": "value", "type": "uint256" },
+                { "name": "nonce", "type": "uint256" },
+                { "name": "deadline", "type": "uint256" }
+            ]
+        },
+        "primaryType": "Permit",
+        "domain": {
+            "name": token_name,
+            "version": version,
+            "chainId": chain_id,
+            "verifyingContract": format!("{token:?}")
+        },
+        "message": {
+            "owner": format!("{owner:?}"),
+            "spender": format!("{spender:?}"),
+            "value": format!("{value:#x}"),
+            "nonce": format!("{nonce:#x}"),
+            "deadline": format!("{deadline:#x}")
+        }
+    });
+    Ok(RawTypedData::from_json(&typed_data.to_string())?)
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    pub async fn inner_balance_of(
+        &self,
+        token: Address,
+        owner: Address,
+    ) -> Result<BigUint, WalletError> {
+        let balance = view_call(self.erc20(token).balance_of(owner)).await?;
+        Ok(u256_to_biguint(balance))
+    }
+
+    pub async fn inner_allowance(
+        &self,
+        token: Address,
+        owner: Address,
+        spender: Address,
+    ) -> Result<BigUint, WalletError> {
+        let allowance = view_call(self.erc20(token).allowance(owner, spender)).await?;
+        Ok(u256_to_biguint(allowance))
+    }
+
+    pub async fn inner_decimals(&self, token: Address) -> Result<u8, WalletError> {
+        view_call(self.erc20(token).decimals()).await
+    }
+
+    /// Sign the EIP-2612 permit which approves `spender` to spend `value` of the wallet token
+    /// before `deadline`, the `version` is the EIP-712 domain version of the token, e.g. "2" of USDC.
+    pub async fn inner_sign_erc20_permit(
+        &self,
+        token: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        version: &str,
+    ) -> Result<Erc20Permit, WalletError> {
+        let owner = self.signer.address();
+        let token_name = view_call(self.erc20(token).name()).await?;
+        let nonce = view_call(self.erc20(token).nonces(owner)).await?;
+        let chain_id = self
+            .provider
+            .get_chainid()
+            .await
+            .map_err(WalletError::from_middleware)?;
+        let typed_data = permit_typed_data(
+            &token_name,
+            version,
+            chain_id.as_u64(),
+            token,
+            owner,
+            spender,
+            value,
+            nonce,
+            deadline,
+        )?;
+        let signature = self.signer.sign_typed_data(&typed_data).await?;
+        let signature = signature.normalized()?.serialize_packed();
+        Ok(Erc20Permit {
+            owner,
+            spender,
+            value,
+            deadline,
+            v: signature[64],
+            r: H256::from_slice(&signature[..32]),
+            s: H256::from_slice(&signature[32..64]),
+        })
+    }
+
+    pub async fn inner_permit_erc20(
+        &self,
+        token: Address,
+        permit: &Erc20Permit,
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        let tx_data = calldata(self.erc20(token).permit(
+            permit.owner,
+            permit.spender,
+            permit.value,
+            permit.deadline,
+            permit.v,
+            permit.r.to_fixed_bytes(),
+            permit.s.to_fixed_bytes(),
+        ))?;
+        let tx_params = EthTxParam {
+            to: token,
+            data: Some(tx_data),
+            ..eth_params
+        };
+        self.sign_and_send_raw_tx(tx_params).await
+    }
+
+    /// Deposit the ERC20 token to the contract `eth_params.to`, approve the contract and wait for
+    /// the approval mined first if the allowance is not enough.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn inner_deposit_erc20_with_approve(
+        &self,
+        sub_account_id: u8,
+        deposit_to: Address,
+        token_addr: Address,
+        amount: BigUint,
+        mapping: bool,
+        is_gateway: bool,
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        let owner = self.signer.address();
+        let allowance = self
+            .inner_allowance(token_addr, owner, eth_params.to)
+            .await?;
+        if allowance < amount {
+            let approve_params = EthTxParam {
+                to: token_addr,
+                nonce: None,
+                value: None,
+                gas: None,
+                ..eth_params.clone()
+            };
+            let tx_hash = self
+                .inner_approve_erc20(eth_params.to, amount.clone(), approve_params)
+                .await?;
+            match self.wait_for_transaction(tx_hash, None).await? {
+                WaitForTxStatus::Success => {}
+                WaitForTxStatus::Failed => {
+                    return Err(WalletError::GetErrorResult(format!(
+                        "approve {tx_hash:?} failed"
+                    )))
+                }
+                WaitForTxStatus::Pending => {
+                    return Err(WalletError::Timeout(format!(
+                        "approve {tx_hash:?} is not mined"
+                    )))
+                }
+            }
+        }
+        self.inner_deposit_erc20(
+            sub_account_id,
+            deposit_to,
+            token_addr,
+            amount,
+            mapping,
+            is_gateway,
+            eth_params,
+        )
+        .await
+    }
+
+    pub async fn balance_of(
+        &self,
+        token: ZkLinkAddress,
+        owner: ZkLinkAddress,
+    ) -> Result<BigUint, WalletError> {
+        let token = Address::from_slice(token.as_bytes());
+        let owner = Address::from_slice(owner.as_bytes());
+        self.inner_balance_of(token, owner).await
+    }
+
+    pub async fn allowance(
+        &self,
+        token: ZkLinkAddress,
+        owner: ZkLinkAddress,
+        spender: ZkLinkAddress,
+    ) -> Result<BigUint, WalletError> {
+        let token = Address::from_slice(token.as_bytes());
+        let owner = Address::from_slice(owner.as_bytes());
+        let spender = Address::from_slice(spender.as_bytes());
+        self.inner_allowance(token, owner, spender).await
+    }
+
+    pub async fn decimals(&self, token: ZkLinkAddress) -> Result<u8, WalletError> {
+        let token = Address::from_slice(token.as_bytes());
+        self.inner_decimals(token).await
+    }
+
+    /// Convert the amount in token units(e.g. "1.5") to the smallest units with the token decimals.
+    pub async fn to_token_units(
+        &self,
+        token: ZkLinkAddress,
+        amount: &str,
+    ) -> Result<BigUint, WalletError> {
+        let decimals = self.decimals(token).await?;
+        parse_token_units(amount, decimals)
+    }
+
+    pub async fn sign_erc20_permit(
+        &self,
+        token: ZkLinkAddress,
+        spender: ZkLinkAddress,
+        amount: BigUint,
+        deadline: u64,
+        version: &str,
+    ) -> Result<Erc20Permit, WalletError> {
+        let token = Address::from_slice(token.as_bytes());
+        let spender = Address::from_slice(spender.as_bytes());
+        let value = biguint_to_u256(&amount)?;
+        self.inner_sign_erc20_permit(token, spender, value, U256::from(deadline), version)
+            .await
+    }
+
+    pub async fn permit_erc20(
+        &self,
+        token: ZkLinkAddress,
+        permit: Erc20Permit,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let token = Address::from_slice(token.as_bytes());
+        self.inner_permit_erc20(token, &permit, eth_params.try_into()?)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn deposit_erc20_with_approve(
+        &self,
+        sub_account_id: u8,
+        deposit_to: ZkLinkAddress,
+        token_addr: ZkLinkAddress,
+        amount: BigUint,
+        mapping: bool,
+        is_gateway: bool,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let deposit_to = Address::from_slice(deposit_to.as_bytes());
+        let token_addr = Address::from_slice(token_addr.as_bytes());
+        self.inner_deposit_erc20_with_approve(
+            sub_account_id,
+            deposit_to,
+            token_addr,
+            amount,
+            mapping,
+            is_gateway,
+            eth_params.try_into()?,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::Token;
+    use ethers::providers::Provider;
+    use ethers::types::Bytes;
+    use zklink_sdk_signers::eth_signer::eip712::fixtures::{
+        PERMIT_DIGEST, PERMIT_OWNER_PRIVATE_KEY, PERMIT_SIGNATURE, PERMIT_TYPED_DATA,
+    };
+    use zklink_sdk_signers::eth_signer::{EthSigner, PackedEthSignature};
+
+    #[test]
+    fn test_parse_token_units() {
+        assert_eq!(
+            parse_token_units("1.5", 6).unwrap(),
+            BigUint::from(1_500_000u32)
+        );
+        assert_eq!(
+            parse_token_units("2", 18).unwrap(),
+            BigUint::from(2_000_000_000_000_000_000u64)
+        );
+        assert!(parse_token_units("abc", 6).is_err());
+    }
+
+    fn usdc() -> Address {
+        "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap()
+    }
+
+    fn spender() -> Address {
+        "0x5505a8cD4594Dbf79d8C59C0Df1414AB871CA896"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_permit_typed_data() {
+        let signer = EthSigner::try_from(PERMIT_OWNER_PRIVATE_KEY).unwrap();
+        let typed_data = permit_typed_data(
+            "USD Coin",
+            "2",
+            1,
+            usdc(),
+            signer.get_address(),
+            spender(),
+            U256::exp10(18),
+            U256::zero(),
+            U256::from(0x6553f100u64),
+        )
+        .unwrap();
+        let expected = RawTypedData::from_json(PERMIT_TYPED_DATA).unwrap();
+        assert_eq!(typed_data.encode().unwrap(), expected.encode().unwrap());
+        assert_eq!(
+            ethers::utils::hex::encode(typed_data.sign_hash().unwrap()),
+            PERMIT_DIGEST
+        );
+
+        let signature = signer.sign_typed_data(&typed_data).unwrap();
+        assert_eq!(signature.as_hex(), PERMIT_SIGNATURE);
+        assert_eq!(
+            signature.typed_data_recover_signer(&typed_data).unwrap(),
+            signer.get_address()
+        );
+        let packed = signature.normalized().unwrap().serialize_packed();
+        assert_eq!(
+            PackedEthSignature::deserialize_packed(&packed)
+                .unwrap()
+                .as_hex(),
+            PERMIT_SIGNATURE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_erc20_permit() {
+        let (provider, mock) = Provider::mocked();
+        let signer = EthSigner::try_from(PERMIT_OWNER_PRIVATE_KEY).unwrap();
+        let wallet = Wallet::new_with(provider, signer).unwrap();
+        // the responses are popped in the reverse order: name, nonces and chain id
+        mock.push(U256::from(1)).unwrap();
+        mock.push(Bytes::from(ethers::abi::encode(&[Token::Uint(
+            U256::zero(),
+        )])))
+        .unwrap();
+        mock.push(Bytes::from(ethers::abi::encode(&[Token::String(
+            "USD Coin".into(),
+        )])))
+        .unwrap();
+
+        let permit = wallet
+            .inner_sign_erc20_permit(
+                usdc(),
+                spender(),
+                U256::exp10(18),
+                U256::from(0x6553f100u64),
+                "2",
+            )
+            .await
+            .unwrap();
+        let signature = PackedEthSignature::from_hex(PERMIT_SIGNATURE)
+            .unwrap()
+            .serialize_packed();
+        assert_eq!(permit.owner, wallet.signer.get_address());
+        assert_eq!(permit.r.as_bytes(), &signature[..32]);
+        assert_eq!(permit.s.as_bytes(), &signature[32..64]);
+        assert_eq!(permit.v, 28);
+    }
+}
@@ -1,9 +1,10 @@
 use ethers::abi::AbiError;
 use ethers::abi::Error as EthAbiError;
-use ethers::providers::ProviderError;
+use ethers::providers::{MiddlewareError, ProviderError};
 use thiserror::Error;
 use wasm_bindgen::JsValue;
 use zklink_sdk_signers::eth_signer::EthSignerError;
+use zklink_sdk_types::basic_types::ChainId;
 
 #[derive(Debug, Error)]
 pub enum WalletError {
@@ -15,16 +16,34 @@ pub enum WalletError {
     ParseJsonError(String),
     #[error("Get error result: {0}")]
     GetErrorResult(String),
+    #[error("Zklink rpc error: {0}")]
+    ZkLinkRpcError(String),
+    #[error("Timeout: {0}")]
+    Timeout(String),
+    #[error("Unsupported chain: {0}")]
+    UnsupportedChain(ChainId),
+    #[error("Layer1 chain id mismatch, expected: {expected}, actual: {actual}")]
+    Layer1ChainIdMismatch { expected: u64, actual: u64 },
     #[error("Abi error: {0}")]
     AbiError(#[from] AbiError),
     #[error("Layer1 provider error: {0}")]
     ProviderError(#[from] ProviderError),
+    #[error("Layer1 middleware error: {0}")]
+    MiddlewareError(String),
+    #[error("Layer1 signer error: {0}")]
+    SignerError(String),
     #[error("signer error: {0}")]
     EthSignerError(#[from] EthSignerError),
     #[error("Eth abi error: {0}")]
     EthAbiError(#[from] EthAbiError),
 }
 
+impl WalletError {
+    pub fn from_middleware<E: MiddlewareError>(error: E) -> Self {
+        WalletError::MiddlewareError(error.to_string())
+    }
+}
+
 impl From<WalletError> for JsValue {
     fn from(error: WalletError) -> Self {
         JsValue::from_str(&format!("error: {error}"))
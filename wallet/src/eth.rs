@@ -1,5 +1,5 @@
 use crate::error::WalletError;
-use ethers::abi::{Contract, Token};
+use crate::utils::{biguint_to_u256, layer1_address};
 use ethers::core::types::transaction::eip2718::TypedTransaction;
 use ethers::types::{Address, Eip1559TransactionRequest, TransactionRequest, U256};
 use serde::{Deserialize, Serialize};
@@ -14,6 +14,11 @@ pub struct EthTxOption {
     pub value: Option<BigUint>,
     pub gas: Option<u64>,
     pub gas_price: Option<BigUint>,
+    /// The `max_fee_per_gas` of the EIP-1559 transaction, estimated by the fee history if not set.
+    #[serde(default)]
+    pub max_fee_per_gas: Option<BigUint>,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: Option<BigUint>,
 }
 
 #[derive(Clone, Default)]
@@ -25,23 +30,41 @@ pub struct EthTxParam {
     pub(crate) value: Option<U256>,
     pub(crate) gas: Option<U256>,
     pub(crate) gas_price: Option<U256>,
+    pub(crate) max_fee_per_gas: Option<U256>,
+    pub(crate) max_priority_fee_per_gas: Option<U256>,
 }
 
-impl From<EthTxOption> for EthTxParam {
-    fn from(eth_tx_option: EthTxOption) -> EthTxParam {
-        EthTxParam {
+impl TryFrom<EthTxOption> for EthTxParam {
+    type Error = WalletError;
+
+    fn try_from(eth_tx_option: EthTxOption) -> Result<EthTxParam, WalletError> {
+        Ok(EthTxParam {
             is_support_eip1559: eth_tx_option.is_support_eip1559,
-            to: Address::from_slice(eth_tx_option.to.as_bytes()),
+            to: layer1_address(&eth_tx_option.to)?,
             data: None,
             nonce: eth_tx_option.nonce.map(U256::from),
             value: eth_tx_option
                 .value
-                .map(|v| U256::from_str_radix(&v.to_string(), 10).unwrap_or_default()),
+                .as_ref()
+                .map(biguint_to_u256)
+                .transpose()?,
             gas: eth_tx_option.gas.map(U256::from),
             gas_price: eth_tx_option
                 .gas_price
-                .map(|g| U256::from_str_radix(&g.to_string(), 10).unwrap_or_default()),
-        }
+                .as_ref()
+                .map(biguint_to_u256)
+                .transpose()?,
+            max_fee_per_gas: eth_tx_option
+                .max_fee_per_gas
+                .as_ref()
+                .map(biguint_to_u256)
+                .transpose()?,
+            max_priority_fee_per_gas: eth_tx_option
+                .max_priority_fee_per_gas
+                .as_ref()
+                .map(biguint_to_u256)
+                .transpose()?,
+        })
     }
 }
 
@@ -67,7 +90,9 @@ pub fn new_typed_tx(from: Address, tx_params: EthTxParam, chain_id: u64) -> Type
     };
     tx.set_from(from);
     tx.set_to(tx_params.to);
-    tx.set_nonce(tx_params.nonce.unwrap());
+    if let Some(nonce) = tx_params.nonce {
+        tx.set_nonce(nonce);
+    }
     if let Some(data) = tx_params.data {
         tx.set_data(data.into());
     }
@@ -80,16 +105,61 @@ pub fn new_typed_tx(from: Address, tx_params: EthTxParam, chain_id: u64) -> Type
     if let Some(gas_price) = tx_params.gas_price {
         tx.set_gas_price(gas_price);
     }
+    if let TypedTransaction::Eip1559(tx) = &mut tx {
+        if let Some(max_fee_per_gas) = tx_params.max_fee_per_gas {
+            tx.max_fee_per_gas = Some(max_fee_per_gas);
+        }
+        if let Some(max_priority_fee_per_gas) = tx_params.max_priority_fee_per_gas {
+            tx.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        }
+    }
     tx.set_chain_id(chain_id);
     tx
 }
 
-pub fn encode_tx(
-    contract: Contract,
-    method: &str,
-    params: Vec<Token>,
-) -> Result<Vec<u8>, WalletError> {
-    let function = contract.function(method)?;
-    let tx_data = function.encode_input(&params)?;
-    Ok(tx_data)
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_eth_tx_param_from_option() {
+        let to = ZkLinkAddress::from_str("0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9").unwrap();
+        let option = EthTxOption {
+            is_support_eip1559: true,
+            to: to.clone(),
+            nonce: Some(1),
+            value: Some(BigUint::from(100u32)),
+            gas: Some(21000),
+            gas_price: None,
+            max_fee_per_gas: Some(BigUint::from(10u32).pow(10)),
+            max_priority_fee_per_gas: Some(BigUint::from(10u32).pow(9)),
+        };
+        let params = EthTxParam::try_from(option.clone()).unwrap();
+        assert_eq!(params.to, Address::from_slice(to.as_bytes()));
+        assert_eq!(params.nonce, Some(U256::from(1)));
+        assert_eq!(params.value, Some(U256::from(100)));
+        assert_eq!(params.gas, Some(U256::from(21000)));
+        assert_eq!(params.gas_price, None);
+        assert_eq!(params.max_fee_per_gas, Some(U256::exp10(10)));
+        assert_eq!(params.max_priority_fee_per_gas, Some(U256::exp10(9)));
+
+        // the fee overflows the uint256
+        let invalid_option = EthTxOption {
+            max_fee_per_gas: Some(BigUint::from(2u32).pow(256)),
+            ..option.clone()
+        };
+        assert!(matches!(
+            EthTxParam::try_from(invalid_option),
+            Err(WalletError::InvalidInputParameter)
+        ));
+        let invalid_option = EthTxOption {
+            to: ZkLinkAddress::from([0x11; 32]),
+            ..option
+        };
+        assert!(matches!(
+            EthTxParam::try_from(invalid_option),
+            Err(WalletError::InvalidInputParameter)
+        ));
+    }
 }
@@ -0,0 +1,331 @@
+use crate::bindings::zklink;
+use crate::error::WalletError;
+use crate::eth::{EthTxOption, EthTxParam};
+use crate::signer::Layer1Signer;
+use crate::utils::biguint_to_u128;
+use crate::wallet::{calldata, view_call, Wallet};
+use ethers::providers::Middleware;
+use ethers::types::{Bytes, U256};
+use serde::{Deserialize, Serialize};
+use zklink_sdk_provider::response::AccountSnapshotResp;
+use zklink_sdk_types::basic_types::{BigUint, ChainId, SubAccountId, TokenId};
+use zklink_sdk_types::prelude::{BigIntSerdeWrapper, H256};
+use zklink_sdk_types::tx_type::exit_info::ExitInfo;
+
+/// The `StoredBlockInfo` of the last executed block, which is the state root of the exodus proof.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredBlockInfo {
+    pub block_number: u32,
+    pub priority_operations: u64,
+    pub pending_onchain_operations_hash: H256,
+    pub timestamp: U256,
+    pub state_hash: H256,
+    pub commitment: H256,
+    pub sync_hash: H256,
+}
+
+impl StoredBlockInfo {
+    pub(crate) fn to_contract(&self) -> zklink::StoredBlockInfo {
+        zklink::StoredBlockInfo {
+            block_number: self.block_number,
+            priority_operations: self.priority_operations,
+            pending_onchain_operations_hash: self.pending_onchain_operations_hash.to_fixed_bytes(),
+            timestamp: self.timestamp,
+            state_hash: self.state_hash.to_fixed_bytes(),
+            commitment: self.commitment.to_fixed_bytes(),
+            sync_hash: self.sync_hash.to_fixed_bytes(),
+        }
+    }
+}
+
+/// Build the `ExitInfo` of every token with positive balance in the account snapshot, the layer1
+/// target token is the same as the layer2 source token.
+pub fn exit_infos_from_snapshot(
+    snapshot: &AccountSnapshotResp,
+    chain_id: ChainId,
+) -> Vec<ExitInfo> {
+    let mut exit_infos = Vec::new();
+    for (sub_account_id, balances) in &snapshot.balances {
+        for (token_id, balance) in balances {
+            if *balance <= BigIntSerdeWrapper::default() {
+                continue;
+            }
+            exit_infos.push(ExitInfo {
+                chain_id,
+                withdrawal_account_id: snapshot.id,
+                received_address: snapshot.address.clone(),
+                sub_account_id: *sub_account_id,
+                l2_source_token: *token_id,
+                l1_target_token: *token_id,
+                signature: Default::default(),
+            });
+        }
+    }
+    exit_infos.sort_by_key(|e| (*e.sub_account_id, *e.l2_source_token));
+    exit_infos
+}
+
+/// The token id of the zklink contract is uint16.
+fn contract_token_id(token_id: TokenId) -> Result<u16, WalletError> {
+    u16::try_from(*token_id).map_err(|_| WalletError::InvalidInputParameter)
+}
+
+/// The exodus proof is a sequence of uint256 in big-endian.
+fn proof_words(proof: &[u8]) -> Result<Vec<U256>, WalletError> {
+    if proof.len() % 32 != 0 {
+        return Err(WalletError::InvalidInputParameter);
+    }
+    Ok(proof.chunks(32).map(U256::from_big_endian).collect())
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    pub async fn inner_is_exodus_mode(&self, eth_params: EthTxParam) -> Result<bool, WalletError> {
+        view_call(self.zklink(eth_params.to).exodus_mode()).await
+    }
+
+    pub async fn inner_activate_exodus_mode(
+        &self,
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        let tx_data = calldata(self.zklink(eth_params.to).activate_exodus_mode())?;
+        let tx_params = EthTxParam {
+            data: Some(tx_data),
+            ..eth_params
+        };
+        self.sign_and_send_raw_tx(tx_params).await
+    }
+
+    pub async fn inner_perform_exodus(
+        &self,
+        stored_block_info: &StoredBlockInfo,
+        exit_info: &ExitInfo,
+        amount: BigUint,
+        proof: &[u8],
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        // the amount of the exodus is uint128
+        let amount = biguint_to_u128(&amount)?;
+        let tx_data = calldata(self.zklink(eth_params.to).perform_exodus(
+            stored_block_info.to_contract(),
+            exit_info.received_address.to_fixed_bytes(),
+            *exit_info.withdrawal_account_id,
+            *exit_info.sub_account_id,
+            contract_token_id(exit_info.l1_target_token)?,
+            contract_token_id(exit_info.l2_source_token)?,
+            amount,
+            proof_words(proof)?,
+        ))?;
+        let tx_params = EthTxParam {
+            data: Some(tx_data),
+            ..eth_params
+        };
+        self.sign_and_send_raw_tx(tx_params).await
+    }
+
+    pub async fn inner_cancel_outstanding_deposits(
+        &self,
+        n: u64,
+        deposits_pubdata: Vec<Vec<u8>>,
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        let deposits_pubdata = deposits_pubdata.into_iter().map(Bytes::from).collect();
+        let tx_data = calldata(
+            self.zklink(eth_params.to)
+                .cancel_outstanding_deposits_for_exodus_mode(n, deposits_pubdata),
+        )?;
+        let tx_params = EthTxParam {
+            data: Some(tx_data),
+            ..eth_params
+        };
+        self.sign_and_send_raw_tx(tx_params).await
+    }
+
+    pub async fn inner_performed_exodus(
+        &self,
+        exit_info: &ExitInfo,
+        eth_params: EthTxParam,
+    ) -> Result<bool, WalletError> {
+        view_call(self.zklink(eth_params.to).performed_exodus(
+            *exit_info.withdrawal_account_id,
+            *exit_info.sub_account_id,
+            contract_token_id(exit_info.l1_target_token)?,
+            contract_token_id(exit_info.l2_source_token)?,
+        ))
+        .await
+    }
+
+    pub async fn is_exodus_mode(&self, eth_params: EthTxOption) -> Result<bool, WalletError> {
+        self.inner_is_exodus_mode(eth_params.try_into()?).await
+    }
+
+    pub async fn activate_exodus_mode(&self, eth_params: EthTxOption) -> Result<H256, WalletError> {
+        self.inner_activate_exodus_mode(eth_params.try_into()?)
+            .await
+    }
+
+    /// Submit `performExodus` with the proof of the `amount` of the exit generated off-chain.
+    pub async fn perform_exodus(
+        &self,
+        stored_block_info: StoredBlockInfo,
+        exit_info: ExitInfo,
+        amount: BigUint,
+        proof: Vec<u8>,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        self.inner_perform_exodus(
+            &stored_block_info,
+            &exit_info,
+            amount,
+            &proof,
+            eth_params.try_into()?,
+        )
+        .await
+    }
+
+    /// Cancel the first `n` outstanding deposits, the `deposits_pubdata` are the pubdata of the
+    /// deposits in the priority queue.
+    pub async fn cancel_outstanding_deposits(
+        &self,
+        n: u64,
+        deposits_pubdata: Vec<Vec<u8>>,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        self.inner_cancel_outstanding_deposits(n, deposits_pubdata, eth_params.try_into()?)
+            .await
+    }
+
+    /// Query which of the exits have been performed.
+    pub async fn performed_exits(
+        &self,
+        exit_infos: Vec<ExitInfo>,
+        eth_params: EthTxOption,
+    ) -> Result<Vec<(ExitInfo, bool)>, WalletError> {
+        let eth_params: EthTxParam = eth_params.try_into()?;
+        let mut performed = Vec::with_capacity(exit_infos.len());
+        for exit_info in exit_infos {
+            let is_performed = self
+                .inner_performed_exodus(&exit_info, eth_params.clone())
+                .await?;
+            performed.push((exit_info, is_performed));
+        }
+        Ok(performed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::Token;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use zklink_sdk_types::basic_types::{AccountId, BlockNumber, Nonce, ZkLinkAddress};
+    use zklink_sdk_types::prelude::PubKeyHash;
+
+    #[test]
+    fn test_exit_infos_from_snapshot() {
+        let balances = HashMap::from([
+            (
+                SubAccountId(1),
+                HashMap::from([
+                    (TokenId(18), BigIntSerdeWrapper::from(BigUint::from(100u32))),
+                    (TokenId(17), BigIntSerdeWrapper::from(BigUint::from(0u32))),
+                ]),
+            ),
+            (
+                SubAccountId(0),
+                HashMap::from([(TokenId(1), BigIntSerdeWrapper::from(BigUint::from(1u32)))]),
+            ),
+        ]);
+        let snapshot = AccountSnapshotResp {
+            id: AccountId(10),
+            address: ZkLinkAddress::from_str("0xAFAFf3aD1a0425D792432D9eCD1c3e26Ef2C42E9").unwrap(),
+            nonce: Nonce(0),
+            pub_key_hash: PubKeyHash::zero(),
+            sub_account_nonces: Default::default(),
+            balances,
+            order_slots: Default::default(),
+            block_number: BlockNumber(100),
+            positions: Default::default(),
+        };
+        let exit_infos = exit_infos_from_snapshot(&snapshot, ChainId(1));
+        assert_eq!(exit_infos.len(), 2);
+        assert_eq!(exit_infos[0].sub_account_id, SubAccountId(0));
+        assert_eq!(exit_infos[0].l2_source_token, TokenId(1));
+        assert_eq!(exit_infos[1].sub_account_id, SubAccountId(1));
+        assert_eq!(exit_infos[1].l1_target_token, TokenId(18));
+        assert_eq!(exit_infos[1].withdrawal_account_id, AccountId(10));
+    }
+
+    #[test]
+    fn test_proof_words() {
+        assert!(proof_words(&[0u8; 33]).is_err());
+        let mut proof = [1u8; 64];
+        proof[63] = 2;
+        let proof = proof_words(&proof).unwrap();
+        assert_eq!(proof.len(), 2);
+        assert_eq!(proof[0], U256::from_big_endian(&[1u8; 32]));
+        assert_eq!(proof[1].low_u32() & 0xff, 2);
+    }
+
+    #[test]
+    fn test_contract_token_id() {
+        assert_eq!(contract_token_id(TokenId(18)).unwrap(), 18);
+        assert!(contract_token_id(TokenId(u16::MAX as u32 + 1)).is_err());
+    }
+
+    #[test]
+    fn test_perform_exodus_calldata() {
+        let (wallet, _mock) = Wallet::mocked();
+        let stored_block_info = StoredBlockInfo {
+            block_number: 10,
+            priority_operations: 2,
+            pending_onchain_operations_hash: H256::repeat_byte(0x11),
+            timestamp: U256::from(1693472232u32),
+            state_hash: H256::repeat_byte(0x22),
+            commitment: H256::repeat_byte(0x33),
+            sync_hash: H256::repeat_byte(0x44),
+        };
+        let owner = [0x55; 32];
+        let proof = proof_words(&[0x66; 64]).unwrap();
+        let data = calldata(wallet.zklink(Default::default()).perform_exodus(
+            stored_block_info.to_contract(),
+            owner,
+            10,
+            1,
+            18,
+            17,
+            1000,
+            proof.clone(),
+        ))
+        .unwrap();
+
+        // the typed binding encodes the same calldata as the json abi
+        let tokens = vec![
+            Token::Tuple(vec![
+                Token::Uint(U256::from(10)),
+                Token::Uint(U256::from(2)),
+                Token::FixedBytes(vec![0x11; 32]),
+                Token::Uint(U256::from(1693472232u32)),
+                Token::FixedBytes(vec![0x22; 32]),
+                Token::FixedBytes(vec![0x33; 32]),
+                Token::FixedBytes(vec![0x44; 32]),
+            ]),
+            Token::FixedBytes(owner.to_vec()),
+            Token::Uint(U256::from(10)),
+            Token::Uint(U256::from(1)),
+            Token::Uint(U256::from(18)),
+            Token::Uint(U256::from(17)),
+            Token::Uint(U256::from(1000)),
+            Token::Array(proof.into_iter().map(Token::Uint).collect()),
+        ];
+        let expected = wallet
+            .get_l1_contract(false)
+            .unwrap()
+            .function("performExodus")
+            .unwrap()
+            .encode_input(&tokens)
+            .unwrap();
+        assert_eq!(data, expected);
+    }
+}
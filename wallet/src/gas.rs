@@ -0,0 +1,503 @@
+use crate::error::WalletError;
+use crate::eth::{new_typed_tx, EthTxParam};
+use crate::signer::Layer1Signer;
+use crate::wallet::Wallet;
+use ethers::providers::Middleware;
+use ethers::types::{BlockNumber, FeeHistory, U256};
+use std::sync::Mutex;
+use zklink_sdk_types::prelude::H256;
+
+/// The gas limit of the transaction which only transfers ETH.
+const TRANSFER_GAS_LIMIT: u64 = 21000;
+
+#[derive(Debug, Clone)]
+pub struct FeeConfig {
+    /// The number of latest blocks used for the fee history.
+    pub fee_history_blocks: u64,
+    /// The percentile of the priority fee paid in each block.
+    pub reward_percentile: f64,
+    /// The `max_fee_per_gas` is `base_fee * base_fee_multiplier / 100 + priority_fee`, which keeps
+    /// the transaction valid even the base fee increases in the next blocks.
+    pub base_fee_multiplier: u64,
+    /// The min `max_priority_fee_per_gas`.
+    pub min_priority_fee: U256,
+    /// The percent of the fee bump of the replacement transaction, most nodes require 10% at least.
+    pub replacement_bump_percent: u64,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            fee_history_blocks: 10,
+            reward_percentile: 50.0,
+            base_fee_multiplier: 200,
+            min_priority_fee: U256::zero(),
+            replacement_bump_percent: 15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eip1559Fees {
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+}
+
+/// Estimate the EIP-1559 fees with the base fee of the next block and the median of the priority
+/// fees paid in the history blocks.
+pub fn estimate_eip1559_fees(
+    history: &FeeHistory,
+    config: &FeeConfig,
+) -> Result<Eip1559Fees, WalletError> {
+    let next_base_fee = history
+        .base_fee_per_gas
+        .last()
+        .copied()
+        .ok_or_else(|| WalletError::GetErrorResult("empty fee history".into()))?;
+    let mut rewards: Vec<U256> = history
+        .reward
+        .iter()
+        .filter_map(|r| r.first().copied())
+        .filter(|r| !r.is_zero())
+        .collect();
+    rewards.sort();
+    let priority_fee = rewards
+        .get(rewards.len() / 2)
+        .copied()
+        .unwrap_or_default()
+        .max(config.min_priority_fee);
+    Ok(Eip1559Fees {
+        max_fee_per_gas: next_base_fee * config.base_fee_multiplier / 100 + priority_fee,
+        max_priority_fee_per_gas: priority_fee,
+    })
+}
+
+/// Bump the fee of the replaced transaction by `percent`, rounded up.
+pub fn bump_fee(fee: U256, percent: u64) -> U256 {
+    (fee * (100 + percent) + 99) / 100
+}
+
+/// The local nonce of the wallet address, so that the concurrent transactions get different nonces.
+#[derive(Debug, Default)]
+pub struct NonceTracker {
+    next_nonce: Mutex<Option<U256>>,
+}
+
+impl NonceTracker {
+    /// Returns the larger one of the tracked nonce and the `pending_nonce` of the node, and
+    /// increases the tracked nonce.
+    pub fn next(&self, pending_nonce: U256) -> U256 {
+        let mut next_nonce = self.next_nonce.lock().unwrap();
+        let nonce = next_nonce.map_or(pending_nonce, |n| n.max(pending_nonce));
+        *next_nonce = Some(nonce + 1);
+        nonce
+    }
+
+    /// Forget the tracked nonce, the next nonce is the `pending_nonce` of the node.
+    pub fn reset(&self) {
+        *self.next_nonce.lock().unwrap() = None;
+    }
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    /// Estimate the EIP-1559 fees with the `eth_feeHistory` of the latest blocks.
+    pub async fn estimate_eip1559_fees(&self) -> Result<Eip1559Fees, WalletError> {
+        let history = self
+            .provider
+            .fee_history(
+                self.fee_config.fee_history_blocks,
+                BlockNumber::Latest,
+                &[self.fee_config.reward_percentile],
+            )
+            .await
+            .map_err(WalletError::from_middleware)?;
+        estimate_eip1559_fees(&history, &self.fee_config)
+    }
+
+    /// The nonce of the next transaction sent by the wallet.
+    pub async fn next_nonce(&self) -> Result<U256, WalletError> {
+        let from = self.signer.address();
+        let pending_nonce = self
+            .provider
+            .get_transaction_count(from, Some(BlockNumber::Pending.into()))
+            .await
+            .map_err(WalletError::from_middleware)?;
+        Ok(self.nonce_tracker.next(pending_nonce))
+    }
+
+    /// Resync the nonce with the node, e.g. after the transactions are dropped.
+    pub fn reset_nonce(&self) {
+        self.nonce_tracker.reset()
+    }
+
+    /// Fill the fees of the transaction that are not set by the caller.
+    pub(crate) async fn fill_fees(&self, params: &mut EthTxParam) -> Result<(), WalletError> {
+        if params.is_support_eip1559 {
+            if params.gas_price.is_none() && params.max_fee_per_gas.is_none() {
+                let fees = self.estimate_eip1559_fees().await?;
+                params.max_fee_per_gas = Some(fees.max_fee_per_gas);
+                params.max_priority_fee_per_gas = Some(fees.max_priority_fee_per_gas);
+            }
+        } else if params.gas_price.is_none() {
+            params.gas_price = Some(
+                self.provider
+                    .get_gas_price()
+                    .await
+                    .map_err(WalletError::from_middleware)?,
+            );
+        }
+        Ok(())
+    }
+
+    async fn replace_tx(&self, tx_hash: H256, is_cancel: bool) -> Result<H256, WalletError> {
+        let from = self.signer.address();
+        let tx = self
+            .provider
+            .get_transaction(tx_hash)
+            .await
+            .map_err(WalletError::from_middleware)?
+            .ok_or_else(|| WalletError::GetErrorResult(format!("{tx_hash:?} is not found")))?;
+        if tx.block_number.is_some() {
+            return Err(WalletError::GetErrorResult(format!(
+                "{tx_hash:?} is already mined"
+            )));
+        }
+        if tx.from != from {
+            return Err(WalletError::GetErrorResult(format!(
+                "{tx_hash:?} is not sent by the wallet"
+            )));
+        }
+
+        let bump = self.fee_config.replacement_bump_percent;
+        let mut params = if is_cancel {
+            EthTxParam {
+                to: from,
+                gas: Some(TRANSFER_GAS_LIMIT.into()),
+                ..Default::default()
+            }
+        } else {
+            EthTxParam {
+                to: tx.to.unwrap_or_default(),
+                data: Some(tx.input.to_vec()),
+                value: Some(tx.value),
+                gas: Some(tx.gas),
+                ..Default::default()
+            }
+        };
+        params.nonce = Some(tx.nonce);
+        if tx.transaction_type.map(|t| t.as_u64()) == Some(2) {
+            let fees = self.estimate_eip1559_fees().await?;
+            let priority_fee = bump_fee(tx.max_priority_fee_per_gas.unwrap_or_default(), bump)
+                .max(fees.max_priority_fee_per_gas);
+            let max_fee = bump_fee(tx.max_fee_per_gas.unwrap_or_default(), bump)
+                .max(fees.max_fee_per_gas)
+                .max(priority_fee);
+            params.is_support_eip1559 = true;
+            params.max_fee_per_gas = Some(max_fee);
+            params.max_priority_fee_per_gas = Some(priority_fee);
+        } else {
+            let gas_price = self
+                .provider
+                .get_gas_price()
+                .await
+                .map_err(WalletError::from_middleware)?;
+            params.gas_price =
+                Some(bump_fee(tx.gas_price.unwrap_or_default(), bump).max(gas_price));
+        }
+
+        let chain_id = self
+            .provider
+            .get_chainid()
+            .await
+            .map_err(WalletError::from_middleware)?;
+        let typed_tx = new_typed_tx(from, params, chain_id.as_u64());
+        self.sign_and_send_typed_tx(typed_tx).await
+    }
+
+    /// Replace the pending transaction with the same one paying the bumped fee.
+    pub async fn speed_up(&self, tx_hash: H256) -> Result<H256, WalletError> {
+        self.replace_tx(tx_hash, false).await
+    }
+
+    /// Replace the pending transaction with a zero value transfer to the wallet itself paying the
+    /// bumped fee.
+    pub async fn cancel(&self, tx_hash: H256) -> Result<H256, WalletError> {
+        self.replace_tx(tx_hash, true).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::abi::Address;
+    use ethers::providers::{MockProvider, Provider};
+    use ethers::types::{Transaction, TransactionReceipt, U64};
+
+    #[test]
+    fn test_estimate_eip1559_fees() {
+        let gwei = U256::exp10(9);
+        let history = FeeHistory {
+            base_fee_per_gas: vec![gwei * 10, gwei * 12, gwei * 13, gwei * 12, gwei * 11],
+            gas_used_ratio: vec![0.9, 0.6, 0.3, 0.2],
+            oldest_block: U256::from(100),
+            reward: vec![
+                vec![gwei * 2],
+                vec![gwei],
+                vec![U256::zero()],
+                vec![gwei * 3],
+            ],
+        };
+        let fees = estimate_eip1559_fees(&history, &FeeConfig::default()).unwrap();
+        assert_eq!(fees.max_priority_fee_per_gas, gwei * 2);
+        assert_eq!(fees.max_fee_per_gas, gwei * 24);
+
+        let config = FeeConfig {
+            min_priority_fee: gwei * 5,
+            ..Default::default()
+        };
+        let fees = estimate_eip1559_fees(&history, &config).unwrap();
+        assert_eq!(fees.max_priority_fee_per_gas, gwei * 5);
+
+        let history = FeeHistory {
+            base_fee_per_gas: vec![],
+            gas_used_ratio: vec![],
+            oldest_block: U256::zero(),
+            reward: vec![],
+        };
+        assert!(estimate_eip1559_fees(&history, &config).is_err());
+    }
+
+    #[test]
+    fn test_bump_fee() {
+        assert_eq!(bump_fee(U256::from(100), 15), U256::from(115));
+        assert_eq!(bump_fee(U256::from(101), 10), U256::from(112));
+        assert_eq!(bump_fee(U256::zero(), 10), U256::zero());
+    }
+
+    #[test]
+    fn test_nonce_tracker() {
+        let tracker = NonceTracker::default();
+        assert_eq!(tracker.next(U256::from(5)), U256::from(5));
+        assert_eq!(tracker.next(U256::from(5)), U256::from(6));
+        assert_eq!(tracker.next(U256::from(10)), U256::from(10));
+        tracker.reset();
+        assert_eq!(tracker.next(U256::from(3)), U256::from(3));
+    }
+
+    const PENDING_TX_HASH: H256 = H256::repeat_byte(0x11);
+    const REPLACEMENT_TX_HASH: H256 = H256::repeat_byte(0x33);
+
+    /// The pending transaction sent by the mocked wallet with the nonce 7.
+    fn pending_tx(wallet: &Wallet<Provider<MockProvider>>, is_eip1559: bool) -> Transaction {
+        let gwei = U256::exp10(9);
+        let mut tx = Transaction {
+            hash: PENDING_TX_HASH,
+            nonce: U256::from(7),
+            from: wallet.signer.address(),
+            to: Some(Address::repeat_byte(0x22)),
+            value: U256::from(100),
+            gas: U256::from(50000),
+            input: vec![1, 2, 3].into(),
+            ..Default::default()
+        };
+        if is_eip1559 {
+            tx.transaction_type = Some(U64::from(2));
+            tx.max_fee_per_gas = Some(gwei * 30);
+            tx.max_priority_fee_per_gas = Some(gwei * 2);
+        } else {
+            tx.transaction_type = Some(U64::from(0));
+            tx.gas_price = Some(gwei * 20);
+        }
+        tx
+    }
+
+    /// Assert the replacement transaction sent by `eth_sendRawTransaction` is signed from the
+    /// `expected` params.
+    fn assert_sent_tx(
+        wallet: &Wallet<Provider<MockProvider>>,
+        mock: &MockProvider,
+        expected: EthTxParam,
+    ) {
+        mock.assert_request("eth_chainId", ()).unwrap();
+        let typed_tx = new_typed_tx(wallet.signer.address(), expected, 1);
+        let signature = wallet.signer.sign_transaction(&typed_tx).unwrap();
+        let raw_tx = typed_tx.rlp_signed(&signature.0);
+        mock.assert_request("eth_sendRawTransaction", [raw_tx])
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_speed_up() {
+        let gwei = U256::exp10(9);
+        let (wallet, mock) = Wallet::mocked();
+        let tx = pending_tx(&wallet, true);
+        // the fees estimated by the history are lower than the bumped fees of the pending tx
+        let history = FeeHistory {
+            base_fee_per_gas: vec![gwei * 10],
+            gas_used_ratio: vec![0.5],
+            oldest_block: U256::from(100),
+            reward: vec![vec![gwei]],
+        };
+        mock.push(REPLACEMENT_TX_HASH).unwrap();
+        mock.push(U256::from(1)).unwrap();
+        mock.push(history).unwrap();
+        mock.push(tx.clone()).unwrap();
+
+        let tx_hash = wallet.speed_up(PENDING_TX_HASH).await.unwrap();
+        assert_eq!(tx_hash, REPLACEMENT_TX_HASH);
+        mock.assert_request("eth_getTransactionByHash", [PENDING_TX_HASH])
+            .unwrap();
+        mock.assert_request(
+            "eth_feeHistory",
+            (U256::from(10), BlockNumber::Latest, [50.0]),
+        )
+        .unwrap();
+        // the same transaction with the same nonce paying 15% more fees
+        let expected = EthTxParam {
+            is_support_eip1559: true,
+            to: tx.to.unwrap(),
+            data: Some(tx.input.to_vec()),
+            nonce: Some(U256::from(7)),
+            value: Some(U256::from(100)),
+            gas: Some(U256::from(50000)),
+            gas_price: None,
+            max_fee_per_gas: Some(gwei * 345 / 10),
+            max_priority_fee_per_gas: Some(gwei * 23 / 10),
+        };
+        assert_sent_tx(&wallet, &mock, expected);
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let gwei = U256::exp10(9);
+        let (wallet, mock) = Wallet::mocked();
+        mock.push(REPLACEMENT_TX_HASH).unwrap();
+        mock.push(U256::from(1)).unwrap();
+        // the gas price of the node is higher than the bumped gas price of the pending tx
+        mock.push(gwei * 25).unwrap();
+        mock.push(pending_tx(&wallet, false)).unwrap();
+
+        let tx_hash = wallet.cancel(PENDING_TX_HASH).await.unwrap();
+        assert_eq!(tx_hash, REPLACEMENT_TX_HASH);
+        mock.assert_request("eth_getTransactionByHash", [PENDING_TX_HASH])
+            .unwrap();
+        mock.assert_request("eth_gasPrice", ()).unwrap();
+        // the zero value transfer to the wallet itself with the same nonce
+        let expected = EthTxParam {
+            to: wallet.signer.address(),
+            nonce: Some(U256::from(7)),
+            gas: Some(U256::from(TRANSFER_GAS_LIMIT)),
+            gas_price: Some(gwei * 25),
+            ..Default::default()
+        };
+        assert_sent_tx(&wallet, &mock, expected);
+
+        // the bumped gas price is used if it's higher than the gas price of the node
+        mock.push(REPLACEMENT_TX_HASH).unwrap();
+        mock.push(U256::from(1)).unwrap();
+        mock.push(gwei * 10).unwrap();
+        mock.push(pending_tx(&wallet, false)).unwrap();
+        wallet.cancel(PENDING_TX_HASH).await.unwrap();
+        mock.assert_request("eth_getTransactionByHash", [PENDING_TX_HASH])
+            .unwrap();
+        mock.assert_request("eth_gasPrice", ()).unwrap();
+        let expected = EthTxParam {
+            to: wallet.signer.address(),
+            nonce: Some(U256::from(7)),
+            gas: Some(U256::from(TRANSFER_GAS_LIMIT)),
+            gas_price: Some(gwei * 23),
+            ..Default::default()
+        };
+        assert_sent_tx(&wallet, &mock, expected);
+    }
+
+    #[tokio::test]
+    async fn test_replace_invalid_tx() {
+        let (wallet, mock) = Wallet::mocked();
+        let assert_error = |result: Result<H256, WalletError>, expected: &str| match result {
+            Err(WalletError::GetErrorResult(msg)) => assert!(msg.contains(expected), "{msg}"),
+            other => panic!("unexpected result: {other:?}"),
+        };
+
+        mock.push(Option::<Transaction>::None).unwrap();
+        assert_error(wallet.speed_up(PENDING_TX_HASH).await, "is not found");
+
+        let mined_tx = Transaction {
+            block_number: Some(U64::from(100)),
+            ..pending_tx(&wallet, true)
+        };
+        mock.push(mined_tx).unwrap();
+        assert_error(wallet.speed_up(PENDING_TX_HASH).await, "is already mined");
+
+        let other_tx = Transaction {
+            from: Address::repeat_byte(0x44),
+            ..pending_tx(&wallet, true)
+        };
+        mock.push(other_tx).unwrap();
+        assert_error(
+            wallet.cancel(PENDING_TX_HASH).await,
+            "is not sent by the wallet",
+        );
+    }
+
+    // The default account of anvil, start the node by `anvil` and run the test with `--ignored`.
+    const ANVIL_URL: &str = "http://localhost:8545";
+    const ANVIL_PRIVATE_KEY: &str =
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    async fn set_automine(wallet: &Wallet, automine: bool) {
+        wallet
+            .provider
+            .request::<_, ()>("evm_setAutomine", [automine])
+            .await
+            .unwrap();
+    }
+
+    async fn receipt(wallet: &Wallet, tx_hash: H256) -> Option<TransactionReceipt> {
+        wallet
+            .provider
+            .get_transaction_receipt(tx_hash)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_speed_up_and_cancel() {
+        let wallet = Wallet::new(ANVIL_URL, ANVIL_PRIVATE_KEY).unwrap();
+        set_automine(&wallet, false).await;
+
+        let params = EthTxParam {
+            is_support_eip1559: true,
+            to: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+                .parse()
+                .unwrap(),
+            value: Some(U256::from(1)),
+            ..Default::default()
+        };
+        let (tx_hash1, tx_hash2) = futures::join!(
+            wallet.sign_and_send_raw_tx(params.clone()),
+            wallet.sign_and_send_raw_tx(params.clone())
+        );
+        let (tx_hash1, tx_hash2) = (tx_hash1.unwrap(), tx_hash2.unwrap());
+        let tx1 = wallet.provider.get_transaction(tx_hash1).await.unwrap();
+        let tx2 = wallet.provider.get_transaction(tx_hash2).await.unwrap();
+        assert_ne!(tx1.unwrap().nonce, tx2.unwrap().nonce);
+
+        let sped_up_hash = wallet.speed_up(tx_hash1).await.unwrap();
+        assert_ne!(sped_up_hash, tx_hash1);
+        let cancel_hash = wallet.cancel(tx_hash2).await.unwrap();
+        wallet
+            .provider
+            .request::<_, serde_json::Value>("evm_mine", ())
+            .await
+            .unwrap();
+        set_automine(&wallet, true).await;
+
+        assert!(receipt(&wallet, sped_up_hash).await.is_some());
+        assert!(receipt(&wallet, cancel_hash).await.is_some());
+        assert!(receipt(&wallet, tx_hash1).await.is_none());
+        assert!(receipt(&wallet, tx_hash2).await.is_none());
+        assert!(wallet.speed_up(sped_up_hash).await.is_err());
+    }
+}
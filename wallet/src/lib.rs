@@ -1,4 +1,18 @@
 pub mod abi;
+pub mod bindings;
+pub mod confirmation;
+pub mod contract_reader;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod deposit_tracker;
+pub mod eip1271;
+pub mod erc20;
 pub mod error;
 pub mod eth;
+pub mod exodus;
+pub mod gas;
+pub mod multi_chain;
+pub mod priority_op;
+pub mod signer;
+pub(crate) mod utils;
 pub mod wallet;
+pub mod withdrawal;
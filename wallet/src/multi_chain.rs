@@ -0,0 +1,420 @@
+use crate::error::WalletError;
+use crate::eth::{EthTxOption, EthTxParam};
+use crate::signer::Layer1Signer;
+use crate::utils::layer1_address;
+use crate::wallet::Wallet;
+use ethers::providers::{Http, Middleware, Provider};
+use std::collections::HashMap;
+use zklink_sdk_provider::response::{ChainResp, EthPropertyResp};
+#[cfg(not(target_arch = "wasm32"))]
+use zklink_sdk_provider::ZkLinkRpcClient;
+use zklink_sdk_signers::eth_signer::EthSigner;
+use zklink_sdk_types::basic_types::{BigUint, ChainId, ZkLinkAddress};
+use zklink_sdk_types::prelude::H256;
+
+/// The layer1 contract a transaction of the zklink chain is sent to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum L1Route {
+    /// The zklink main contract deployed on the layer1 of the chain.
+    Main {
+        chain_id: ChainId,
+        contract: ZkLinkAddress,
+    },
+    /// The `l1_gateway` contract deployed on the layer1 of `chain_id`, which bridges the
+    /// deposit to the `target_chain_id`.
+    Gateway {
+        chain_id: ChainId,
+        target_chain_id: ChainId,
+        contract: ZkLinkAddress,
+    },
+}
+
+impl L1Route {
+    /// The zklink chain whose layer1 the transaction is sent to.
+    pub fn chain_id(&self) -> ChainId {
+        match self {
+            L1Route::Main { chain_id, .. } | L1Route::Gateway { chain_id, .. } => *chain_id,
+        }
+    }
+
+    pub fn contract(&self) -> &ZkLinkAddress {
+        match self {
+            L1Route::Main { contract, .. } | L1Route::Gateway { contract, .. } => contract,
+        }
+    }
+
+    pub fn is_gateway(&self) -> bool {
+        matches!(self, L1Route::Gateway { .. })
+    }
+}
+
+/// The layer1 wallets of all the zklink chains sharing one layer1 signer.
+pub struct MultiChainWallet<M = Provider<Http>, S = EthSigner> {
+    chains: HashMap<ChainId, ChainResp>,
+    eth_property: Option<EthPropertyResp>,
+    wallets: HashMap<ChainId, Wallet<M, S>>,
+}
+
+impl MultiChainWallet {
+    /// Create the wallet of every chain which has a layer1 rpc url in `l1_urls`, the chains
+    /// without url can only be deposited to through the gateway.
+    pub fn new(
+        chains: Vec<ChainResp>,
+        eth_property: Option<EthPropertyResp>,
+        l1_urls: &HashMap<ChainId, String>,
+        private_key: &str,
+    ) -> Result<Self, WalletError> {
+        let wallets = l1_urls
+            .iter()
+            .map(|(chain_id, url)| Ok((*chain_id, Wallet::new(url, private_key)?)))
+            .collect::<Result<HashMap<_, _>, WalletError>>()?;
+        Self::new_with(chains, eth_property, wallets)
+    }
+
+    /// Create the wallet with the `getSupportChains` and `getEthProperty` of the zklink rpc.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn new_with_rpc<C: ZkLinkRpcClient + Sync>(
+        rpc: &C,
+        l1_urls: &HashMap<ChainId, String>,
+        private_key: &str,
+    ) -> Result<Self, WalletError> {
+        let chains = rpc
+            .get_support_chains()
+            .await
+            .map_err(|e| WalletError::ZkLinkRpcError(e.to_string()))?;
+        // the gateways are only deployed for the chains bridged by the primary chain
+        let eth_property = rpc.get_eth_property().await.ok();
+        Self::new(chains, eth_property, l1_urls, private_key)
+    }
+}
+
+impl<M: Middleware, S: Layer1Signer> MultiChainWallet<M, S> {
+    /// Create the wallet with the layer1 wallet of every chain in `wallets`.
+    pub fn new_with(
+        chains: Vec<ChainResp>,
+        eth_property: Option<EthPropertyResp>,
+        wallets: HashMap<ChainId, Wallet<M, S>>,
+    ) -> Result<Self, WalletError> {
+        let chains: HashMap<_, _> = chains.into_iter().map(|c| (c.chain_id, c)).collect();
+        if let Some(chain_id) = wallets.keys().find(|id| !chains.contains_key(id)) {
+            return Err(WalletError::UnsupportedChain(*chain_id));
+        }
+        Ok(Self {
+            chains,
+            eth_property,
+            wallets,
+        })
+    }
+
+    pub fn chain(&self, chain_id: ChainId) -> Option<&ChainResp> {
+        self.chains.get(&chain_id)
+    }
+
+    pub fn wallet(&self, chain_id: ChainId) -> Option<&Wallet<M, S>> {
+        self.wallets.get(&chain_id)
+    }
+
+    /// The route of deposit to `target_chain_id`, the deposit is sent to the main contract
+    /// directly if the layer1 of the target chain is configured, otherwise through the gateway.
+    pub fn deposit_route(&self, target_chain_id: ChainId) -> Result<L1Route, WalletError> {
+        if self.wallets.contains_key(&target_chain_id) {
+            return self.main_route(target_chain_id);
+        }
+        let eth_property = self
+            .eth_property
+            .as_ref()
+            .filter(|p| self.wallets.contains_key(&p.chain_id))
+            .ok_or(WalletError::UnsupportedChain(target_chain_id))?;
+        let gateway = eth_property
+            .gateways
+            .iter()
+            .find(|g| g.chain_id == target_chain_id)
+            .ok_or(WalletError::UnsupportedChain(target_chain_id))?;
+        Ok(L1Route::Gateway {
+            chain_id: eth_property.chain_id,
+            target_chain_id,
+            contract: gateway.l1_gateway_contract.clone(),
+        })
+    }
+
+    /// The route of the full exit and the claim, which are only accepted by the main contract.
+    pub fn main_route(&self, chain_id: ChainId) -> Result<L1Route, WalletError> {
+        let chain = self
+            .chains
+            .get(&chain_id)
+            .filter(|_| self.wallets.contains_key(&chain_id))
+            .ok_or(WalletError::UnsupportedChain(chain_id))?;
+        Ok(L1Route::Main {
+            chain_id,
+            contract: chain.main_contract.clone(),
+        })
+    }
+
+    /// Get the wallet of the route and check the layer1 chain id of its provider before signing.
+    async fn checked_wallet(&self, route: &L1Route) -> Result<&Wallet<M, S>, WalletError> {
+        let chain_id = route.chain_id();
+        let wallet = self
+            .wallets
+            .get(&chain_id)
+            .ok_or(WalletError::UnsupportedChain(chain_id))?;
+        let expected = self
+            .chains
+            .get(&chain_id)
+            .ok_or(WalletError::UnsupportedChain(chain_id))?
+            .layer_one_chain_id;
+        let actual = wallet
+            .provider
+            .get_chainid()
+            .await
+            .map_err(WalletError::from_middleware)?;
+        if actual != expected {
+            return Err(WalletError::Layer1ChainIdMismatch {
+                expected: expected.as_u64(),
+                actual: actual.as_u64(),
+            });
+        }
+        Ok(wallet)
+    }
+
+    fn route_params(route: &L1Route, eth_params: EthTxOption) -> Result<EthTxParam, WalletError> {
+        EthTxOption {
+            to: route.contract().clone(),
+            ..eth_params
+        }
+        .try_into()
+    }
+
+    /// Deposit ETH to `deposit_to` of the target chain, `deposit_to` must be a 20 bytes address.
+    pub async fn deposit_eth(
+        &self,
+        target_chain_id: ChainId,
+        sub_account_id: u8,
+        deposit_to: ZkLinkAddress,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let deposit_to = layer1_address(&deposit_to)?;
+        let route = self.deposit_route(target_chain_id)?;
+        let wallet = self.checked_wallet(&route).await?;
+        wallet
+            .inner_deposit_eth(
+                sub_account_id,
+                deposit_to,
+                route.is_gateway(),
+                Self::route_params(&route, eth_params)?,
+            )
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn deposit_erc20(
+        &self,
+        target_chain_id: ChainId,
+        sub_account_id: u8,
+        deposit_to: ZkLinkAddress,
+        token_addr: ZkLinkAddress,
+        amount: BigUint,
+        mapping: bool,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let deposit_to = layer1_address(&deposit_to)?;
+        let token_addr = layer1_address(&token_addr)?;
+        let route = self.deposit_route(target_chain_id)?;
+        let wallet = self.checked_wallet(&route).await?;
+        wallet
+            .inner_deposit_erc20(
+                sub_account_id,
+                deposit_to,
+                token_addr,
+                amount,
+                mapping,
+                route.is_gateway(),
+                Self::route_params(&route, eth_params)?,
+            )
+            .await
+    }
+
+    pub async fn full_exit(
+        &self,
+        chain_id: ChainId,
+        account_id: u32,
+        sub_account_id: u8,
+        token_id: u16,
+        mapping: bool,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let route = self.main_route(chain_id)?;
+        let wallet = self.checked_wallet(&route).await?;
+        wallet
+            .inner_full_exit(
+                account_id,
+                sub_account_id,
+                token_id,
+                mapping,
+                Self::route_params(&route, eth_params)?,
+            )
+            .await
+    }
+
+    /// Claim the pending balance of the owner from the main contract of the chain.
+    pub async fn claim(
+        &self,
+        chain_id: ChainId,
+        owner: ZkLinkAddress,
+        token_id: u16,
+        amount: BigUint,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let owner = layer1_address(&owner)?;
+        let route = self.main_route(chain_id)?;
+        let wallet = self.checked_wallet(&route).await?;
+        wallet
+            .inner_withdraw_pending_balance(
+                owner,
+                token_id,
+                amount,
+                Self::route_params(&route, eth_params)?,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::providers::MockProvider;
+    use std::str::FromStr;
+    use zklink_sdk_provider::response::GateWayInfo;
+    use zklink_sdk_types::basic_types::TokenId;
+    use zklink_sdk_types::prelude::U256;
+
+    fn chain(chain_id: u8, main_contract: &str) -> ChainResp {
+        ChainResp {
+            chain_id: ChainId(chain_id),
+            chain_type: 0,
+            layer_one_chain_id: U256::from(chain_id),
+            main_contract: ZkLinkAddress::from_str(main_contract).unwrap(),
+            gas_token_id: TokenId(1),
+            deposit_confirmation: 1,
+        }
+    }
+
+    #[test]
+    fn test_route() {
+        let private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let main_contract = "0x0000000000000000000000000000000000000001";
+        let gateway = "0x0000000000000000000000000000000000000002";
+        let eth_property = EthPropertyResp {
+            chain_id: ChainId(1),
+            layer_one_chain_id: U256::from(1),
+            gateways: vec![GateWayInfo {
+                chain_id: ChainId(2),
+                l1_gateway_contract: ZkLinkAddress::from_str(gateway).unwrap(),
+                l2_gateway_contract: Default::default(),
+                tokens: vec![],
+            }],
+            gas_token_id: TokenId(1),
+            deposit_confirmation: 1,
+        };
+        let l1_urls = HashMap::from([(ChainId(1), "http://localhost:8545".to_string())]);
+        let wallet = MultiChainWallet::new(
+            vec![chain(1, main_contract), chain(2, main_contract)],
+            Some(eth_property),
+            &l1_urls,
+            private_key,
+        )
+        .unwrap();
+
+        let route = wallet.deposit_route(ChainId(1)).unwrap();
+        assert!(!route.is_gateway());
+        let route = wallet.deposit_route(ChainId(2)).unwrap();
+        assert!(route.is_gateway());
+        assert_eq!(route.chain_id(), ChainId(1));
+        assert_eq!(route.contract(), &ZkLinkAddress::from_str(gateway).unwrap());
+        assert!(wallet.deposit_route(ChainId(3)).is_err());
+        assert!(wallet.main_route(ChainId(2)).is_err());
+
+        let l1_urls = HashMap::from([(ChainId(3), "http://localhost:8545".to_string())]);
+        assert!(MultiChainWallet::new(vec![], None, &l1_urls, private_key).is_err());
+    }
+
+    fn mocked_wallet() -> (MultiChainWallet<Provider<MockProvider>>, MockProvider) {
+        let (wallet, mock) = Wallet::mocked();
+        let wallets = HashMap::from([(ChainId(1), wallet)]);
+        let main_contract = "0x0000000000000000000000000000000000000001";
+        let wallet = MultiChainWallet::new_with(vec![chain(1, main_contract)], None, wallets);
+        (wallet.unwrap(), mock)
+    }
+
+    #[tokio::test]
+    async fn test_layer1_chain_id_mismatch() {
+        let (wallet, mock) = mocked_wallet();
+        let deposit_to = ZkLinkAddress::from([0x11; 20]);
+        // the layer1 chain id of the provider is 5 but the chain 1 is expected
+        mock.push(U256::from(5)).unwrap();
+        let result = wallet
+            .deposit_eth(ChainId(1), 0, deposit_to.clone(), Default::default())
+            .await;
+        assert!(matches!(
+            result,
+            Err(WalletError::Layer1ChainIdMismatch {
+                expected: 1,
+                actual: 5
+            })
+        ));
+        mock.push(U256::from(5)).unwrap();
+        let result = wallet
+            .full_exit(ChainId(1), 10, 1, 17, false, Default::default())
+            .await;
+        assert!(matches!(
+            result,
+            Err(WalletError::Layer1ChainIdMismatch { .. })
+        ));
+
+        mock.push(U256::from(1)).unwrap();
+        let route = wallet.main_route(ChainId(1)).unwrap();
+        assert!(wallet.checked_wallet(&route).await.is_ok());
+        // the wallet of the chain is checked before the chain id
+        let route = L1Route::Main {
+            chain_id: ChainId(2),
+            contract: Default::default(),
+        };
+        assert!(matches!(
+            wallet.checked_wallet(&route).await,
+            Err(WalletError::UnsupportedChain(ChainId(2)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reject_starknet_address() {
+        let (wallet, _mock) = mocked_wallet();
+        // the starknet address is 32 bytes, no request is sent to the provider
+        let starknet_addr = ZkLinkAddress::from([0x11; 32]);
+        let token_addr = ZkLinkAddress::from([0x22; 20]);
+        let result = wallet
+            .deposit_eth(ChainId(1), 0, starknet_addr.clone(), Default::default())
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+        let result = wallet
+            .deposit_erc20(
+                ChainId(1),
+                0,
+                starknet_addr.clone(),
+                token_addr,
+                BigUint::from(100u32),
+                false,
+                Default::default(),
+            )
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+        let result = wallet
+            .claim(
+                ChainId(1),
+                starknet_addr,
+                17,
+                BigUint::from(100u32),
+                Default::default(),
+            )
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::error::WalletError;
+use crate::signer::Layer1Signer;
+use crate::wallet::Wallet;
+use ethers::abi::{Address, Event, RawLog, Token};
+use ethers::providers::Middleware;
+use ethers::types::{BlockNumber, Filter, Log};
+use serde::Serialize;
+use zklink_sdk_types::basic_types::tx_hash::TxHash;
+use zklink_sdk_types::basic_types::{
+    AccountId, BigUint, ChainId, SubAccountId, TokenId, ZkLinkAddress,
+};
+use zklink_sdk_types::prelude::H256;
+use zklink_sdk_types::tx_type::deposit::Deposit;
+use zklink_sdk_types::tx_type::full_exit::FullExit;
+use zklink_sdk_types::tx_type::TxTrait;
+
+/// The length of the `Deposit` and `FullExit` priority operation pubdata.
+const PRIORITY_OP_PUBDATA_LEN: usize = 59;
+
+/// The priority operation decoded from the `NewPriorityRequest` event of the zklink contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum PriorityOp {
+    Deposit(Box<Deposit>),
+    FullExit(Box<FullExit>),
+}
+
+impl PriorityOp {
+    pub fn serial_id(&self) -> u64 {
+        match self {
+            PriorityOp::Deposit(deposit) => deposit.serial_id,
+            PriorityOp::FullExit(full_exit) => full_exit.serial_id,
+        }
+    }
+
+    /// The hash of the layer1 transaction which created the priority operation.
+    pub fn l2_hash(&self) -> H256 {
+        match self {
+            PriorityOp::Deposit(deposit) => deposit.l2_hash,
+            PriorityOp::FullExit(full_exit) => full_exit.l2_hash,
+        }
+    }
+
+    /// The expected zklink transaction hash of the priority operation.
+    pub fn tx_hash(&self) -> TxHash {
+        let hash = match self {
+            PriorityOp::Deposit(deposit) => deposit.tx_hash(),
+            PriorityOp::FullExit(full_exit) => full_exit.tx_hash(),
+        };
+        TxHash::from_slice(&hash).unwrap()
+    }
+
+    /// Decode the `pubData` of the `NewPriorityRequest` event.
+    pub fn from_pubdata(
+        sender: Address,
+        serial_id: u64,
+        pubdata: &[u8],
+        l2_hash: H256,
+    ) -> Result<Self, WalletError> {
+        if pubdata.len() != PRIORITY_OP_PUBDATA_LEN {
+            return Err(WalletError::GetErrorResult(format!(
+                "invalid priority op pubdata length: {}",
+                pubdata.len()
+            )));
+        }
+        let read_u16 = |offset: usize| u16::from_be_bytes([pubdata[offset], pubdata[offset + 1]]);
+        let read_u128 = |offset: usize| {
+            let mut bytes = [0u8; 16];
+            bytes.copy_from_slice(&pubdata[offset..offset + 16]);
+            u128::from_be_bytes(bytes)
+        };
+        let read_owner = |offset: usize| owner_address(&pubdata[offset..offset + 32]);
+        let chain_id = ChainId(pubdata[1]);
+        let account_id = AccountId(u32::from_be_bytes([
+            pubdata[2], pubdata[3], pubdata[4], pubdata[5],
+        ]));
+        let sub_account_id = SubAccountId(pubdata[6]);
+
+        match pubdata[0] {
+            // opType | chainId | accountId | subAccountId | tokenId | targetTokenId | amount | owner
+            Deposit::TX_TYPE => Ok(PriorityOp::Deposit(Box::new(Deposit {
+                from: ZkLinkAddress::from(sender.to_fixed_bytes()),
+                to: read_owner(27),
+                from_chain_id: chain_id,
+                sub_account_id,
+                l1_source_token: TokenId(read_u16(7) as u32),
+                l2_target_token: TokenId(read_u16(9) as u32),
+                amount: BigUint::from(read_u128(11)),
+                serial_id,
+                l2_hash,
+                eth_hash: None,
+            }))),
+            // opType | chainId | accountId | subAccountId | owner | tokenId | srcTokenId | amount
+            FullExit::TX_TYPE => Ok(PriorityOp::FullExit(Box::new(FullExit {
+                to_chain_id: chain_id,
+                account_id,
+                sub_account_id,
+                exit_address: read_owner(7),
+                l2_source_token: TokenId(read_u16(41) as u32),
+                l1_target_token: TokenId(read_u16(39) as u32),
+                oracle_prices: Default::default(),
+                serial_id,
+                l2_hash,
+            }))),
+            op_type => Err(WalletError::GetErrorResult(format!(
+                "unsupported priority op type: {op_type}"
+            ))),
+        }
+    }
+}
+
+/// The owner is encoded as bytes32, the layer1 address of evm chains is left padded with zero.
+fn owner_address(owner: &[u8]) -> ZkLinkAddress {
+    if owner[..12].iter().all(|b| *b == 0) {
+        ZkLinkAddress::from_slice(&owner[12..]).unwrap()
+    } else {
+        ZkLinkAddress::from_slice(owner).unwrap()
+    }
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    fn new_priority_request_event(&self) -> Result<Event, WalletError> {
+        Ok(self
+            .get_l1_contract(false)?
+            .event("NewPriorityRequest")?
+            .clone())
+    }
+
+    /// Decode the `NewPriorityRequest` event logs emitted by the zklink contract, the logs of other
+    /// events or other contracts are ignored.
+    pub fn decode_priority_ops(
+        &self,
+        zklink_addr: Address,
+        logs: &[Log],
+    ) -> Result<Vec<PriorityOp>, WalletError> {
+        let event = self.new_priority_request_event()?;
+        let mut ops = Vec::new();
+        for log in logs {
+            if log.address != zklink_addr || log.topics.first() != Some(&event.signature()) {
+                continue;
+            }
+            let l2_hash = log
+                .transaction_hash
+                .ok_or_else(|| WalletError::GetErrorResult("log of pending tx".into()))?;
+            let raw_log = RawLog {
+                topics: log.topics.clone(),
+                data: log.data.to_vec(),
+            };
+            let params = event.parse_log(raw_log)?.params;
+            let (sender, serial_id, pubdata) =
+                match (&params[0].value, &params[1].value, &params[3].value) {
+                    (Token::Address(sender), Token::Uint(serial_id), Token::Bytes(pubdata)) => {
+                        (*sender, serial_id.as_u64(), pubdata)
+                    }
+                    _ => {
+                        return Err(WalletError::GetErrorResult(
+                            "invalid NewPriorityRequest event".into(),
+                        ))
+                    }
+                };
+            ops.push(PriorityOp::from_pubdata(
+                sender, serial_id, pubdata, l2_hash,
+            )?);
+        }
+        Ok(ops)
+    }
+
+    /// Get the priority operations created by the layer1 transaction.
+    pub async fn get_priority_ops_by_tx_hash(
+        &self,
+        zklink_addr: ZkLinkAddress,
+        tx_hash: H256,
+    ) -> Result<Vec<PriorityOp>, WalletError> {
+        let receipt = self
+            .provider
+            .get_transaction_receipt(tx_hash)
+            .await
+            .map_err(WalletError::from_middleware)?
+            .ok_or_else(|| WalletError::GetErrorResult(format!("{tx_hash:?} is not mined")))?;
+        self.decode_priority_ops(Address::from_slice(zklink_addr.as_bytes()), &receipt.logs)
+    }
+
+    /// Get the priority operations emitted by the zklink contract in the block range(both included).
+    pub async fn get_priority_ops(
+        &self,
+        zklink_addr: ZkLinkAddress,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<PriorityOp>, WalletError> {
+        let event = self.new_priority_request_event()?;
+        let zklink_addr = Address::from_slice(zklink_addr.as_bytes());
+        let filter = Filter::new()
+            .address(zklink_addr)
+            .topic0(event.signature())
+            .from_block(BlockNumber::Number(from_block.into()))
+            .to_block(BlockNumber::Number(to_block.into()));
+        let logs = self
+            .provider
+            .get_logs(&filter)
+            .await
+            .map_err(WalletError::from_middleware)?;
+        self.decode_priority_ops(zklink_addr, &logs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_decode_deposit_pubdata() {
+        let owner = "afaff3ad1a0425d792432d9ecd1c3e26ef2c42e9";
+        let pubdata = ethers::utils::hex::decode(format!(
+            "0101000000000100120011{:032x}{:0>64}",
+            100u128, owner
+        ))
+        .unwrap();
+        let sender = Address::from_str(owner).unwrap();
+        let l2_hash =
+            H256::from_str("0xe35f3a39d542f6d276c2f203e8fd64fcb8bf5db062b71ccacf45d5ecd9d456f3")
+                .unwrap();
+        let op = PriorityOp::from_pubdata(sender, 32001, &pubdata, l2_hash).unwrap();
+        let PriorityOp::Deposit(deposit) = &op else {
+            panic!("not a deposit");
+        };
+        assert_eq!(deposit.from_chain_id, ChainId(1));
+        assert_eq!(deposit.sub_account_id, SubAccountId(1));
+        assert_eq!(deposit.l1_source_token, TokenId(18));
+        assert_eq!(deposit.l2_target_token, TokenId(17));
+        assert_eq!(deposit.amount, BigUint::from(100u32));
+        assert_eq!(deposit.to, ZkLinkAddress::from_hex(owner).unwrap());
+        assert_eq!(op.serial_id(), 32001);
+        assert_eq!(op.l2_hash(), l2_hash);
+    }
+
+    #[test]
+    fn test_decode_full_exit_pubdata() {
+        let owner = "afaff3ad1a0425d792432d9ecd1c3e26ef2c42e9";
+        let pubdata = ethers::utils::hex::decode(format!(
+            "05020000000a03{:0>64}00110012{:032x}",
+            owner, 0u128
+        ))
+        .unwrap();
+        let sender = Address::from_str(owner).unwrap();
+        let op = PriorityOp::from_pubdata(sender, 100, &pubdata, H256::zero()).unwrap();
+        let PriorityOp::FullExit(full_exit) = &op else {
+            panic!("not a full exit");
+        };
+        assert_eq!(full_exit.to_chain_id, ChainId(2));
+        assert_eq!(full_exit.account_id, AccountId(10));
+        assert_eq!(full_exit.sub_account_id, SubAccountId(3));
+        assert_eq!(full_exit.l1_target_token, TokenId(17));
+        assert_eq!(full_exit.l2_source_token, TokenId(18));
+        assert_eq!(
+            full_exit.exit_address,
+            ZkLinkAddress::from_hex(owner).unwrap()
+        );
+
+        assert!(PriorityOp::from_pubdata(sender, 100, &pubdata[1..], H256::zero()).is_err());
+    }
+
+    #[test]
+    fn test_decode_priority_ops() {
+        let (wallet, _mock) = Wallet::mocked();
+        let event = wallet.new_priority_request_event().unwrap();
+        let owner = "afaff3ad1a0425d792432d9ecd1c3e26ef2c42e9";
+        let sender = Address::from_str(owner).unwrap();
+        let pubdata = ethers::utils::hex::decode(format!(
+            "0101000000000100120011{:032x}{:0>64}",
+            100u128, owner
+        ))
+        .unwrap();
+        let zklink_addr = Address::repeat_byte(0x11);
+        let log = Log {
+            address: zklink_addr,
+            topics: vec![event.signature()],
+            data: ethers::abi::encode(&[
+                Token::Address(sender),
+                Token::Uint(32001u64.into()),
+                Token::Uint(1u64.into()),
+                Token::Bytes(pubdata),
+                Token::Uint(100u64.into()),
+            ])
+            .into(),
+            transaction_hash: Some(H256::repeat_byte(0x22)),
+            ..Default::default()
+        };
+        // the same event emitted by another contract
+        let fake_log = Log {
+            address: Address::repeat_byte(0x33),
+            ..log.clone()
+        };
+        let ops = wallet
+            .decode_priority_ops(zklink_addr, &[fake_log, log])
+            .unwrap();
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].serial_id(), 32001);
+        assert_eq!(ops[0].l2_hash(), H256::repeat_byte(0x22));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::WalletError;
+use ethers::abi::Address;
+use ethers::core::types::transaction::eip2718::TypedTransaction;
+use ethers::core::types::transaction::eip712::TypedData as EthersTypedData;
+use ethers::signers::Signer;
+use serde::Serialize;
+use std::future::{ready, Future};
+use zklink_sdk_signers::eth_signer::eip712::eip712::TypedData;
+use zklink_sdk_signers::eth_signer::{EthSigner, PackedEthSignature};
+
+/// The signer of the layer1 transactions and typed data sent by the `Wallet`, it's implemented by
+/// the local `EthSigner` and any ethers signer wrapped by `EthersSigner`(e.g. ledger, aws kms).
+pub trait Layer1Signer {
+    /// The address of the signer.
+    fn address(&self) -> Address;
+
+    /// Sign the transaction, the chain id of the transaction must be set.
+    fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+    ) -> impl Future<Output = Result<PackedEthSignature, WalletError>>;
+
+    /// Sign the EIP-712 typed data, results are identical to `eth_signTypedData_v4`.
+    fn sign_typed_data<M: Serialize, D: Serialize>(
+        &self,
+        typed_data: &TypedData<M, D>,
+    ) -> impl Future<Output = Result<PackedEthSignature, WalletError>>;
+}
+
+impl Layer1Signer for EthSigner {
+    fn address(&self) -> Address {
+        self.get_address()
+    }
+
+    fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+    ) -> impl Future<Output = Result<PackedEthSignature, WalletError>> {
+        ready(EthSigner::sign_transaction(self, tx).map_err(WalletError::from))
+    }
+
+    fn sign_typed_data<M: Serialize, D: Serialize>(
+        &self,
+        typed_data: &TypedData<M, D>,
+    ) -> impl Future<Output = Result<PackedEthSignature, WalletError>> {
+        ready(EthSigner::sign_typed_data(self, typed_data).map_err(WalletError::from))
+    }
+}
+
+/// The adapter of the ethers signers.
+#[derive(Debug, Clone)]
+pub struct EthersSigner<S>(pub S);
+
+impl<S: Signer> Layer1Signer for EthersSigner<S> {
+    fn address(&self) -> Address {
+        self.0.address()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+    ) -> Result<PackedEthSignature, WalletError> {
+        let signature = self
+            .0
+            .sign_transaction(tx)
+            .await
+            .map_err(|e| WalletError::SignerError(e.to_string()))?;
+        Ok(PackedEthSignature(signature))
+    }
+
+    async fn sign_typed_data<M: Serialize, D: Serialize>(
+        &self,
+        typed_data: &TypedData<M, D>,
+    ) -> Result<PackedEthSignature, WalletError> {
+        // both of them are the `eth_signTypedData_v4` request
+        let typed_data: EthersTypedData = serde_json::to_value(typed_data)
+            .and_then(serde_json::from_value)
+            .map_err(|e| WalletError::ParseJsonError(e.to_string()))?;
+        let signature = self
+            .0
+            .sign_typed_data(&typed_data)
+            .await
+            .map_err(|e| WalletError::SignerError(e.to_string()))?;
+        Ok(PackedEthSignature(signature))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::signers::LocalWallet;
+    use std::str::FromStr;
+    use zklink_sdk_signers::eth_signer::eip712::eip712::RawTypedData;
+    use zklink_sdk_signers::eth_signer::eip712::fixtures::{
+        PERMIT_OWNER_PRIVATE_KEY, PERMIT_TYPED_DATA,
+    };
+
+    #[tokio::test]
+    async fn test_ethers_signer() {
+        let eth_signer = EthSigner::try_from(PERMIT_OWNER_PRIVATE_KEY).unwrap();
+        let ethers_signer = EthersSigner(LocalWallet::from_str(PERMIT_OWNER_PRIVATE_KEY).unwrap());
+        assert_eq!(
+            Layer1Signer::address(&eth_signer),
+            Layer1Signer::address(&ethers_signer)
+        );
+
+        let typed_data = RawTypedData::from_json(PERMIT_TYPED_DATA).unwrap();
+        let signature = Layer1Signer::sign_typed_data(&eth_signer, &typed_data)
+            .await
+            .unwrap();
+        let ethers_signature = ethers_signer.sign_typed_data(&typed_data).await.unwrap();
+        assert_eq!(signature.as_hex(), ethers_signature.as_hex());
+    }
+}
@@ -0,0 +1,55 @@
+//! The conversions between the zklink types and the layer1 types shared by the wallet modules.
+use crate::error::WalletError;
+use ethers::types::{Address, U256};
+use std::str::FromStr;
+use zklink_sdk_types::basic_types::{BigUint, ZkLinkAddress};
+
+/// Convert the zklink address to the layer1 address, the 32 bytes address(e.g. starknet) is invalid.
+pub(crate) fn layer1_address(address: &ZkLinkAddress) -> Result<Address, WalletError> {
+    if address.as_bytes().len() != Address::len_bytes() {
+        return Err(WalletError::InvalidInputParameter);
+    }
+    Ok(Address::from_slice(address.as_bytes()))
+}
+
+pub(crate) fn biguint_to_u256(amount: &BigUint) -> Result<U256, WalletError> {
+    U256::from_dec_str(&amount.to_string()).map_err(|_| WalletError::InvalidInputParameter)
+}
+
+pub(crate) fn u256_to_biguint(amount: U256) -> BigUint {
+    BigUint::from_str(&amount.to_string()).unwrap()
+}
+
+/// The amounts of the zklink contract are uint128 at most.
+pub(crate) fn biguint_to_u128(amount: &BigUint) -> Result<u128, WalletError> {
+    u128::from_str(&amount.to_string()).map_err(|_| WalletError::InvalidInputParameter)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_convert_amount() {
+        let amount = BigUint::from(u128::MAX);
+        assert_eq!(biguint_to_u128(&amount).unwrap(), u128::MAX);
+        assert!(biguint_to_u128(&(amount.clone() + 1u32)).is_err());
+
+        let amount = BigUint::from(2u32).pow(256) - 1u32;
+        let value = biguint_to_u256(&amount).unwrap();
+        assert_eq!(value, U256::MAX);
+        assert_eq!(u256_to_biguint(value), amount);
+        assert!(biguint_to_u256(&(amount + 1u32)).is_err());
+    }
+
+    #[test]
+    fn test_layer1_address() {
+        let address = ZkLinkAddress::from([0x11; 20]);
+        assert_eq!(
+            layer1_address(&address).unwrap(),
+            Address::repeat_byte(0x11)
+        );
+        let starknet_address = ZkLinkAddress::from([0x11; 32]);
+        assert!(layer1_address(&starknet_address).is_err());
+    }
+}
@@ -1,9 +1,17 @@
 use crate::abi::load_contracts;
+use crate::bindings::erc1271::IERC1271;
+use crate::bindings::erc20::IERC20;
+use crate::bindings::l1_gateway::L1Gateway;
+use crate::bindings::zklink::ZkLink;
 use crate::error::WalletError;
-use crate::eth::{encode_tx, new_call_typed_tx, new_typed_tx, EthTxOption, EthTxParam};
+use crate::eth::{new_call_typed_tx, new_typed_tx, EthTxOption, EthTxParam};
+use crate::gas::{FeeConfig, NonceTracker};
+use crate::signer::Layer1Signer;
+use crate::utils::{biguint_to_u128, biguint_to_u256};
 use bigdecimal::num_bigint::BigUint;
-use ethers::abi::{Address, Contract, Detokenize, Token, Tokenize, Uint};
-use ethers::contract::encode_function_data;
+use ethers::abi::{Address, Contract, Detokenize, Token, Tokenize};
+use ethers::contract::{encode_function_data, ContractCall};
+use ethers::core::types::transaction::eip2718::TypedTransaction;
 use ethers::providers::{Http, Middleware, Provider};
 use ethers::types::BlockNumber;
 use std::collections::HashMap;
@@ -16,10 +24,14 @@ use zklink_sdk_signers::eth_signer::EthSigner;
 use zklink_sdk_types::basic_types::ZkLinkAddress;
 use zklink_sdk_types::prelude::{PubKeyHash, H256, U256};
 
-pub struct Wallet {
+/// The layer1 wallet, which works over any ethers middleware(http, ws, ipc or mock provider) and
+/// any `Layer1Signer`.
+pub struct Wallet<M = Provider<Http>, S = EthSigner> {
     pub contracts: HashMap<String, Contract>,
-    pub signer: EthSigner,
-    pub provider: Arc<Provider<Http>>,
+    pub signer: S,
+    pub provider: Arc<M>,
+    pub fee_config: FeeConfig,
+    pub(crate) nonce_tracker: NonceTracker,
 }
 
 #[wasm_bindgen]
@@ -29,39 +41,67 @@ pub enum WaitForTxStatus {
     Pending,
 }
 
+pub(crate) fn calldata<M: Middleware, D: Detokenize>(
+    call: ContractCall<M, D>,
+) -> Result<Vec<u8>, WalletError> {
+    call.calldata()
+        .map(|data| data.to_vec())
+        .ok_or(WalletError::InvalidInputParameter)
+}
+
+/// Query the view function of the typed contract binding by `eth_call`.
+pub(crate) async fn view_call<M: Middleware, D: Detokenize>(
+    call: ContractCall<M, D>,
+) -> Result<D, WalletError> {
+    call.call()
+        .await
+        .map_err(|e| WalletError::GetErrorResult(e.to_string()))
+}
+
 impl Wallet {
-    pub fn new(url: &str, private_key: &str) -> Self {
-        let signer = EthSigner::try_from(private_key).unwrap();
-        let provider = Arc::new(Provider::<Http>::try_from(url).unwrap());
-        let contracts = load_contracts();
-        Self {
+    /// Create the wallet with the http provider and the local private key.
+    pub fn new(url: &str, private_key: &str) -> Result<Self, WalletError> {
+        let signer = EthSigner::try_from(private_key)?;
+        let provider = Provider::<Http>::try_from(url).map_err(|_| WalletError::InvalidNetwork)?;
+        Self::new_with(provider, signer)
+    }
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    pub fn new_with(provider: M, signer: S) -> Result<Self, WalletError> {
+        Ok(Self {
+            contracts: load_contracts()?,
             signer,
-            provider,
-            contracts,
-        }
+            provider: Arc::new(provider),
+            fee_config: Default::default(),
+            nonce_tracker: Default::default(),
+        })
     }
 
-    pub fn get_l1_contract(&self, is_gateway: bool) -> Contract {
+    pub fn get_l1_contract(&self, is_gateway: bool) -> Result<Contract, WalletError> {
         let contract_name = if is_gateway { "l1_gateway" } else { "zklink" };
         self.contracts
-            .get(&contract_name.to_string())
-            .unwrap()
-            .clone()
+            .get(contract_name)
+            .cloned()
+            .ok_or(WalletError::InvalidInputParameter)
     }
 
     pub async fn get_balance(&self) -> Result<U256, WalletError> {
-        let from = self.signer.get_address();
-        Ok(self.provider.get_balance(from, None).await?)
+        let from = self.signer.address();
+        self.provider
+            .get_balance(from, None)
+            .await
+            .map_err(WalletError::from_middleware)
     }
 
     pub async fn get_nonce(&self, block_number: String) -> Result<U256, WalletError> {
         let block_number = BlockNumber::from_str(&block_number)
             .map_err(|_e| WalletError::InvalidInputParameter)?;
-        let from = self.signer.get_address();
-        Ok(self
-            .provider
+        let from = self.signer.address();
+        self.provider
             .get_transaction_count(from, Some(block_number.into()))
-            .await?)
+            .await
+            .map_err(WalletError::from_middleware)
     }
 
     pub async fn wait_for_transaction(
@@ -71,7 +111,11 @@ impl Wallet {
     ) -> Result<WaitForTxStatus, WalletError> {
         let mut retries = retries.unwrap_or(60);
         loop {
-            let receipt = self.provider.get_transaction_receipt(tx_hash).await?;
+            let receipt = self
+                .provider
+                .get_transaction_receipt(tx_hash)
+                .await
+                .map_err(WalletError::from_middleware)?;
             if let Some(receipt) = receipt {
                 if let Some(status) = receipt.status {
                     if status.as_u64() == 1 {
@@ -81,7 +125,7 @@ impl Wallet {
                     }
                 }
             }
-            retries -= 1;
+            retries = retries.saturating_sub(1);
             if retries == 0 {
                 return Ok(WaitForTxStatus::Pending);
             }
@@ -96,7 +140,7 @@ impl Wallet {
         method: &str,
         args: T,
     ) -> Result<Vec<Token>, WalletError> {
-        let contract = self.get_l1_contract(is_gateway);
+        let contract = self.get_l1_contract(is_gateway)?;
         let function = contract
             .function(method)
             .map_err(WalletError::EthAbiError)?;
@@ -105,9 +149,18 @@ impl Wallet {
             data: Some(encoded_data.to_vec()),
             ..eth_params.clone()
         };
-        let chain_id = self.provider.get_chainid().await?;
+        let chain_id = self
+            .provider
+            .get_chainid()
+            .await
+            .map_err(WalletError::from_middleware)?;
         let typed_tx = new_call_typed_tx(params, chain_id.as_u64());
-        let data: Vec<u8> = (*(self.provider).call(&typed_tx, None).await?).to_vec();
+        let data = self
+            .provider
+            .call(&typed_tx, None)
+            .await
+            .map_err(WalletError::from_middleware)?
+            .to_vec();
         let tokens = function
             .decode_output(&data)
             .map_err(WalletError::EthAbiError)?;
@@ -115,35 +168,64 @@ impl Wallet {
     }
 
     pub async fn sign_and_send_raw_tx(&self, params: EthTxParam) -> Result<H256, WalletError> {
-        let from = self.signer.get_address();
-        let gas_price = if let Some(gas_price) = params.gas_price {
-            gas_price
-        } else {
-            self.provider.get_gas_price().await?
-        };
-        let nonce = if let Some(nonce) = params.nonce {
-            nonce
-        } else {
-            self.provider
-                .get_transaction_count(from, Some(ethers::types::BlockNumber::Pending.into()))
-                .await?
-        };
+        let from = self.signer.address();
+        let mut tx_params = params;
+        self.fill_fees(&mut tx_params).await?;
+        let is_tracked_nonce = tx_params.nonce.is_none();
+        if is_tracked_nonce {
+            tx_params.nonce = Some(self.next_nonce().await?);
+        }
 
-        let chain_id = self.provider.get_chainid().await?;
-        let tx_params = EthTxParam {
-            nonce: Some(nonce),
-            gas_price: Some(gas_price),
-            ..params.clone()
-        };
-        let mut typed_tx = new_typed_tx(from, tx_params, chain_id.as_u64());
+        let chain_id = self
+            .provider
+            .get_chainid()
+            .await
+            .map_err(WalletError::from_middleware)?;
+        let typed_tx = new_typed_tx(from, tx_params, chain_id.as_u64());
+        let result = self.sign_and_send_typed_tx(typed_tx).await;
+        if result.is_err() && is_tracked_nonce {
+            // the nonce is not used, resync it with the node
+            self.reset_nonce();
+        }
+        result
+    }
+
+    pub async fn sign_and_send_typed_tx(
+        &self,
+        mut typed_tx: TypedTransaction,
+    ) -> Result<H256, WalletError> {
         if typed_tx.gas().is_none() {
-            let gas_limit = self.provider.estimate_gas(&typed_tx, None).await?;
+            let gas_limit = self
+                .provider
+                .estimate_gas(&typed_tx, None)
+                .await
+                .map_err(WalletError::from_middleware)?;
             typed_tx.set_gas(gas_limit);
         }
-        let signature = self.signer.sign_transaction(&typed_tx)?;
+        let signature = self.signer.sign_transaction(&typed_tx).await?;
         let raw_tx = typed_tx.rlp_signed(&signature.0).to_vec();
-        let pending_tx = *(self.provider).send_raw_transaction(raw_tx.into()).await?;
-        Ok(pending_tx)
+        let pending_tx = self
+            .provider
+            .send_raw_transaction(raw_tx.into())
+            .await
+            .map_err(WalletError::from_middleware)?;
+        Ok(*pending_tx)
+    }
+
+    pub(crate) fn zklink(&self, contract: Address) -> ZkLink<M> {
+        ZkLink::new(contract, self.provider.clone())
+    }
+
+    pub(crate) fn l1_gateway(&self, contract: Address) -> L1Gateway<M> {
+        L1Gateway::new(contract, self.provider.clone())
+    }
+
+    pub(crate) fn erc20(&self, token: Address) -> IERC20<M> {
+        IERC20::new(token, self.provider.clone())
+    }
+
+    pub(crate) fn erc1271(&self, account: Address) -> IERC1271<M> {
+        IERC1271::new(account, self.provider.clone())
     }
 
     pub async fn inner_approve_erc20(
@@ -152,18 +234,13 @@ impl Wallet {
         amount: BigUint,
         eth_params: EthTxParam,
     ) -> Result<H256, WalletError> {
-        let params = vec![
-            Token::Address(zklink_addr),
-            Token::Uint(ethers::types::U256::from_str(&amount.to_string()).unwrap()),
-        ];
-        let contract = self.contracts.get(&"erc20".to_owned()).unwrap();
-        let tx_data = encode_tx(contract.clone(), "approve", params)?;
+        let amount = biguint_to_u256(&amount)?;
+        let tx_data = calldata(self.erc20(eth_params.to).approve(zklink_addr, amount))?;
         let tx_params = EthTxParam {
             data: Some(tx_data),
-            ..eth_params.clone()
+            ..eth_params
         };
-        let tx_hash = self.sign_and_send_raw_tx(tx_params).await?;
-        Ok(tx_hash)
+        self.sign_and_send_raw_tx(tx_params).await
     }
 
     pub async fn inner_deposit_eth(
@@ -175,18 +252,22 @@ impl Wallet {
     ) -> Result<H256, WalletError> {
         let mut bytes = [0; 32];
         bytes[12..].copy_from_slice(deposit_to.as_bytes());
-        let params = vec![
-            Token::FixedBytes(bytes.to_vec()),
-            Token::Uint(ethers::types::U256::from(sub_account_id)),
-        ];
-        let contract = self.get_l1_contract(is_gateway);
-        let tx_data = encode_tx(contract.clone(), "depositETH", params)?;
+        let tx_data = if is_gateway {
+            calldata(
+                self.l1_gateway(eth_params.to)
+                    .deposit_eth(bytes, sub_account_id),
+            )?
+        } else {
+            calldata(
+                self.zklink(eth_params.to)
+                    .deposit_eth(bytes, sub_account_id),
+            )?
+        };
         let tx_params = EthTxParam {
             data: Some(tx_data),
-            ..eth_params.clone()
+            ..eth_params
         };
-        let tx_hash = self.sign_and_send_raw_tx(tx_params).await?;
-        Ok(tx_hash)
+        self.sign_and_send_raw_tx(tx_params).await
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -198,20 +279,17 @@ impl Wallet {
         mapping: bool,
         eth_params: EthTxParam,
     ) -> Result<H256, WalletError> {
-        let params = vec![
-            Token::Uint(ethers::types::U256::from(account_id)),
-            Token::Uint(ethers::types::U256::from(sub_account_id)),
-            Token::Uint(ethers::types::U256::from(token_id)),
-            Token::Bool(mapping),
-        ];
-        let contract = self.get_l1_contract(false);
-        let tx_data = encode_tx(contract.clone(), "requestFullExit", params)?;
+        let tx_data = calldata(self.zklink(eth_params.to).request_full_exit(
+            account_id,
+            sub_account_id,
+            token_id,
+            mapping,
+        ))?;
         let tx_params = EthTxParam {
             data: Some(tx_data),
-            ..eth_params.clone()
+            ..eth_params
         };
-        let tx_hash = self.sign_and_send_raw_tx(tx_params).await?;
-        Ok(tx_hash)
+        self.sign_and_send_raw_tx(tx_params).await
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -226,24 +304,34 @@ impl Wallet {
         eth_params: EthTxParam,
     ) -> Result<H256, WalletError> {
         let mut bytes = [0; 32];
-        // Token::FixedBytes will encode the bytes to right padded,eg. address is "0x1234...4321",
+        // The bytes32 is right padded when encoding,eg. address is "0x1234...4321",
         // the encode result will be incorrect "0x1234...4321000...000".So there must left padded first.
         bytes[12..].copy_from_slice(deposit_to.as_bytes());
-        let params = vec![
-            Token::Address(token_addr),
-            Token::Uint(ethers::types::U256::from_str_radix(&amount.to_string(), 10).unwrap()),
-            Token::FixedBytes(bytes.to_vec()),
-            Token::Uint(ethers::types::U256::from(sub_account_id)),
-            Token::Bool(mapping),
-        ];
-        let contract = self.get_l1_contract(is_gateway);
-        let tx_data = encode_tx(contract.clone(), "depositERC20", params)?;
+        let tx_data = if is_gateway {
+            let amount = biguint_to_u256(&amount)?;
+            calldata(self.l1_gateway(eth_params.to).deposit_erc20(
+                token_addr,
+                amount,
+                bytes,
+                sub_account_id,
+                mapping,
+            ))?
+        } else {
+            // the amount of the main contract is uint104
+            let amount = biguint_to_u128(&amount)?;
+            calldata(self.zklink(eth_params.to).deposit_erc20(
+                token_addr,
+                amount,
+                bytes,
+                sub_account_id,
+                mapping,
+            ))?
+        };
         let tx_params = EthTxParam {
             data: Some(tx_data),
-            ..eth_params.clone()
+            ..eth_params
         };
-        let tx_hash = self.sign_and_send_raw_tx(tx_params).await?;
-        Ok(tx_hash)
+        self.sign_and_send_raw_tx(tx_params).await
     }
 
     pub async fn inner_set_auth_pubkey_hash(
@@ -254,18 +342,16 @@ impl Wallet {
     ) -> Result<H256, WalletError> {
         let mut bytes = [0; 32];
         bytes[12..].copy_from_slice(new_pubkey_hash.as_ref());
-        let params = vec![
-            Token::Bytes(bytes.to_vec()),
-            Token::Uint(ethers::types::U256::from(nonce)),
-        ];
-        let contract = self.get_l1_contract(false);
-        let tx_data = encode_tx(contract.clone(), "setAuthPubkeyHash", params)?;
+        let nonce = u32::try_from(nonce).map_err(|_| WalletError::InvalidInputParameter)?;
+        let tx_data = calldata(
+            self.zklink(eth_params.to)
+                .set_auth_pubkey_hash(bytes.to_vec().into(), nonce),
+        )?;
         let tx_params = EthTxParam {
             data: Some(tx_data),
-            ..eth_params.clone()
+            ..eth_params
         };
-        let tx_hash = self.sign_and_send_raw_tx(tx_params).await?;
-        Ok(tx_hash)
+        self.sign_and_send_raw_tx(tx_params).await
     }
 
     pub async fn approve_erc20(
@@ -275,7 +361,7 @@ impl Wallet {
         eth_params: EthTxOption,
     ) -> Result<H256, WalletError> {
         let zklink_addr = Address::from_slice(zklink_addr.as_bytes());
-        self.inner_approve_erc20(zklink_addr, amount, eth_params.into())
+        self.inner_approve_erc20(zklink_addr, amount, eth_params.try_into()?)
             .await
     }
 
@@ -286,7 +372,7 @@ impl Wallet {
         eth_params: EthTxOption,
     ) -> Result<H256, WalletError> {
         let deposit_to = Address::from_slice(deposit_to.as_bytes());
-        self.inner_deposit_eth(sub_account_id, deposit_to, false, eth_params.into())
+        self.inner_deposit_eth(sub_account_id, deposit_to, false, eth_params.try_into()?)
             .await
     }
 
@@ -297,7 +383,7 @@ impl Wallet {
         eth_params: EthTxOption,
     ) -> Result<H256, WalletError> {
         let deposit_to = Address::from_slice(deposit_to.as_bytes());
-        self.inner_deposit_eth(sub_account_id, deposit_to, true, eth_params.into())
+        self.inner_deposit_eth(sub_account_id, deposit_to, true, eth_params.try_into()?)
             .await
     }
 
@@ -319,7 +405,7 @@ impl Wallet {
             amount,
             mapping,
             false,
-            eth_params.into(),
+            eth_params.try_into()?,
         )
         .await
     }
@@ -342,7 +428,7 @@ impl Wallet {
             amount,
             mapping,
             true,
-            eth_params.into(),
+            eth_params.try_into()?,
         )
         .await
     }
@@ -353,7 +439,7 @@ impl Wallet {
         new_pubkey_hash: PubKeyHash,
         eth_params: EthTxOption,
     ) -> Result<H256, WalletError> {
-        self.inner_set_auth_pubkey_hash(nonce, new_pubkey_hash, eth_params.into())
+        self.inner_set_auth_pubkey_hash(nonce, new_pubkey_hash, eth_params.try_into()?)
             .await
     }
 
@@ -370,18 +456,77 @@ impl Wallet {
             sub_account_id,
             token_id,
             mapping,
-            eth_params.into(),
+            eth_params.try_into()?,
         )
         .await
     }
 
     pub async fn inner_get_fee(&self, eth_params: EthTxParam) -> Result<BigUint, WalletError> {
-        let tokens = self.tx_call(eth_params, true, "fee", ()).await?;
-        let fee = Uint::from_tokens(tokens).map_err(|e| WalletError::GetErrorResult(e.0))?;
-        Ok(BigUint::from_str(&fee.to_string()).unwrap())
+        let fee = view_call(self.l1_gateway(eth_params.to).fee()).await?;
+        Ok(BigUint::from(fee))
     }
 
     pub async fn get_fee(&self, eth_params: EthTxOption) -> Result<BigUint, WalletError> {
-        self.inner_get_fee(eth_params.into()).await
+        self.inner_get_fee(eth_params.try_into()?).await
+    }
+}
+
+#[cfg(test)]
+impl Wallet<Provider<ethers::providers::MockProvider>> {
+    /// Create the wallet over the mock provider, the responses pushed into the mock provider are
+    /// popped in the reverse order.
+    pub(crate) fn mocked() -> (Self, ethers::providers::MockProvider) {
+        let private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let (provider, mock) = Provider::mocked();
+        let signer = EthSigner::try_from(private_key).unwrap();
+        (Self::new_with(provider, signer).unwrap(), mock)
+    }
+}
+
+/// The zklink rpc server returning the queued responses of each method in order, `None` and the
+/// methods not listed are responded with an error.
+#[cfg(test)]
+pub(crate) async fn mock_rpc(
+    responses: Vec<(&'static str, Vec<Option<serde_json::Value>>)>,
+) -> (
+    jsonrpsee::http_client::HttpClient,
+    jsonrpsee::server::ServerHandle,
+) {
+    use jsonrpsee::http_client::HttpClientBuilder;
+    use jsonrpsee::server::{RpcModule, Server};
+    use jsonrpsee::types::ErrorObjectOwned;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    let mut module = RpcModule::new(());
+    for (method, responses) in responses {
+        let responses = Mutex::new(VecDeque::from(responses));
+        module
+            .register_method(method, move |_, _| {
+                match responses.lock().unwrap().pop_front().flatten() {
+                    Some(value) => Ok(value),
+                    None => Err(ErrorObjectOwned::owned(-32000, "not found", None::<()>)),
+                }
+            })
+            .unwrap();
+    }
+    let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", server.local_addr().unwrap());
+    let handle = server.start(module);
+    (HttpClientBuilder::default().build(url).unwrap(), handle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_wallet() {
+        let private_key = "be725250b123a39dab5b7579334d5888987c72a58f4508062545fe6e08ca94f4";
+        let wallet = Wallet::new("http://localhost:8545", private_key).unwrap();
+        assert!(wallet.get_l1_contract(false).is_ok());
+        assert!(wallet.get_l1_contract(true).is_ok());
+        assert!(Wallet::new("http://localhost:8545", "invalid key").is_err());
+        assert!(Wallet::new("invalid url", private_key).is_err());
     }
 }
@@ -0,0 +1,464 @@
+use crate::error::WalletError;
+use crate::eth::{EthTxOption, EthTxParam};
+use crate::signer::Layer1Signer;
+use crate::utils::{biguint_to_u128, biguint_to_u256, layer1_address, u256_to_biguint};
+use crate::wallet::{calldata, view_call, Wallet};
+use ethers::abi::{Address, RawLog, Token};
+use ethers::providers::Middleware;
+use ethers::types::{Log, U256};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use zklink_sdk_types::basic_types::{BigUint, ZkLinkAddress};
+use zklink_sdk_types::prelude::H256;
+use zklink_sdk_types::tx_type::withdraw::Withdraw;
+use zklink_sdk_utils::serde::BigUintSerdeAsRadix10Str;
+
+/// The denominator of the `withdraw_fee_ratio`, 100 means 1%.
+pub const MAX_ACCEPT_FEE_RATE: u16 = 10000;
+
+/// The withdrawal events emitted by the zklink contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum WithdrawalEvent {
+    /// The token is transferred to the owner.
+    Withdrawal {
+        token_id: u16,
+        #[serde(with = "BigUintSerdeAsRadix10Str")]
+        amount: BigUint,
+    },
+    /// The token is failed to transfer and added to the pending balance of the owner.
+    WithdrawalPending {
+        token_id: u16,
+        recipient: H256,
+        #[serde(with = "BigUintSerdeAsRadix10Str")]
+        amount: BigUint,
+    },
+    /// The fast withdrawal is accepted by the acceptor.
+    Accept {
+        acceptor: Address,
+        receiver: Address,
+        token: Address,
+        #[serde(with = "BigUintSerdeAsRadix10Str")]
+        amount: BigUint,
+        withdraw_fee_rate: u16,
+        account_id_of_nonce: u32,
+        sub_account_id_of_nonce: u8,
+        nonce: u32,
+        #[serde(with = "BigUintSerdeAsRadix10Str")]
+        amount_receive: BigUint,
+    },
+}
+
+/// The amount received by the receiver of the fast withdrawal, the acceptor gets the fee.
+pub fn accept_amount(amount: &BigUint, withdraw_fee_ratio: u16) -> BigUint {
+    amount * BigUint::from(MAX_ACCEPT_FEE_RATE.saturating_sub(withdraw_fee_ratio))
+        / BigUint::from(MAX_ACCEPT_FEE_RATE)
+}
+
+impl<M: Middleware, S: Layer1Signer> Wallet<M, S> {
+    pub async fn inner_get_pending_balance(
+        &self,
+        owner: &ZkLinkAddress,
+        token_id: u16,
+        eth_params: EthTxParam,
+    ) -> Result<BigUint, WalletError> {
+        let balance = view_call(
+            self.zklink(eth_params.to)
+                .get_pending_balance(owner.to_fixed_bytes(), token_id),
+        )
+        .await?;
+        Ok(BigUint::from(balance))
+    }
+
+    pub async fn inner_withdraw_pending_balance(
+        &self,
+        owner: Address,
+        token_id: u16,
+        amount: BigUint,
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        let amount = biguint_to_u128(&amount)?;
+        let tx_data = calldata(
+            self.zklink(eth_params.to)
+                .withdraw_pending_balance(owner, token_id, amount),
+        )?;
+        let tx_params = EthTxParam {
+            data: Some(tx_data),
+            ..eth_params
+        };
+        self.sign_and_send_raw_tx(tx_params).await
+    }
+
+    /// Accept the fast withdrawal, the `amount` is the layer1 amount of the withdrawal and the token
+    /// is ETH if `token_addr` is `None`. The receiver(`withdraw.to`) must be a layer1 address.
+    pub async fn inner_accept(
+        &self,
+        withdraw: &Withdraw,
+        token_addr: Option<Address>,
+        amount: BigUint,
+        eth_params: EthTxParam,
+    ) -> Result<H256, WalletError> {
+        let receiver = layer1_address(&withdraw.to)?;
+        let contract = self.zklink(eth_params.to);
+        let l1_amount = biguint_to_u128(&amount)?;
+        let (tx_data, value) = match token_addr {
+            Some(token_addr) => {
+                let tx_data = calldata(contract.accept_erc20(
+                    receiver,
+                    token_addr,
+                    l1_amount,
+                    withdraw.withdraw_fee_ratio,
+                    *withdraw.account_id,
+                    *withdraw.sub_account_id,
+                    *withdraw.nonce,
+                ))?;
+                (tx_data, eth_params.value)
+            }
+            None => {
+                let tx_data = calldata(contract.accept_eth(
+                    receiver,
+                    l1_amount,
+                    withdraw.withdraw_fee_ratio,
+                    *withdraw.account_id,
+                    *withdraw.sub_account_id,
+                    *withdraw.nonce,
+                ))?;
+                let value = accept_amount(&amount, withdraw.withdraw_fee_ratio);
+                (tx_data, Some(biguint_to_u256(&value)?))
+            }
+        };
+        let tx_params = EthTxParam {
+            data: Some(tx_data),
+            value,
+            ..eth_params
+        };
+        self.sign_and_send_raw_tx(tx_params).await
+    }
+
+    /// Decode the `Withdrawal`, `WithdrawalPending` and `Accept` events emitted by the zklink
+    /// contract, the logs of other events or other contracts are ignored.
+    pub fn decode_withdrawal_events(
+        &self,
+        zklink_addr: Address,
+        logs: &[Log],
+    ) -> Result<Vec<WithdrawalEvent>, WalletError> {
+        let contract = self.get_l1_contract(false)?;
+        let withdrawal = contract.event("Withdrawal")?;
+        let withdrawal_pending = contract.event("WithdrawalPending")?;
+        let accept = contract.event("Accept")?;
+        let invalid_event = || WalletError::GetErrorResult("invalid withdrawal event".into());
+        let uint = |token: &Token| token.clone().into_uint().ok_or_else(invalid_event);
+        let address = |token: &Token| token.clone().into_address().ok_or_else(invalid_event);
+
+        let mut events = Vec::new();
+        for log in logs {
+            if log.address != zklink_addr {
+                continue;
+            }
+            let Some(topic) = log.topics.first() else {
+                continue;
+            };
+            let raw_log = RawLog {
+                topics: log.topics.clone(),
+                data: log.data.to_vec(),
+            };
+            let event = if *topic == withdrawal.signature() {
+                let params = withdrawal.parse_log(raw_log)?.params;
+                WithdrawalEvent::Withdrawal {
+                    token_id: uint(&params[0].value)?.as_u32() as u16,
+                    amount: u256_to_biguint(uint(&params[1].value)?),
+                }
+            } else if *topic == withdrawal_pending.signature() {
+                let params = withdrawal_pending.parse_log(raw_log)?.params;
+                let recipient = params[1]
+                    .value
+                    .clone()
+                    .into_fixed_bytes()
+                    .ok_or_else(invalid_event)?;
+                WithdrawalEvent::WithdrawalPending {
+                    token_id: uint(&params[0].value)?.as_u32() as u16,
+                    recipient: H256::from_slice(&recipient),
+                    amount: u256_to_biguint(uint(&params[2].value)?),
+                }
+            } else if *topic == accept.signature() {
+                let params = accept.parse_log(raw_log)?.params;
+                WithdrawalEvent::Accept {
+                    acceptor: address(&params[0].value)?,
+                    receiver: address(&params[1].value)?,
+                    token: address(&params[2].value)?,
+                    amount: u256_to_biguint(uint(&params[3].value)?),
+                    withdraw_fee_rate: uint(&params[4].value)?.as_u32() as u16,
+                    account_id_of_nonce: uint(&params[5].value)?.as_u32(),
+                    sub_account_id_of_nonce: uint(&params[6].value)?.as_u32() as u8,
+                    nonce: uint(&params[7].value)?.as_u32(),
+                    amount_receive: u256_to_biguint(uint(&params[8].value)?),
+                }
+            } else {
+                continue;
+            };
+            events.push(event);
+        }
+        Ok(events)
+    }
+
+    /// Wait for the transaction mined and returns the withdrawal events emitted by it.
+    pub async fn wait_for_withdrawal_events(
+        &self,
+        zklink_addr: ZkLinkAddress,
+        tx_hash: H256,
+        retries: Option<u32>,
+    ) -> Result<Vec<WithdrawalEvent>, WalletError> {
+        let zklink_addr = layer1_address(&zklink_addr)?;
+        let mut retries = retries.unwrap_or(60);
+        loop {
+            if let Some(receipt) = self
+                .provider
+                .get_transaction_receipt(tx_hash)
+                .await
+                .map_err(WalletError::from_middleware)?
+            {
+                if receipt.status.map(|s| s.as_u64()) == Some(0) {
+                    return Err(WalletError::GetErrorResult(format!(
+                        "transaction {tx_hash:?} failed"
+                    )));
+                }
+                return self.decode_withdrawal_events(zklink_addr, &receipt.logs);
+            }
+            retries = retries.saturating_sub(1);
+            if retries == 0 {
+                return Err(WalletError::Timeout(format!(
+                    "transaction {tx_hash:?} is not mined"
+                )));
+            }
+            async_std::task::sleep(Duration::from_secs(1)).await
+        }
+    }
+
+    pub async fn get_pending_balance(
+        &self,
+        owner: ZkLinkAddress,
+        token_id: u16,
+        eth_params: EthTxOption,
+    ) -> Result<BigUint, WalletError> {
+        self.inner_get_pending_balance(&owner, token_id, eth_params.try_into()?)
+            .await
+    }
+
+    /// Query the pending balances of the tokens.
+    pub async fn get_pending_balances(
+        &self,
+        owner: ZkLinkAddress,
+        token_ids: Vec<u16>,
+        eth_params: EthTxOption,
+    ) -> Result<HashMap<u16, BigUint>, WalletError> {
+        let eth_params: EthTxParam = eth_params.try_into()?;
+        let mut balances = HashMap::with_capacity(token_ids.len());
+        for token_id in token_ids {
+            let balance = self
+                .inner_get_pending_balance(&owner, token_id, eth_params.clone())
+                .await?;
+            balances.insert(token_id, balance);
+        }
+        Ok(balances)
+    }
+
+    pub async fn withdraw_pending_balance(
+        &self,
+        owner: ZkLinkAddress,
+        token_id: u16,
+        amount: BigUint,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let owner = layer1_address(&owner)?;
+        self.inner_withdraw_pending_balance(owner, token_id, amount, eth_params.try_into()?)
+            .await
+    }
+
+    /// Accept the fast withdrawal of ETH, the value sent is the `amount` minus the fee.
+    pub async fn accept_eth(
+        &self,
+        withdraw: &Withdraw,
+        amount: BigUint,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        self.inner_accept(withdraw, None, amount, eth_params.try_into()?)
+            .await
+    }
+
+    /// Accept the fast withdrawal of ERC20 token, the acceptor should approve the zklink contract
+    /// with the accept amount at least.
+    pub async fn accept_erc20(
+        &self,
+        withdraw: &Withdraw,
+        token_addr: ZkLinkAddress,
+        amount: BigUint,
+        eth_params: EthTxOption,
+    ) -> Result<H256, WalletError> {
+        let token_addr = layer1_address(&token_addr)?;
+        self.inner_accept(withdraw, Some(token_addr), amount, eth_params.try_into()?)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ethers::providers::{MockProvider, Provider};
+
+    #[test]
+    fn test_accept_amount() {
+        let amount = BigUint::from(1_000_000u32);
+        assert_eq!(accept_amount(&amount, 0), amount);
+        assert_eq!(accept_amount(&amount, 50), BigUint::from(995_000u32));
+        assert_eq!(
+            accept_amount(&amount, MAX_ACCEPT_FEE_RATE),
+            BigUint::from(0u32)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reject_starknet_address() {
+        let (wallet, _mock) = Wallet::mocked();
+        let eth_params = EthTxOption {
+            to: ZkLinkAddress::from([0x55; 20]),
+            ..Default::default()
+        };
+        // the starknet address is 32 bytes
+        let starknet_addr = ZkLinkAddress::from([0x11; 32]);
+        let withdraw = Withdraw {
+            to: starknet_addr.clone(),
+            ..Default::default()
+        };
+        let result = wallet
+            .accept_eth(&withdraw, BigUint::from(100u32), eth_params.clone())
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+        let result = wallet
+            .withdraw_pending_balance(
+                starknet_addr.clone(),
+                17,
+                BigUint::from(100u32),
+                eth_params.clone(),
+            )
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+        let result = wallet
+            .wait_for_withdrawal_events(starknet_addr, H256::zero(), Some(1))
+            .await;
+        assert!(matches!(result, Err(WalletError::InvalidInputParameter)));
+    }
+
+    fn topic(wallet: &Wallet<Provider<MockProvider>>, name: &str) -> H256 {
+        wallet
+            .get_l1_contract(false)
+            .unwrap()
+            .event(name)
+            .unwrap()
+            .signature()
+    }
+
+    fn uint_topic(value: u64) -> H256 {
+        H256::from_low_u64_be(value)
+    }
+
+    #[test]
+    fn test_decode_withdrawal_events() {
+        let (wallet, _mock) = Wallet::mocked();
+        let recipient = H256::repeat_byte(0x11);
+        let acceptor = Address::repeat_byte(0x22);
+        let receiver = Address::repeat_byte(0x33);
+        let token = Address::repeat_byte(0x44);
+        let zklink_addr = Address::repeat_byte(0x55);
+        let mut logs = vec![
+            Log {
+                topics: vec![topic(&wallet, "Withdrawal"), uint_topic(18)],
+                data: ethers::abi::encode(&[Token::Uint(U256::from(1000))]).into(),
+                ..Default::default()
+            },
+            Log {
+                topics: vec![topic(&wallet, "ExodusMode")],
+                ..Default::default()
+            },
+            Log {
+                topics: vec![
+                    topic(&wallet, "WithdrawalPending"),
+                    uint_topic(17),
+                    recipient,
+                ],
+                data: ethers::abi::encode(&[Token::Uint(U256::from(2000))]).into(),
+                ..Default::default()
+            },
+            Log {
+                topics: vec![topic(&wallet, "Accept")],
+                data: ethers::abi::encode(&[
+                    Token::Address(acceptor),
+                    Token::Address(receiver),
+                    Token::Address(token),
+                    Token::Uint(U256::from(1_000_000)),
+                    Token::Uint(U256::from(50)),
+                    Token::Uint(U256::from(10)),
+                    Token::Uint(U256::from(1)),
+                    Token::Uint(U256::from(3)),
+                    Token::Uint(U256::from(995_000)),
+                ])
+                .into(),
+                ..Default::default()
+            },
+        ];
+        for log in &mut logs {
+            log.address = zklink_addr;
+        }
+        // the same event emitted by another contract
+        logs.push(Log {
+            address: Address::repeat_byte(0x66),
+            ..logs[0].clone()
+        });
+        let events = wallet.decode_withdrawal_events(zklink_addr, &logs).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                WithdrawalEvent::Withdrawal {
+                    token_id: 18,
+                    amount: BigUint::from(1000u32),
+                },
+                WithdrawalEvent::WithdrawalPending {
+                    token_id: 17,
+                    recipient,
+                    amount: BigUint::from(2000u32),
+                },
+                WithdrawalEvent::Accept {
+                    acceptor,
+                    receiver,
+                    token,
+                    amount: BigUint::from(1_000_000u32),
+                    withdraw_fee_rate: 50,
+                    account_id_of_nonce: 10,
+                    sub_account_id_of_nonce: 1,
+                    nonce: 3,
+                    amount_receive: BigUint::from(995_000u32),
+                },
+            ]
+        );
+        // the amounts are serialized as the decimal strings
+        let json = serde_json::to_value(&events[2]).unwrap();
+        assert_eq!(json["accept"]["amount"], "1000000");
+        assert_eq!(json["accept"]["amountReceive"], "995000");
+        assert_eq!(json["accept"]["withdrawFeeRate"], 50);
+        assert!(json["accept"].get("amount_receive").is_none());
+        let json = serde_json::to_value(&events[1]).unwrap();
+        assert_eq!(json["withdrawalPending"]["amount"], "2000");
+        assert_eq!(json["withdrawalPending"]["tokenId"], 17);
+
+        // the indexed token id is missing
+        let invalid_log = Log {
+            address: zklink_addr,
+            topics: vec![topic(&wallet, "Withdrawal")],
+            data: ethers::abi::encode(&[Token::Uint(U256::from(1000))]).into(),
+            ..Default::default()
+        };
+        assert!(wallet
+            .decode_withdrawal_events(zklink_addr, &[invalid_log])
+            .is_err());
+    }
+}